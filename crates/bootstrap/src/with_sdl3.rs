@@ -1,6 +1,8 @@
+use std::mem::MaybeUninit;
 use std::time::Instant;
 
 use sdl3::event::{Event, WindowEvent};
+use sdl3::sys::events::{SDL_Event, SDL_PollEvent};
 use yakui::{UVec2, Yakui};
 use yakui_sdl3::YakuiSdl3;
 
@@ -21,7 +23,7 @@ pub fn run<T: ExampleBody>(mut yak: Yakui, mut state: ExampleState, title: Strin
     window_builder.resizable().position_centered().metal_view();
 
     let window = window_builder.build().unwrap();
-    let mut event_pump = sdl_context.event_pump().unwrap();
+    let _event_pump = sdl_context.event_pump().unwrap();
 
     let (width, height) = window.size();
     let size = UVec2::new(width, height);
@@ -30,12 +32,13 @@ pub fn run<T: ExampleBody>(mut yak: Yakui, mut state: ExampleState, title: Strin
     let mut graphics = pollster::block_on(Graphics::new(&window, size, get_sample_count()));
 
     'main_loop: loop {
-        for event in event_pump.poll_iter() {
-            if yak_window.handle_event(&mut yak, &event) {
+        // Events are polled raw so that yakui knows where files are dropped.
+        while let Some(raw) = poll_raw_event() {
+            if yak_window.handle_raw_event(&mut yak, &raw) {
                 continue;
             }
 
-            match event {
+            match Event::from_ll(raw) {
                 Event::Quit { .. } => {
                     break 'main_loop;
                 }
@@ -76,3 +79,8 @@ pub fn run<T: ExampleBody>(mut yak: Yakui, mut state: ExampleState, title: Strin
         profiling::finish_frame!();
     }
 }
+
+fn poll_raw_event() -> Option<SDL_Event> {
+    let mut raw = MaybeUninit::uninit();
+    unsafe { SDL_PollEvent(raw.as_mut_ptr()).then(|| raw.assume_init()) }
+}
@@ -7,7 +7,7 @@ mod dynamic_scope;
 mod root;
 
 use std::any::{type_name, TypeId};
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::VecDeque;
 use std::mem::replace;
 use std::panic::Location;
@@ -18,6 +18,7 @@ use thunderdome::Arena;
 
 use crate::id::WidgetId;
 use crate::response::Response;
+use crate::stats::FrameStats;
 use crate::widget::{ErasedWidget, Widget};
 
 use self::dummy::DummyWidget;
@@ -36,6 +37,7 @@ struct DomInner {
     root: WidgetId,
     globals: RefCell<AnyMap>,
     dynamic_scope: DynamicScope,
+    frame_stats: Cell<FrameStats>,
 }
 
 /// A node in the [`Dom`].
@@ -186,6 +188,18 @@ impl Dom {
         globals.entry::<T>().or_insert_with(init).clone()
     }
 
+    /// Returns statistics about the most recently painted frame.
+    ///
+    /// While the DOM is being built, this describes the previous frame, which
+    /// lets widgets display it.
+    pub fn frame_stats(&self) -> FrameStats {
+        self.inner.frame_stats.get()
+    }
+
+    pub(crate) fn set_frame_stats(&self, stats: FrameStats) {
+        self.inner.frame_stats.set(stats);
+    }
+
     /// Convenience method for calling [`Dom::begin_widget`] immediately
     /// followed by [`Dom::end_widget`].
     #[track_caller]
@@ -287,6 +301,7 @@ impl DomInner {
             stack: RefCell::new(Vec::new()),
             root: WidgetId::new(root),
             dynamic_scope: DynamicScope::new(),
+            frame_stats: Cell::new(FrameStats::default()),
         }
     }
 }
@@ -1,12 +1,15 @@
 //! Defines the events that can be sent to yakui and handled by widgets.
 
+use std::path::PathBuf;
+
 use {crate::WidgetId, glam::Vec2};
 
 use crate::geometry::Rect;
-use crate::input::{KeyCode, Modifiers, MouseButton};
+use crate::input::{KeyCode, Modifiers, MouseButton, ScrollDelta, Shortcut};
 
 /// An event that can be handled by yakui.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The viewport has changed. This can mean resizing as well as positioning.
     ViewportChanged(Rect),
@@ -26,8 +29,10 @@ pub enum Event {
 
     /// The user scrolled with the mouse.
     MouseScroll {
-        /// How far the mouse scrolled in physical pixels.
-        delta: Vec2,
+        /// How far the mouse scrolled, either in lines or in physical pixels.
+        /// Positive values scroll content up and to the left, revealing more
+        /// of what's below and to the right.
+        delta: ScrollDelta,
     },
 
     /// A key changed, telling whether it is now pressed.
@@ -50,6 +55,38 @@ pub enum Event {
 
     /// Request focus of a specific widget, or clear focus if `None`.
     RequestFocus(Option<WidgetId>),
+
+    /// A file from outside the application is being dragged over the window.
+    /// Platforms send one event per file.
+    FileHovered {
+        /// The path of the file, or an empty path on platforms that only
+        /// report paths once files are dropped.
+        path: PathBuf,
+
+        /// The position of the cursor in physical pixels, if the platform
+        /// reports one. Otherwise, the last known cursor position is used.
+        position: Option<Vec2>,
+    },
+
+    /// Files that were being dragged over the window left it, or the drag was
+    /// cancelled.
+    FileHoverCancelled,
+
+    /// A file from outside the application was dropped onto the window.
+    /// Platforms send one event per file.
+    FileDropped {
+        /// The path of the file.
+        path: PathBuf,
+
+        /// The position of the cursor in physical pixels, if the platform
+        /// reports one. Otherwise, the last known cursor position is used.
+        position: Option<Vec2>,
+    },
+
+    /// The window gained or lost focus. Losing focus releases any held mouse
+    /// buttons and modifier keys, since their release events will go to
+    /// another window.
+    WindowFocusChanged(bool),
 }
 
 /// An event that can be handled by an individual widget.
@@ -66,8 +103,9 @@ pub enum WidgetEvent {
 
     /// The user scrolled with the mouse.
     MouseScroll {
-        /// How much the wheel scrolled in logical pixels.
-        delta: Vec2,
+        /// How far the mouse scrolled, either in lines or in logical pixels.
+        /// Use [`ScrollDelta::to_pixels`] to get a distance to scroll by.
+        delta: ScrollDelta,
 
         /// The current state of the keyboard modifier keys.
         modifiers: Modifiers,
@@ -88,6 +126,25 @@ pub enum WidgetEvent {
         /// The position of the mouse cursor at the time of the event.
         position: Vec2,
 
+        /// How many times the button has been pressed in quick succession,
+        /// like 2 for a double-click. Releases report the count of the press
+        /// they end.
+        click_count: u32,
+
+        /// The current state of the keyboard modifier keys.
+        modifiers: Modifiers,
+    },
+
+    /// A mouse button was held down without moving for long enough to count
+    /// as a long press. Sent to the widgets under the cursor, or the widget
+    /// holding pointer capture. The button's release is still sent afterwards.
+    MouseLongPress {
+        /// Which button was held.
+        button: MouseButton,
+
+        /// Where the button was pressed.
+        position: Vec2,
+
         /// The current state of the keyboard modifier keys.
         modifiers: Modifiers,
     },
@@ -107,8 +164,40 @@ pub enum WidgetEvent {
     /// Text was sent to the widget.
     TextInput(char, Modifiers),
 
+    /// A shortcut that the widget registered with
+    /// [`InputState::register_shortcut`][crate::input::InputState::register_shortcut]
+    /// was pressed.
+    ShortcutTriggered(Shortcut),
+
     /// The widget was focused or unfocused.
     FocusChanged(bool),
+
+    /// The widget held pointer capture and lost it before the mouse buttons
+    /// were released, like when another widget captured the pointer. Widgets
+    /// should cancel any drag in progress.
+    PointerCaptureLost,
+
+    /// A file from outside the application is being dragged over the widget.
+    FileHovered {
+        /// The path of the file.
+        path: PathBuf,
+
+        /// The position of the mouse cursor at the time of the event.
+        position: Vec2,
+    },
+
+    /// A file that was hovering over the widget was dropped somewhere else, or
+    /// the drag was cancelled.
+    FileHoverCancelled,
+
+    /// A file from outside the application was dropped onto the widget.
+    FileDropped {
+        /// The path of the file.
+        path: PathBuf,
+
+        /// The position of the mouse cursor at the time of the event.
+        position: Vec2,
+    },
 }
 
 /// Responses that can be given to an event.
@@ -126,6 +215,7 @@ pub enum EventResponse {
 bitflags::bitflags! {
     /// A bitfield of events that a widget can register to be notified about.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct EventInterest: u8 {
         /// Notify this widget of mouse events occurring within its layout
         /// rectangle.
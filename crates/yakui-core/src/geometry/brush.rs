@@ -0,0 +1,238 @@
+use glam::{Vec2, Vec4};
+
+use super::{Color, Rect};
+
+/// Describes how to fill a shape: with a solid color or a gradient.
+///
+/// Gradient positions are relative to the bounds of the shape being filled,
+/// where `(0, 0)` is the top-left corner and `(1, 1)` is the bottom-right
+/// corner. Colors are interpolated in linear space.
+///
+/// ```rust
+/// # use yakui_core::geometry::{Brush, Color, Vec2};
+/// let health = Brush::linear(
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(1.0, 0.0),
+///     [(0.0, Color::RED), (0.5, Color::YELLOW), (1.0, Color::GREEN)],
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Brush {
+    /// Fill with a single color.
+    Solid(Color),
+
+    /// Fill with colors changing along a line.
+    Linear(LinearGradient),
+
+    /// Fill with colors changing with the distance from a point.
+    Radial(RadialGradient),
+}
+
+/// A color at a specific offset along a gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// How far along the gradient this stop is, from 0.0 to 1.0.
+    pub offset: f32,
+
+    /// The color of the gradient at this stop.
+    pub color: Color,
+}
+
+impl From<(f32, Color)> for GradientStop {
+    fn from((offset, color): (f32, Color)) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// A gradient changing color along the line from `start` to `end`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+    /// Where the gradient starts, relative to the filled shape's bounds.
+    pub start: Vec2,
+
+    /// Where the gradient ends, relative to the filled shape's bounds.
+    pub end: Vec2,
+
+    /// The colors of the gradient, sorted by offset.
+    pub stops: Vec<GradientStop>,
+}
+
+/// A gradient changing color going outwards from `center`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient {
+    /// The center of the gradient, relative to the filled shape's bounds.
+    pub center: Vec2,
+
+    /// The radius of the gradient on each axis, relative to the filled
+    /// shape's bounds. A radius of `(0.5, 0.5)` reaches the edges of the
+    /// shape.
+    pub radius: Vec2,
+
+    /// The colors of the gradient, sorted by offset.
+    pub stops: Vec<GradientStop>,
+}
+
+impl Brush {
+    /// Create a linear gradient from `start` to `end`.
+    pub fn linear<S, I>(start: Vec2, end: Vec2, stops: I) -> Self
+    where
+        S: Into<GradientStop>,
+        I: IntoIterator<Item = S>,
+    {
+        Self::Linear(LinearGradient {
+            start,
+            end,
+            stops: sorted_stops(stops),
+        })
+    }
+
+    /// Create a radial gradient around `center` with the given radius.
+    pub fn radial<S, I>(center: Vec2, radius: Vec2, stops: I) -> Self
+    where
+        S: Into<GradientStop>,
+        I: IntoIterator<Item = S>,
+    {
+        Self::Radial(RadialGradient {
+            center,
+            radius,
+            stops: sorted_stops(stops),
+        })
+    }
+
+    /// Returns the color of this brush if it is a single solid color.
+    pub fn as_solid(&self) -> Option<Color> {
+        match self {
+            Self::Solid(color) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// Returns the linear color of the brush at `pos`, when filling a shape
+    /// with the given bounds.
+    pub fn color_at(&self, bounds: Rect, pos: Vec2) -> Vec4 {
+        let relative = (pos - bounds.pos()) / bounds.size().max(Vec2::splat(f32::EPSILON));
+
+        match self {
+            Self::Solid(color) => color.to_linear(),
+            Self::Linear(gradient) => {
+                let dir = gradient.end - gradient.start;
+                let t =
+                    (relative - gradient.start).dot(dir) / dir.length_squared().max(f32::EPSILON);
+                sample_stops(&gradient.stops, t)
+            }
+            Self::Radial(gradient) => {
+                let offset =
+                    (relative - gradient.center) / gradient.radius.max(Vec2::splat(f32::EPSILON));
+                sample_stops(&gradient.stops, offset.length())
+            }
+        }
+    }
+
+    /// Returns the positions along each axis where geometry filled with this
+    /// brush should be split, so that interpolating vertex colors across it
+    /// matches the brush closely. Positions cover `rect`, which should be a
+    /// part of the shape with the given bounds, and include its edges.
+    pub fn grid_lines(&self, bounds: Rect, rect: Rect) -> (Vec<f32>, Vec<f32>) {
+        let mut xs = vec![rect.pos().x, rect.max().x];
+        let mut ys = vec![rect.pos().y, rect.max().y];
+
+        match self {
+            Self::Solid(_) => {}
+
+            // Colors only change along one axis, so splitting at each stop is
+            // exact.
+            Self::Linear(gradient) if gradient.start.x == gradient.end.x => {
+                for stop in &gradient.stops {
+                    let y = gradient.start.y + (gradient.end.y - gradient.start.y) * stop.offset;
+                    ys.push(bounds.pos().y + y * bounds.size().y);
+                }
+            }
+            Self::Linear(gradient) if gradient.start.y == gradient.end.y => {
+                for stop in &gradient.stops {
+                    let x = gradient.start.x + (gradient.end.x - gradient.start.x) * stop.offset;
+                    xs.push(bounds.pos().x + x * bounds.size().x);
+                }
+            }
+
+            Self::Linear(gradient) => {
+                let segments = 4 * gradient.stops.len().max(2);
+                uniform_lines(&mut xs, &mut ys, bounds, segments);
+            }
+            Self::Radial(gradient) => {
+                let segments = 8 * gradient.stops.len().max(2);
+                uniform_lines(&mut xs, &mut ys, bounds, segments);
+            }
+        }
+
+        let clean = |lines: &mut Vec<f32>, a: f32, b: f32| {
+            let (min, max) = (a.min(b), a.max(b));
+            lines.retain(|&line| line >= min && line <= max);
+            lines.sort_by(f32::total_cmp);
+            lines.dedup_by(|a, b| (*a - *b).abs() < 0.01);
+        };
+
+        clean(&mut xs, rect.pos().x, rect.max().x);
+        clean(&mut ys, rect.pos().y, rect.max().y);
+
+        (xs, ys)
+    }
+}
+
+impl From<Color> for Brush {
+    fn from(color: Color) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl Default for Brush {
+    fn default() -> Self {
+        Self::Solid(Color::WHITE)
+    }
+}
+
+fn sorted_stops<S, I>(stops: I) -> Vec<GradientStop>
+where
+    S: Into<GradientStop>,
+    I: IntoIterator<Item = S>,
+{
+    let mut stops: Vec<GradientStop> = stops.into_iter().map(Into::into).collect();
+    stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    stops
+}
+
+fn sample_stops(stops: &[GradientStop], t: f32) -> Vec4 {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Vec4::ZERO;
+    };
+
+    if t <= first.offset {
+        return first.color.to_linear();
+    }
+
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+
+        if t <= b.offset {
+            let span = b.offset - a.offset;
+            let ratio = if span > 0.0 {
+                (t - a.offset) / span
+            } else {
+                1.0
+            };
+
+            return a.color.to_linear().lerp(b.color.to_linear(), ratio);
+        }
+    }
+
+    last.color.to_linear()
+}
+
+fn uniform_lines(xs: &mut Vec<f32>, ys: &mut Vec<f32>, bounds: Rect, segments: usize) {
+    let step = bounds.size() / segments as f32;
+
+    for i in 1..segments {
+        let line = bounds.pos() + step * i as f32;
+        xs.push(line.x);
+        ys.push(line.y);
+    }
+}
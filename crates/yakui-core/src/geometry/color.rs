@@ -93,6 +93,37 @@ impl Color {
         Self::from_linear(self.to_linear().lerp(other.to_linear(), ratio))
     }
 
+    /// Parse a color written as `#rrggbb` or `#rrggbbaa`. The leading `#` is
+    /// optional.
+    ///
+    /// ```rust
+    /// # use yakui_core::geometry::Color;
+    /// assert_eq!(Color::parse_hex("#ff6137"), Some(Color::rgb(255, 97, 55)));
+    /// assert_eq!(Color::parse_hex("ff613780"), Some(Color::rgba(255, 97, 55, 128)));
+    /// assert_eq!(Color::parse_hex("orange"), None);
+    /// ```
+    pub fn parse_hex(value: &str) -> Option<Self> {
+        let digits = value.strip_prefix('#').unwrap_or(value);
+        if !matches!(digits.len(), 6 | 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+        let a = if digits.len() == 8 { channel(6)? } else { 255 };
+
+        Some(Self::rgba(channel(0)?, channel(2)?, channel(4)?, a))
+    }
+
+    /// Format the color as `#rrggbb`, or `#rrggbbaa` if it is not fully
+    /// opaque.
+    pub fn to_hex_string(&self) -> String {
+        if self.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
     /// Turn the color into an array.
     pub fn to_array(&self) -> [u8; 4] {
         [self.r, self.g, self.b, self.a]
@@ -148,3 +179,21 @@ impl From<[u8; 4]> for Color {
         }
     }
 }
+
+/// Colors are serialized as hex strings, as formatted by
+/// [`Color::to_hex_string`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse_hex(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid hex color: {value:?}")))
+    }
+}
@@ -2,11 +2,14 @@ use glam::Vec2;
 
 /// Defines box constraints used for layout.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constraints {
     /// The minimum size that is allowed by these constraints.
+    #[cfg_attr(feature = "serde", serde(with = "unbounded"))]
     pub min: Vec2,
 
     /// The maximum size that is allowed by these constraints.
+    #[cfg_attr(feature = "serde", serde(with = "unbounded"))]
     pub max: Vec2,
 }
 
@@ -86,3 +89,82 @@ impl Constraints {
 fn is_nonmax(value: Vec2) -> bool {
     value.x < f32::MAX && value.y < f32::MAX
 }
+
+/// Constraints are often unbounded, but formats like JSON can't represent
+/// infinity. In human-readable formats, each infinite axis is written as the
+/// string `"inf"` or `"-inf"` instead of a number.
+#[cfg(feature = "serde")]
+mod unbounded {
+    use std::fmt;
+
+    use glam::Vec2;
+    use serde::de::{self, Deserialize, Deserializer, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    struct Axis(f32);
+
+    impl Serialize for Axis {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if self.0 == f32::INFINITY {
+                serializer.serialize_str("inf")
+            } else if self.0 == f32::NEG_INFINITY {
+                serializer.serialize_str("-inf")
+            } else {
+                serializer.serialize_f32(self.0)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Axis {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(AxisVisitor)
+        }
+    }
+
+    struct AxisVisitor;
+
+    impl Visitor<'_> for AxisVisitor {
+        type Value = Axis;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a number, \"inf\", or \"-inf\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Axis, E> {
+            Ok(Axis(value as f32))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Axis, E> {
+            Ok(Axis(value as f32))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Axis, E> {
+            Ok(Axis(value as f32))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Axis, E> {
+            match value {
+                "inf" => Ok(Axis(f32::INFINITY)),
+                "-inf" => Ok(Axis(f32::NEG_INFINITY)),
+                _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(value: &Vec2, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            [Axis(value.x), Axis(value.y)].serialize(serializer)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec2, D::Error> {
+        if deserializer.is_human_readable() {
+            let [x, y] = <[Axis; 2]>::deserialize(deserializer)?;
+            Ok(Vec2::new(x.0, y.0))
+        } else {
+            Vec2::deserialize(deserializer)
+        }
+    }
+}
@@ -14,6 +14,7 @@ use glam::Vec2;
 /// where `dim.pixels` is the `px` unit in CSS and `dim.percent` is the `%` unit
 /// in CSS.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dim {
     /// The portion of the value in logical pixels. Works like the `px` unit in
     /// CSS.
@@ -60,6 +61,7 @@ impl Dim {
 ///
 /// See [`Dim`] for more information on the units available and their meaning.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dim2 {
     /// The dimension on the X axis.
     pub x: Dim,
@@ -1,5 +1,6 @@
 //! Defines common geometry types used by yakui.
 
+mod brush;
 mod color;
 mod constraints;
 mod dim;
@@ -9,6 +10,7 @@ mod urect;
 #[doc(no_inline)]
 pub use glam::{UVec2, Vec2, Vec4};
 
+pub use self::brush::*;
 pub use self::color::*;
 pub use self::constraints::*;
 pub use self::dim::*;
@@ -17,6 +19,7 @@ pub use self::urect::*;
 
 /// Defines how a flexible container should size its children.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlexFit {
     /// The container forces its children to stretch to its size.
     Tight,
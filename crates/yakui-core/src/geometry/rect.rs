@@ -7,6 +7,7 @@ use crate::geometry::URect;
 
 /// A bounding rectangle with floating point coordinates.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pos: Vec2,
     size: Vec2,
@@ -7,6 +7,7 @@ use super::Rect;
 
 /// A bounding rectangle with positive integer coordinates.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct URect {
     pos: UVec2,
     size: UVec2,
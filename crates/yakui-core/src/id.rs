@@ -17,6 +17,19 @@ impl WidgetId {
     pub(crate) fn index(&self) -> Index {
         self.0
     }
+
+    /// Converts the ID into a number, like for sending it to another process.
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Converts a number created by [`WidgetId::to_bits`] back into an ID.
+    /// Returns `None` if the number can't be a valid ID.
+    #[inline]
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        Index::from_bits(bits).map(Self)
+    }
 }
 
 impl fmt::Debug for WidgetId {
@@ -25,6 +38,23 @@ impl fmt::Debug for WidgetId {
     }
 }
 
+/// Widget IDs are serialized as the number returned by [`WidgetId::to_bits`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for WidgetId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.to_bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WidgetId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u64::deserialize(deserializer)?;
+        Self::from_bits(bits)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid widget ID: {bits}")))
+    }
+}
+
 /// Identifies a texture that may be managed by yakui or handled by the user.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TextureId {
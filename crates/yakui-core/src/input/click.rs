@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use glam::Vec2;
+
+use super::MouseButton;
+
+/// Settings for detecting double-clicks and long presses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickSettings {
+    /// The longest time between two presses of a button for the second one to
+    /// continue a multi-click, like a double-click.
+    pub multi_click_time: Duration,
+
+    /// How far in logical pixels the cursor can move between presses of a
+    /// multi-click, or while a button is held for a long press.
+    pub click_distance: f32,
+
+    /// How long a button has to be held without moving for it to count as a
+    /// long press.
+    pub long_press_time: Duration,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        Self {
+            multi_click_time: Duration::from_millis(500),
+            click_distance: 4.0,
+            long_press_time: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    button: MouseButton,
+    position: Vec2,
+    time: Duration,
+}
+
+/// Counts consecutive clicks and watches held buttons for long presses.
+///
+/// Without a time source, every press is a single click and nothing is ever
+/// a long press.
+#[derive(Debug, Default)]
+pub(crate) struct ClickTracker {
+    pub settings: ClickSettings,
+
+    /// The most recent press and how many presses came before it in quick
+    /// succession, including itself.
+    last: Option<(Press, u32)>,
+
+    /// The held button that could still become a long press.
+    held: Option<Press>,
+}
+
+impl ClickTracker {
+    /// Records a button press and returns its click count.
+    pub fn press(&mut self, button: MouseButton, position: Vec2, time: Option<Duration>) -> u32 {
+        let Some(time) = time else {
+            self.last = None;
+            self.held = None;
+            return 1;
+        };
+
+        let count = match self.last {
+            Some((last, count))
+                if last.button == button
+                    && time.saturating_sub(last.time) <= self.settings.multi_click_time
+                    && last.position.distance(position) <= self.settings.click_distance =>
+            {
+                count + 1
+            }
+            _ => 1,
+        };
+
+        let press = Press {
+            button,
+            position,
+            time,
+        };
+        self.last = Some((press, count));
+        self.held = Some(press);
+
+        count
+    }
+
+    /// Records a button release and returns the click count of the press it
+    /// ends.
+    pub fn release(&mut self, button: MouseButton) -> u32 {
+        if self.held.is_some_and(|held| held.button == button) {
+            self.held = None;
+        }
+
+        match self.last {
+            Some((last, count)) if last.button == button => count,
+            _ => 1,
+        }
+    }
+
+    /// Cancels a pending long press if the cursor moved too far from where
+    /// the button was pressed.
+    pub fn moved(&mut self, position: Vec2) {
+        if self
+            .held
+            .is_some_and(|held| held.position.distance(position) > self.settings.click_distance)
+        {
+            self.held = None;
+        }
+    }
+
+    /// Forgets every press, like when the window loses focus.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.held = None;
+    }
+
+    /// Returns the held button and where it was pressed if it just became a
+    /// long press. Each press is only reported once.
+    pub fn long_press(&mut self, time: Option<Duration>) -> Option<(MouseButton, Vec2)> {
+        let held = self.held?;
+        if time?.saturating_sub(held.time) < self.settings.long_press_time {
+            return None;
+        }
+
+        self.held = None;
+        Some((held.button, held.position))
+    }
+}
@@ -1,5 +1,7 @@
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
 
 use glam::Vec2;
 use smallvec::SmallVec;
@@ -8,11 +10,14 @@ use crate::dom::{Dom, DomNode};
 use crate::event::{Event, EventInterest, EventResponse, WidgetEvent};
 use crate::id::WidgetId;
 use crate::layout::LayoutDom;
-use crate::navigation::{navigate, NavDirection};
+use crate::navigation::{is_within, navigate, NavDirection};
 use crate::widget::EventContext;
 
 use super::mouse::MouseButton;
-use super::{KeyCode, Modifiers};
+use super::{
+    is_modifier_key, ClickSettings, ClickTracker, KeyChord, KeyCode, Modifiers, ScrollDelta,
+    Shortcut, ShortcutMatch, ShortcutScope, Shortcuts,
+};
 
 /// Holds yakui's input state, like cursor position, hovered, and selected
 /// widgets.
@@ -38,6 +43,32 @@ pub struct InputState {
 
     /// If set, text input should be active.
     text_input_enabled: Cell<bool>,
+
+    /// The widget that captured the pointer, if any.
+    pointer_capture: Cell<Option<WidgetId>>,
+
+    /// A widget that lost pointer capture and hasn't been told yet.
+    pointer_capture_lost: Cell<Option<WidgetId>>,
+
+    /// Widgets that were sent a file hover event that hasn't been cancelled
+    /// or followed by a drop yet.
+    file_hovered: RefCell<Vec<WidgetId>>,
+
+    /// Keyboard shortcuts registered this frame.
+    shortcuts: RefCell<Shortcuts>,
+
+    /// The current time, if yakui has a time source.
+    time: Cell<Option<Duration>>,
+
+    /// Click counts and long presses of the mouse buttons.
+    clicks: RefCell<ClickTracker>,
+
+    /// Widgets that trapped focus this frame, outermost first.
+    focus_traps: RefCell<Vec<WidgetId>>,
+
+    /// The focus traps that were active as of the last finished frame, along
+    /// with the widget that was selected before each one started.
+    active_focus_traps: RefCell<Vec<(WidgetId, Option<WidgetId>)>>,
 }
 
 #[derive(Debug)]
@@ -120,21 +151,100 @@ impl InputState {
             last_selection: Cell::new(None),
             pending_navigation: Cell::new(None),
             text_input_enabled: Cell::new(false),
+            pointer_capture: Cell::new(None),
+            pointer_capture_lost: Cell::new(None),
+            file_hovered: RefCell::new(Vec::new()),
+            shortcuts: RefCell::new(Shortcuts::default()),
+            time: Cell::new(None),
+            clicks: RefCell::new(ClickTracker::default()),
+            focus_traps: RefCell::new(Vec::new()),
+            active_focus_traps: RefCell::new(Vec::new()),
         }
     }
 
     /// Begin a new frame for input handling.
     pub fn start(&self, dom: &Dom, layout: &LayoutDom) {
         self.text_input_enabled.set(false);
+        self.shortcuts.borrow_mut().clear_bindings();
+        self.focus_traps.borrow_mut().clear();
         self.notify_selection(dom, layout);
+        self.check_long_press(dom, layout);
+
+        if let Some(id) = self.pointer_capture.get() {
+            if dom.get(id).is_none() {
+                self.clear_pointer_capture();
+            }
+        }
     }
 
     /// Finish applying input events for this frame.
     pub fn finish(&self, dom: &Dom, layout: &LayoutDom) {
         self.settle_buttons();
+        self.sync_focus_traps(dom, layout);
         self.handle_navigation(dom, layout);
     }
 
+    /// Traps focus within the given widget for the current frame, like for a
+    /// modal dialog. Keyboard navigation can't leave the widget's subtree and
+    /// shortcuts registered outside of it are ignored.
+    ///
+    /// When a widget starts trapping focus, the selection is cleared unless it
+    /// is already inside of the widget. When it stops, the selection from
+    /// before is restored. Widgets must call this every frame, usually while
+    /// being laid out. If several widgets trap focus, the last one wins.
+    pub fn trap_focus(&self, id: WidgetId) {
+        self.focus_traps.borrow_mut().push(id);
+    }
+
+    /// Returns the widget that focus is currently trapped in, if any.
+    pub fn focus_trap(&self) -> Option<WidgetId> {
+        self.active_focus_traps.borrow().last().map(|(id, _)| *id)
+    }
+
+    fn sync_focus_traps(&self, dom: &Dom, layout: &LayoutDom) {
+        {
+            let registered = self.focus_traps.borrow();
+            let mut active = self.active_focus_traps.borrow_mut();
+
+            if active
+                .iter()
+                .map(|(id, _)| *id)
+                .eq(registered.iter().copied())
+            {
+                return;
+            }
+
+            // Traps that went away give the selection back, innermost first.
+            for &(id, previous) in active.iter().rev() {
+                if !registered.contains(&id) {
+                    self.selection
+                        .set(previous.filter(|&previous| dom.get(previous).is_some()));
+                }
+            }
+
+            let mut next = Vec::with_capacity(registered.len());
+            for &id in registered.iter() {
+                if let Some(&existing) = active.iter().find(|(active, _)| *active == id) {
+                    next.push(existing);
+                    continue;
+                }
+
+                let previous = self.selection.get();
+                if !previous.is_some_and(|selected| is_within(dom, id, selected)) {
+                    self.selection.set(None);
+                }
+
+                next.push((id, previous));
+            }
+
+            *active = next;
+        }
+
+        // Widgets that were hovered might be blocked now, or the other way
+        // around.
+        self.update_hover(dom, layout);
+    }
+
     fn handle_navigation(&self, dom: &Dom, layout: &LayoutDom) {
         if let Some(dir) = self.pending_navigation.take() {
             if let Some(new_focus) = navigate(dom, layout, self, dir) {
@@ -173,6 +283,73 @@ impl InputState {
         self.selection.set(id);
     }
 
+    /// Returns the widget that holds pointer capture, if any.
+    pub fn pointer_capture(&self) -> Option<WidgetId> {
+        self.pointer_capture.get()
+    }
+
+    /// Captures the pointer for the given widget, or releases capture if
+    /// `None`. While captured, every mouse move and button event goes to that
+    /// widget until all mouse buttons are released.
+    ///
+    /// Capturing has no effect if no mouse button is held down. If another
+    /// widget held capture, it is sent [`WidgetEvent::PointerCaptureLost`].
+    pub fn capture_pointer(&self, id: Option<WidgetId>) {
+        if id.is_some() && !self.any_button_down() {
+            return;
+        }
+
+        let previous = self.pointer_capture.replace(id);
+        if previous.is_some() && previous != id {
+            self.pointer_capture_lost.set(previous);
+        }
+    }
+
+    /// Releases pointer capture without notifying the widget that held it.
+    pub(crate) fn clear_pointer_capture(&self) {
+        self.pointer_capture.set(None);
+    }
+
+    fn any_button_down(&self) -> bool {
+        self.mouse
+            .borrow()
+            .buttons
+            .values()
+            .any(|state| state.is_down())
+    }
+
+    /// Registers a keyboard shortcut for the current frame. When it's pressed,
+    /// `widget` is sent [`WidgetEvent::ShortcutTriggered`] and the key event is
+    /// sunk.
+    ///
+    /// Shortcuts are cleared at the start of every frame, so widgets should
+    /// register them every frame, usually while being laid out.
+    pub fn register_shortcut(&self, widget: WidgetId, shortcut: Shortcut, scope: ShortcutScope) {
+        self.shortcuts
+            .borrow_mut()
+            .register(widget, shortcut, scope);
+    }
+
+    /// Returns the current time, or `None` if yakui has no time source. The
+    /// time is measured from an arbitrary point, so only differences between
+    /// times are meaningful.
+    pub fn time(&self) -> Option<Duration> {
+        self.time.get()
+    }
+
+    pub(crate) fn set_time(&self, time: Option<Duration>) {
+        self.time.set(time);
+    }
+
+    /// Returns the settings used to detect double-clicks and long presses.
+    pub fn click_settings(&self) -> ClickSettings {
+        self.clicks.borrow().settings
+    }
+
+    pub(crate) fn set_click_settings(&self, settings: ClickSettings) {
+        self.clicks.borrow_mut().settings = settings;
+    }
+
     /// Attempt to navigate in a direction within the UI.
     pub fn navigate(&self, dir: NavDirection) {
         self.pending_navigation.set(Some(dir));
@@ -209,15 +386,49 @@ impl InputState {
                 self.set_selection(*id);
                 EventResponse::Bubble
             }
+            Event::FileHovered { path, position } => {
+                if position.is_some() {
+                    self.mouse_moved(dom, layout, *position);
+                }
+
+                self.send_file_hovered(dom, layout, path)
+            }
+            Event::FileHoverCancelled => {
+                self.cancel_file_hover(dom, layout, &[]);
+                EventResponse::Bubble
+            }
+            Event::FileDropped { path, position } => {
+                if position.is_some() {
+                    self.mouse_moved(dom, layout, *position);
+                }
+
+                self.send_file_dropped(dom, layout, path)
+            }
+            Event::WindowFocusChanged(focused) => {
+                if !focused {
+                    self.window_focus_lost(dom, layout);
+                }
+
+                EventResponse::Bubble
+            }
             _ => EventResponse::Bubble,
         };
 
         // Any input events can change selection, notify of changes immediately
         self.notify_selection(dom, layout);
+        self.notify_pointer_capture_lost(dom, layout);
 
         res
     }
 
+    pub(crate) fn notify_pointer_capture_lost(&self, dom: &Dom, layout: &LayoutDom) {
+        if let Some(id) = self.pointer_capture_lost.take() {
+            if let Some(mut node) = dom.get_mut(id) {
+                self.fire_event(dom, layout, id, &mut node, &WidgetEvent::PointerCaptureLost);
+            }
+        }
+    }
+
     fn notify_selection(&self, dom: &Dom, layout: &LayoutDom) {
         let mut current = self.selection.get();
         let last = self.last_selection.get();
@@ -265,8 +476,16 @@ impl InputState {
             mouse.position = pos;
         }
 
+        if let Some(pos) = pos {
+            self.clicks.borrow_mut().moved(pos / layout.scale_factor());
+        }
+
         self.send_mouse_move(dom, layout);
-        self.mouse_hit_test(dom, layout);
+        self.update_hover(dom, layout);
+    }
+
+    fn update_hover(&self, dom: &Dom, layout: &LayoutDom) {
+        self.mouse_hit_test(layout);
         self.send_mouse_enter(dom, layout);
         self.send_mouse_leave(dom, layout);
     }
@@ -279,26 +498,89 @@ impl InputState {
         button: MouseButton,
         down: bool,
     ) -> EventResponse {
-        {
+        let click_count = {
             let mut mouse = self.mouse.borrow_mut();
+            let position = mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor();
             let state = mouse.buttons.entry(button).or_insert(ButtonState::Up);
+            let mut clicks = self.clicks.borrow_mut();
 
             match (state.is_down(), down) {
                 // If the state didn't actually change, leave the current value
                 // alone.
-                (true, true) | (false, false) => (),
+                (true, true) | (false, false) => 1,
 
                 (false, true) => {
                     *state = ButtonState::JustDown;
+                    clicks.press(button, position, self.time.get())
                 }
 
                 (true, false) => {
                     *state = ButtonState::JustUp;
+                    clicks.release(button)
                 }
             }
+        };
+
+        let response = self.send_button_change(dom, layout, button, down, click_count);
+
+        // Capture ends once every button is released. Widgets under the
+        // cursor may have changed while it was captured.
+        if self.pointer_capture.get().is_some() && !self.any_button_down() {
+            self.clear_pointer_capture();
+            self.update_hover(dom, layout);
         }
 
-        self.send_button_change(dom, layout, button, down)
+        response
+    }
+
+    /// Releases any held mouse buttons and modifier keys, since their release
+    /// events will go to whichever window has focus now.
+    fn window_focus_lost(&self, dom: &Dom, layout: &LayoutDom) {
+        // Widgets dragging something should cancel the drag instead of seeing
+        // it as a normal release.
+        if let Some(id) = self.pointer_capture.take() {
+            self.pointer_capture_lost.set(Some(id));
+            self.notify_pointer_capture_lost(dom, layout);
+        }
+
+        let (released, position) = {
+            let mut mouse = self.mouse.borrow_mut();
+            let position = mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor();
+
+            let released = mouse
+                .buttons
+                .iter_mut()
+                .filter(|(_, state)| state.is_down())
+                .map(|(&button, state)| {
+                    *state = ButtonState::JustUp;
+                    button
+                })
+                .collect::<SmallVec<[MouseButton; 4]>>();
+
+            (released, position)
+        };
+
+        self.modifiers.set(Modifiers::default());
+        self.shortcuts.borrow_mut().reset();
+        self.clicks.borrow_mut().reset();
+
+        // Every widget with mouse interest hears about the release as if it
+        // happened outside of it, so that nothing is treated as a click.
+        for button in released {
+            for (id, _interest) in layout.interest_mouse.iter() {
+                if let Some(mut node) = dom.get_mut(id) {
+                    let event = WidgetEvent::MouseButtonChanged {
+                        button,
+                        down: false,
+                        inside: false,
+                        position,
+                        click_count: 1,
+                        modifiers: Modifiers::default(),
+                    };
+                    self.fire_event(dom, layout, id, &mut node, &event);
+                }
+            }
+        }
     }
 
     fn keyboard_key_changed(
@@ -309,31 +591,95 @@ impl InputState {
         down: bool,
         modifiers: Option<Modifiers>,
     ) -> EventResponse {
+        let modifiers = modifiers.unwrap_or(self.modifiers.get());
         let selected = self.selection.get();
+
+        // The focused widget gets the first chance to handle the key. If it
+        // doesn't, shortcuts are resolved from the scopes containing it,
+        // innermost to outermost, then global shortcuts.
+        let chord = (down && !is_modifier_key(key)).then(|| KeyChord::new(modifiers, key));
+        let mut scopes = focus_scopes(dom, selected);
+        scopes.push(ShortcutScope::Global);
+
+        if let Some(chord) = chord {
+            // A sequence that was started is finished before anything else
+            // gets a chance to see the key. If the key doesn't continue it,
+            // the key is handled as if the sequence never started.
+            if self.shortcuts.borrow().has_pending() {
+                if let Some(response) = self.press_shortcut(dom, layout, &scopes, chord) {
+                    return response;
+                }
+
+                self.shortcuts.borrow_mut().reset();
+            }
+        }
+
         if let Some(id) = selected {
-            let Some(layout_node) = layout.get(id) else {
-                return EventResponse::Bubble;
-            };
+            if let Some(layout_node) = layout.get(id) {
+                if layout_node
+                    .event_interest
+                    .contains(EventInterest::FOCUSED_KEYBOARD)
+                {
+                    // Panic safety: if this node is in the layout DOM, it must
+                    // be in the DOM.
+                    let mut node = dom.get_mut(id).unwrap();
+                    let event = WidgetEvent::KeyChanged {
+                        key,
+                        down,
+                        modifiers,
+                    };
+                    let response = self.fire_event(dom, layout, id, &mut node, &event);
 
-            if layout_node
-                .event_interest
-                .contains(EventInterest::FOCUSED_KEYBOARD)
-            {
-                // Panic safety: if this node is in the layout DOM, it must be
-                // in the DOM.
-                let mut node = dom.get_mut(id).unwrap();
-                let event = WidgetEvent::KeyChanged {
-                    key,
-                    down,
-                    modifiers: modifiers.unwrap_or(self.modifiers.get()),
-                };
-                return self.fire_event(dom, layout, id, &mut node, &event);
+                    if response == EventResponse::Sink {
+                        return response;
+                    }
+                }
+            }
+        }
+
+        if let Some(chord) = chord {
+            if let Some(response) = self.press_shortcut(dom, layout, &scopes, chord) {
+                return response;
             }
         }
 
         EventResponse::Bubble
     }
 
+    /// Matches a key press against the shortcuts in each scope in order,
+    /// notifying the widget that registered the shortcut if one was completed.
+    /// Returns `None` if no shortcut starts with the keys pressed so far.
+    fn press_shortcut(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        scopes: &[ShortcutScope],
+        chord: KeyChord,
+    ) -> Option<EventResponse> {
+        // Only shortcuts inside of a focus trap are active while it is.
+        let trap = self.focus_trap();
+        let active = |id| trap.is_none_or(|trap| is_within(dom, trap, id));
+
+        for &scope in scopes {
+            let result = self.shortcuts.borrow_mut().press(scope, chord, active);
+
+            match result {
+                ShortcutMatch::Triggered(id, shortcut) => {
+                    if let Some(mut node) = dom.get_mut(id) {
+                        let event = WidgetEvent::ShortcutTriggered(shortcut);
+                        self.fire_event(dom, layout, id, &mut node, &event);
+                    }
+
+                    return Some(EventResponse::Sink);
+                }
+                ShortcutMatch::Pending => return Some(EventResponse::Sink),
+                ShortcutMatch::None => {}
+            }
+        }
+
+        None
+    }
+
     fn modifiers_changed(&self, modifiers: &Modifiers) -> EventResponse {
         self.modifiers.set(*modifiers);
         EventResponse::Bubble
@@ -367,11 +713,28 @@ impl InputState {
         layout: &LayoutDom,
         button: MouseButton,
         down: bool,
+        click_count: u32,
     ) -> EventResponse {
         let mouse = self.mouse.borrow();
         let intersections = self.intersections.borrow();
         let mut overall_response = EventResponse::Bubble;
 
+        if let Some(id) = self.pointer_capture.get() {
+            if let Some(mut node) = dom.get_mut(id) {
+                let event = WidgetEvent::MouseButtonChanged {
+                    button,
+                    down,
+                    inside: intersections.mouse_hit.contains(&id),
+                    position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                    click_count,
+                    modifiers: self.modifiers.get(),
+                };
+                self.fire_event(dom, layout, id, &mut node, &event);
+            }
+
+            return EventResponse::Sink;
+        }
+
         for &id in &intersections.mouse_hit {
             if let Some(mut node) = dom.get_mut(id) {
                 let event = WidgetEvent::MouseButtonChanged {
@@ -379,6 +742,7 @@ impl InputState {
                     down,
                     inside: true,
                     position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                    click_count,
                     modifiers: self.modifiers.get(),
                 };
                 let response = self.fire_event(dom, layout, id, &mut node, &event);
@@ -400,6 +764,7 @@ impl InputState {
                         down,
                         inside: false,
                         position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                        click_count,
                         modifiers: self.modifiers.get(),
                     };
                     self.fire_event(dom, layout, id, &mut node, &event);
@@ -410,7 +775,17 @@ impl InputState {
         overall_response
     }
 
-    fn send_mouse_scroll(&self, dom: &Dom, layout: &LayoutDom, delta: Vec2) -> EventResponse {
+    fn send_mouse_scroll(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        delta: ScrollDelta,
+    ) -> EventResponse {
+        let delta = match delta {
+            ScrollDelta::Pixels(pixels) => ScrollDelta::Pixels(pixels / layout.scale_factor()),
+            lines => lines,
+        };
+
         let intersections = self.intersections.borrow();
 
         let mut overall_response = EventResponse::Bubble;
@@ -433,11 +808,119 @@ impl InputState {
         overall_response
     }
 
+    fn send_file_hovered(&self, dom: &Dom, layout: &LayoutDom, path: &Path) -> EventResponse {
+        let event = WidgetEvent::FileHovered {
+            path: path.to_owned(),
+            position: self.event_position(layout),
+        };
+        let (response, received) = self.send_to_hit_widgets(dom, layout, &event);
+
+        let mut hovered = self.file_hovered.borrow_mut();
+        for id in received {
+            if !hovered.contains(&id) {
+                hovered.push(id);
+            }
+        }
+
+        response
+    }
+
+    fn send_file_dropped(&self, dom: &Dom, layout: &LayoutDom, path: &Path) -> EventResponse {
+        let event = WidgetEvent::FileDropped {
+            path: path.to_owned(),
+            position: self.event_position(layout),
+        };
+        let (response, received) = self.send_to_hit_widgets(dom, layout, &event);
+
+        // Widgets that the file was hovering over but not dropped on need to
+        // know that the hover is over.
+        self.cancel_file_hover(dom, layout, &received);
+
+        response
+    }
+
+    fn cancel_file_hover(&self, dom: &Dom, layout: &LayoutDom, except: &[WidgetId]) {
+        let hovered = self.file_hovered.take();
+
+        for id in hovered {
+            if except.contains(&id) {
+                continue;
+            }
+
+            if let Some(mut node) = dom.get_mut(id) {
+                self.fire_event(dom, layout, id, &mut node, &WidgetEvent::FileHoverCancelled);
+            }
+        }
+    }
+
+    /// Sends a long press event if a held mouse button became one.
+    fn check_long_press(&self, dom: &Dom, layout: &LayoutDom) {
+        let long_press = self.clicks.borrow_mut().long_press(self.time.get());
+        let Some((button, position)) = long_press else {
+            return;
+        };
+
+        let event = WidgetEvent::MouseLongPress {
+            button,
+            position,
+            modifiers: self.modifiers.get(),
+        };
+
+        if let Some(id) = self.pointer_capture.get() {
+            if let Some(mut node) = dom.get_mut(id) {
+                self.fire_event(dom, layout, id, &mut node, &event);
+            }
+        } else {
+            self.send_to_hit_widgets(dom, layout, &event);
+        }
+    }
+
+    /// Sends an event to the widgets under the mouse cursor, deepest first,
+    /// until one of them sinks it. Returns which widgets received the event.
+    fn send_to_hit_widgets(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        event: &WidgetEvent,
+    ) -> (EventResponse, SmallVec<[WidgetId; 4]>) {
+        let intersections = self.intersections.borrow();
+
+        let mut overall_response = EventResponse::Bubble;
+        let mut received = SmallVec::new();
+
+        for &id in &intersections.mouse_hit {
+            if let Some(mut node) = dom.get_mut(id) {
+                received.push(id);
+                let response = self.fire_event(dom, layout, id, &mut node, event);
+
+                if response == EventResponse::Sink {
+                    overall_response = response;
+                    break;
+                }
+            }
+        }
+
+        (overall_response, received)
+    }
+
+    /// The mouse position in logical pixels to report in widget events.
+    fn event_position(&self, layout: &LayoutDom) -> Vec2 {
+        self.mouse.borrow().position.unwrap_or(Vec2::ZERO) / layout.scale_factor()
+    }
+
     fn send_mouse_move(&self, dom: &Dom, layout: &LayoutDom) {
         let mouse = self.mouse.borrow();
         let pos = mouse.position.map(|pos| pos / layout.scale_factor());
         let event = WidgetEvent::MouseMoved(pos);
 
+        if let Some(id) = self.pointer_capture.get() {
+            if let Some(mut node) = dom.get_mut(id) {
+                self.fire_event(dom, layout, id, &mut node, &event);
+            }
+
+            return;
+        }
+
         for (id, interest) in layout.interest_mouse.iter() {
             if interest.intersects(EventInterest::MOUSE_MOVE) {
                 if let Some(mut node) = dom.get_mut(id) {
@@ -497,7 +980,7 @@ impl InputState {
         }
     }
 
-    fn mouse_hit_test(&self, dom: &Dom, layout: &LayoutDom) {
+    fn mouse_hit_test(&self, layout: &LayoutDom) {
         let mut intersections = self.intersections.borrow_mut();
         let mouse = self.mouse.borrow();
 
@@ -505,7 +988,12 @@ impl InputState {
 
         if let Some(mut mouse_pos) = mouse.position {
             mouse_pos /= layout.scale_factor();
-            hit_test(dom, layout, mouse_pos, &mut intersections.mouse_hit);
+            layout.hit_test(mouse_pos, &mut intersections.mouse_hit);
+        }
+
+        // While the pointer is captured, no other widget can be hovered.
+        if let Some(captured) = self.pointer_capture.get() {
+            intersections.mouse_hit.retain(|&id| id == captured);
         }
     }
 
@@ -542,24 +1030,16 @@ impl InputState {
     }
 }
 
-/// Calculate the set of widgets that are under the given point, sorted by
-/// relative height, highest to lowest.
-#[profiling::function]
-fn hit_test(_dom: &Dom, layout: &LayoutDom, coords: Vec2, output: &mut Vec<WidgetId>) {
-    for (id, _interest) in layout.interest_mouse.iter() {
-        let Some(layout_node) = layout.get(id) else {
-            continue;
-        };
+/// Returns the shortcut scopes that contain the focused widget, innermost
+/// first.
+fn focus_scopes(dom: &Dom, selected: Option<WidgetId>) -> SmallVec<[ShortcutScope; 8]> {
+    let mut scopes = SmallVec::new();
+    let mut current = selected;
 
-        let mut rect = layout_node.rect;
-        let mut node = layout_node;
-        while let Some(parent) = node.clipped_by {
-            node = layout.get(parent).unwrap();
-            rect = rect.constrain(node.rect);
-        }
-
-        if rect.contains_point(coords) {
-            output.push(id);
-        }
+    while let Some(id) = current {
+        scopes.push(ShortcutScope::Focus(id));
+        current = dom.get(id).and_then(|node| node.parent);
     }
+
+    scopes
 }
@@ -1,12 +1,21 @@
 //! Defines how yakui responds to input and delegates it to widgets.
 
+mod click;
 mod input_state;
 mod mouse;
 mod mouse_interest;
+mod shortcut;
 
+pub(crate) use self::click::ClickTracker;
 pub(crate) use self::mouse_interest::*;
 
+pub use self::click::ClickSettings;
 pub use self::input_state::*;
 pub use self::mouse::*;
+pub use self::shortcut::{
+    primary_modifier, KeyChord, Platform, Shortcut, ShortcutParseError, ShortcutScope,
+};
+
+pub(crate) use self::shortcut::{is_modifier_key, ShortcutMatch, Shortcuts};
 
 pub use keyboard_types::{Code as KeyCode, Modifiers};
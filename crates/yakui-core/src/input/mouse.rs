@@ -1,5 +1,8 @@
+use glam::Vec2;
+
 /// A button on the mouse.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButton {
     /// The user's primary mouse button. This is usually the left mouse button.
     One,
@@ -10,4 +13,39 @@ pub enum MouseButton {
 
     /// The user's third mouse button. This is usually the middle mouse button.
     Three,
+
+    /// The side button usually used to go back, like in a browser.
+    Back,
+
+    /// The side button usually used to go forward, like in a browser.
+    Forward,
+
+    /// Any other button, identified by a number that depends on the platform.
+    Other(u16),
+}
+
+/// How far the user scrolled with a mouse wheel or trackpad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollDelta {
+    /// Scrolled by a number of lines, like from the notches of a mouse wheel.
+    Lines(Vec2),
+
+    /// Scrolled by an exact distance in pixels, like from a trackpad.
+    Pixels(Vec2),
+}
+
+impl ScrollDelta {
+    /// How many logical pixels a line of scrolling is worth, unless a widget
+    /// says otherwise. This matches Chrome on Windows.
+    pub const DEFAULT_LINE_HEIGHT: f32 = 100.0 / 3.0;
+
+    /// Converts the delta to pixels, treating each line as `line_height`
+    /// pixels.
+    pub fn to_pixels(self, line_height: f32) -> Vec2 {
+        match self {
+            Self::Lines(lines) => lines * line_height,
+            Self::Pixels(pixels) => pixels,
+        }
+    }
 }
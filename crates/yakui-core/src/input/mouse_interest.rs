@@ -7,6 +7,10 @@ use crate::WidgetId;
 pub(crate) struct MouseInterest {
     layers: Vec<Vec<(WidgetId, EventInterest)>>,
     layer_stack: Vec<(WidgetId, usize)>,
+
+    /// The index of the topmost layer that blocks mouse input to the layers
+    /// below it.
+    modal_floor: usize,
 }
 
 impl MouseInterest {
@@ -14,12 +18,14 @@ impl MouseInterest {
         Self {
             layers: Vec::new(),
             layer_stack: Vec::new(),
+            modal_floor: 0,
         }
     }
 
     pub fn clear(&mut self) {
         self.layers.clear();
         self.layer_stack.clear();
+        self.modal_floor = 0;
     }
 
     pub fn insert(&mut self, id: WidgetId, interest: EventInterest) {
@@ -33,7 +39,7 @@ impl MouseInterest {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (WidgetId, EventInterest)> + '_ {
-        self.layers
+        self.layers[self.modal_floor..]
             .iter()
             .rev()
             .flat_map(|layer| layer.iter().copied())
@@ -49,6 +55,13 @@ impl MouseInterest {
         self.layer_stack.push((id, layer_index));
     }
 
+    /// Hide every layer below the current one from hit testing.
+    pub fn block_layers_below(&mut self) {
+        if let Some((_, index)) = self.layer_stack.last() {
+            self.modal_floor = self.modal_floor.max(*index);
+        }
+    }
+
     pub fn pop_layer(&mut self) {
         let top = self.layer_stack.pop();
         debug_assert!(
@@ -0,0 +1,536 @@
+use std::fmt;
+use std::str::FromStr;
+
+use smallvec::SmallVec;
+
+use crate::id::WidgetId;
+
+use super::{KeyCode, Modifiers};
+
+/// The modifier keys that are part of a [`KeyChord`]. Other modifiers, like
+/// caps lock, are ignored when matching shortcuts.
+const CHORD_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::ALT)
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::META);
+
+/// Returns the modifier used for most shortcuts on the current platform:
+/// Command on macOS and Ctrl everywhere else.
+pub fn primary_modifier() -> Modifiers {
+    Platform::current().primary_modifier()
+}
+
+/// A platform that shortcuts can be displayed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Platform {
+    MacOs,
+    Windows,
+    Linux,
+}
+
+impl Platform {
+    /// The platform yakui was compiled for. Platforms other than macOS and
+    /// Windows are treated like Linux.
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+
+    /// The modifier used for most shortcuts on this platform.
+    pub fn primary_modifier(self) -> Modifiers {
+        match self {
+            Self::MacOs => Modifiers::META,
+            Self::Windows | Self::Linux => Modifiers::CONTROL,
+        }
+    }
+}
+
+/// A key pressed while holding an exact set of modifier keys, like Ctrl+S.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyChord {
+    /// The key that triggers the chord.
+    pub key: KeyCode,
+
+    /// The modifiers that must be held. Only Ctrl, Alt, Shift, and Meta are
+    /// considered.
+    pub modifiers: Modifiers,
+}
+
+impl KeyChord {
+    /// Create a chord from a set of modifiers and a key.
+    pub fn new(modifiers: Modifiers, key: KeyCode) -> Self {
+        Self {
+            key,
+            modifiers: modifiers & CHORD_MODIFIERS,
+        }
+    }
+
+    /// Create a chord for a key pressed without any modifiers.
+    pub fn key(key: KeyCode) -> Self {
+        Self::new(Modifiers::empty(), key)
+    }
+
+    /// Create a chord for a key pressed with the platform's primary modifier,
+    /// like Ctrl+C on Windows and Linux or Command+C on macOS.
+    pub fn primary(key: KeyCode) -> Self {
+        Self::new(primary_modifier(), key)
+    }
+
+    /// Add more modifiers to this chord.
+    pub fn with(self, modifiers: Modifiers) -> Self {
+        Self::new(self.modifiers | modifiers, self.key)
+    }
+
+    /// Tells whether a key press with the given modifiers triggers this chord.
+    pub fn matches(&self, key: KeyCode, modifiers: Modifiers) -> bool {
+        self.key == key && self.modifiers == modifiers & CHORD_MODIFIERS
+    }
+
+    /// Returns how this chord should be displayed to users of the given
+    /// platform, like `Ctrl+Shift+S` or `⇧⌘S`.
+    pub fn display_for(&self, platform: Platform) -> String {
+        let mut output = String::new();
+
+        if platform == Platform::MacOs {
+            let symbols = [
+                (Modifiers::CONTROL, "⌃"),
+                (Modifiers::ALT, "⌥"),
+                (Modifiers::SHIFT, "⇧"),
+                (Modifiers::META, "⌘"),
+            ];
+
+            for (modifier, symbol) in symbols {
+                if self.modifiers.contains(modifier) {
+                    output.push_str(symbol);
+                }
+            }
+        } else {
+            let meta = match platform {
+                Platform::Windows => "Win",
+                _ => "Super",
+            };
+            let names = [
+                (Modifiers::CONTROL, "Ctrl"),
+                (Modifiers::ALT, "Alt"),
+                (Modifiers::SHIFT, "Shift"),
+                (Modifiers::META, meta),
+            ];
+
+            for (modifier, name) in names {
+                if self.modifiers.contains(modifier) {
+                    output.push_str(name);
+                    output.push('+');
+                }
+            }
+        }
+
+        output.push_str(&key_name(self.key, platform));
+        output
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display_for(Platform::current()))
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ShortcutParseError;
+
+    /// Parses a chord like `Ctrl+Shift+S`. `Mod` stands for the platform's
+    /// primary modifier. Modifier names are case insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ShortcutParseError(s.to_owned());
+
+        // Split off the key first so that `Ctrl++` can't be confused with an
+        // empty modifier.
+        let (modifier_names, key) = match s.rsplit_once('+') {
+            Some((rest, "")) if rest.ends_with('+') => (&rest[..rest.len() - 1], "+"),
+            Some((rest, key)) => (rest, key),
+            None => ("", s),
+        };
+
+        let mut modifiers = Modifiers::empty();
+        if !modifier_names.is_empty() {
+            for name in modifier_names.split('+') {
+                modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => Modifiers::CONTROL,
+                    "alt" | "option" | "opt" => Modifiers::ALT,
+                    "shift" => Modifiers::SHIFT,
+                    "meta" | "cmd" | "command" | "super" | "win" => Modifiers::META,
+                    "mod" | "primary" => primary_modifier(),
+                    _ => return Err(error()),
+                };
+            }
+        }
+
+        let key = parse_key(key.trim()).ok_or_else(error)?;
+        Ok(Self::new(modifiers, key))
+    }
+}
+
+impl From<KeyCode> for KeyChord {
+    fn from(key: KeyCode) -> Self {
+        Self::key(key)
+    }
+}
+
+/// A keyboard shortcut: one chord, or a sequence of chords pressed one after
+/// another, like `Ctrl+K Ctrl+S`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shortcut {
+    chords: Vec<KeyChord>,
+}
+
+impl Shortcut {
+    /// Create a shortcut from a single chord.
+    pub fn new(chord: KeyChord) -> Self {
+        Self {
+            chords: vec![chord],
+        }
+    }
+
+    /// Create a shortcut that is triggered by pressing the given chords in
+    /// order.
+    ///
+    /// # Panics
+    /// Panics if `chords` is empty.
+    pub fn sequence<I: IntoIterator<Item = KeyChord>>(chords: I) -> Self {
+        let chords: Vec<_> = chords.into_iter().collect();
+        assert!(!chords.is_empty(), "A shortcut needs at least one chord");
+        Self { chords }
+    }
+
+    /// The chords that make up this shortcut.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.chords
+    }
+
+    /// Returns how this shortcut should be displayed to users of the given
+    /// platform, like `Ctrl+K Ctrl+S`.
+    pub fn display_for(&self, platform: Platform) -> String {
+        let chords: Vec<_> = self
+            .chords
+            .iter()
+            .map(|chord| chord.display_for(platform))
+            .collect();
+        chords.join(" ")
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display_for(Platform::current()))
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutParseError;
+
+    /// Parses a shortcut made of chords separated by spaces, like
+    /// `Mod+K Mod+S`. See [`KeyChord`]'s parser for the format of each chord.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .map(KeyChord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(ShortcutParseError(s.to_owned()));
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+impl TryFrom<&str> for Shortcut {
+    type Error = ShortcutParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<KeyChord> for Shortcut {
+    fn from(chord: KeyChord) -> Self {
+        Self::new(chord)
+    }
+}
+
+impl From<KeyCode> for Shortcut {
+    fn from(key: KeyCode) -> Self {
+        Self::new(KeyChord::key(key))
+    }
+}
+
+/// Returned when a string couldn't be parsed as a [`KeyChord`] or
+/// [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutParseError(String);
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid keyboard shortcut: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+/// Where a shortcut registered with
+/// [`InputState::register_shortcut`][super::InputState::register_shortcut]
+/// is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutScope {
+    /// Active no matter which widget is focused. Global shortcuts are checked
+    /// after the focused widget and every scoped shortcut.
+    Global,
+
+    /// Active while the given widget or one of its descendants is focused.
+    /// Scoped shortcuts are checked after the focused widget has had a chance
+    /// to handle the key, innermost scope first.
+    Focus(WidgetId),
+}
+
+/// Tells whether a key is only a modifier, which doesn't trigger or interrupt
+/// shortcuts on its own.
+pub(crate) fn is_modifier_key(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::MetaLeft
+            | KeyCode::MetaRight
+            | KeyCode::CapsLock
+            | KeyCode::NumLock
+            | KeyCode::Fn
+    )
+}
+
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        let key = match c.to_ascii_uppercase() {
+            'A'..='Z' => format!("Key{}", c.to_ascii_uppercase()),
+            '0'..='9' => format!("Digit{c}"),
+            '+' => return Some(KeyCode::Equal),
+            _ => {
+                return PUNCTUATION
+                    .iter()
+                    .find(|(_, symbol)| *symbol == c)
+                    .map(|(key, _)| *key)
+            }
+        };
+        return key.parse().ok();
+    }
+
+    let alias = match name.to_ascii_lowercase().as_str() {
+        "esc" => Some(KeyCode::Escape),
+        "del" => Some(KeyCode::Delete),
+        "ins" => Some(KeyCode::Insert),
+        "up" => Some(KeyCode::ArrowUp),
+        "down" => Some(KeyCode::ArrowDown),
+        "left" => Some(KeyCode::ArrowLeft),
+        "right" => Some(KeyCode::ArrowRight),
+        "pgup" => Some(KeyCode::PageUp),
+        "pgdn" => Some(KeyCode::PageDown),
+        "return" => Some(KeyCode::Enter),
+        "plus" => Some(KeyCode::Equal),
+        _ => None,
+    };
+
+    alias.or_else(|| name.parse().ok())
+}
+
+const PUNCTUATION: [(KeyCode, char); 11] = [
+    (KeyCode::Comma, ','),
+    (KeyCode::Period, '.'),
+    (KeyCode::Slash, '/'),
+    (KeyCode::Backslash, '\\'),
+    (KeyCode::Semicolon, ';'),
+    (KeyCode::Quote, '\''),
+    (KeyCode::BracketLeft, '['),
+    (KeyCode::BracketRight, ']'),
+    (KeyCode::Minus, '-'),
+    (KeyCode::Equal, '='),
+    (KeyCode::Backquote, '`'),
+];
+
+fn key_name(key: KeyCode, platform: Platform) -> String {
+    let mac = platform == Platform::MacOs;
+
+    let name = match key {
+        KeyCode::ArrowUp if mac => "↑",
+        KeyCode::ArrowDown if mac => "↓",
+        KeyCode::ArrowLeft if mac => "←",
+        KeyCode::ArrowRight if mac => "→",
+        KeyCode::Enter if mac => "↩",
+        KeyCode::Backspace if mac => "⌫",
+        KeyCode::Delete if mac => "⌦",
+        KeyCode::Escape if mac => "⎋",
+        KeyCode::Tab if mac => "⇥",
+        KeyCode::ArrowUp => "Up",
+        KeyCode::ArrowDown => "Down",
+        KeyCode::ArrowLeft => "Left",
+        KeyCode::ArrowRight => "Right",
+        KeyCode::Escape => "Esc",
+        KeyCode::Delete => "Del",
+        KeyCode::PageUp => "PgUp",
+        KeyCode::PageDown => "PgDn",
+        _ => {
+            if let Some((_, symbol)) = PUNCTUATION.iter().find(|(code, _)| *code == key) {
+                return symbol.to_string();
+            }
+
+            let name = key.to_string();
+            if let Some(rest) = name.strip_prefix("Key").or(name.strip_prefix("Digit")) {
+                return rest.to_owned();
+            }
+            return name;
+        }
+    };
+
+    name.to_owned()
+}
+
+/// A shortcut that a widget registered for the current frame.
+#[derive(Debug)]
+struct Binding {
+    widget: WidgetId,
+    shortcut: Shortcut,
+    scope: ShortcutScope,
+}
+
+/// What pressing a key did to a set of shortcuts.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ShortcutMatch {
+    /// The key finished a shortcut registered by this widget.
+    Triggered(WidgetId, Shortcut),
+
+    /// The key continued a sequence that isn't finished yet.
+    Pending,
+
+    /// No shortcut starts with the keys pressed so far.
+    None,
+}
+
+/// Holds the shortcuts registered this frame and the chords pressed so far
+/// toward a sequence.
+#[derive(Debug, Default)]
+pub(crate) struct Shortcuts {
+    bindings: Vec<Binding>,
+    pending: SmallVec<[KeyChord; 2]>,
+}
+
+impl Shortcuts {
+    pub fn clear_bindings(&mut self) {
+        self.bindings.clear();
+    }
+
+    pub fn register(&mut self, widget: WidgetId, shortcut: Shortcut, scope: ShortcutScope) {
+        self.bindings.push(Binding {
+            widget,
+            shortcut,
+            scope,
+        });
+    }
+
+    /// Forget any sequence in progress.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Whether some chords of a sequence have been pressed already.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Matches the pending chords followed by `chord` against the shortcuts
+    /// in the given scope that were registered by widgets that are `active`. A
+    /// complete match wins over a sequence that could continue, since there is
+    /// no timeout to wait for more keys.
+    pub fn press(
+        &mut self,
+        scope: ShortcutScope,
+        chord: KeyChord,
+        active: impl Fn(WidgetId) -> bool,
+    ) -> ShortcutMatch {
+        let mut keys = self.pending.clone();
+        keys.push(chord);
+
+        let mut pending = false;
+
+        for binding in &self.bindings {
+            if binding.scope != scope || !active(binding.widget) {
+                continue;
+            }
+
+            let chords = binding.shortcut.chords();
+            if chords == keys.as_slice() {
+                self.pending.clear();
+                return ShortcutMatch::Triggered(binding.widget, binding.shortcut.clone());
+            }
+
+            if chords.starts_with(&keys) {
+                pending = true;
+            }
+        }
+
+        if pending {
+            self.pending = keys;
+            ShortcutMatch::Pending
+        } else {
+            ShortcutMatch::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcuts_parse_and_display_per_platform() {
+        let chord: KeyChord = "ctrl+Shift+S".parse().unwrap();
+        assert_eq!(
+            chord,
+            KeyChord::new(Modifiers::CONTROL | Modifiers::SHIFT, KeyCode::KeyS)
+        );
+        assert_eq!(chord.display_for(Platform::Windows), "Ctrl+Shift+S");
+        assert_eq!(chord.display_for(Platform::MacOs), "⌃⇧S");
+
+        let chord = KeyChord::new(Modifiers::META | Modifiers::ALT, KeyCode::ArrowUp);
+        assert_eq!(chord.display_for(Platform::Linux), "Alt+Super+Up");
+        assert_eq!(chord.display_for(Platform::MacOs), "⌥⌘↑");
+
+        let shortcut: Shortcut = "Ctrl+K Ctrl+/".parse().unwrap();
+        assert_eq!(shortcut.chords().len(), 2);
+        assert_eq!(shortcut.display_for(Platform::Linux), "Ctrl+K Ctrl+/");
+        assert_eq!("Ctrl++".parse::<KeyChord>().unwrap().key, KeyCode::Equal);
+        assert_eq!(
+            "F5".parse::<KeyChord>().unwrap(),
+            KeyChord::key(KeyCode::F5)
+        );
+
+        // Lock keys don't stop a chord from matching, but extra modifiers do.
+        let chord = KeyChord::new(Modifiers::CONTROL, KeyCode::KeyC);
+        assert!(chord.matches(KeyCode::KeyC, Modifiers::CONTROL | Modifiers::CAPS_LOCK));
+        assert!(!chord.matches(KeyCode::KeyC, Modifiers::CONTROL | Modifiers::SHIFT));
+
+        assert!("Hyper+S".parse::<KeyChord>().is_err());
+        assert!("Ctrl+Nope".parse::<KeyChord>().is_err());
+        assert!("".parse::<Shortcut>().is_err());
+    }
+}
@@ -11,7 +11,7 @@ use crate::geometry::{Constraints, Rect};
 use crate::id::WidgetId;
 use crate::input::{InputState, MouseInterest};
 use crate::paint::PaintDom;
-use crate::widget::LayoutContext;
+use crate::widget::{BaselineContext, LayoutContext};
 
 /// Contains information on how each widget in the DOM is laid out and what
 /// events they're interested in.
@@ -20,6 +20,10 @@ pub struct LayoutDom {
     nodes: Arena<LayoutDomNode>,
     clip_stack: Vec<WidgetId>,
 
+    /// Clip stacks set aside by widgets that escaped clipping, along with the
+    /// widget that did.
+    escaped_clips: Vec<(WidgetId, Vec<WidgetId>)>,
+
     unscaled_viewport: Rect,
     scale_factor: f32,
 
@@ -28,10 +32,14 @@ pub struct LayoutDom {
 
 /// A node in a [`LayoutDom`].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayoutDomNode {
     /// The bounding rectangle of the node in logical pixels.
     pub rect: Rect,
 
+    /// The constraints that the node was laid out with.
+    pub constraints: Constraints,
+
     /// This node will clip its descendants to its bounding rectangle.
     pub clipping_enabled: bool,
 
@@ -42,8 +50,15 @@ pub struct LayoutDomNode {
     /// This node is clipped to the region defined by the given node.
     pub clipped_by: Option<WidgetId>,
 
+    /// This node and its descendants ignore clipping from its ancestors.
+    pub escapes_clipping: bool,
+
     /// What events the widget reported interest in.
     pub event_interest: EventInterest,
+
+    /// The distance from the top of the node to the baseline of its first
+    /// line of text, if it has one.
+    pub baseline: Option<f32>,
 }
 
 impl LayoutDom {
@@ -52,6 +67,7 @@ impl LayoutDom {
         Self {
             nodes: Arena::new(),
             clip_stack: Vec::new(),
+            escaped_clips: Vec::new(),
 
             unscaled_viewport: Rect::ONE,
             scale_factor: 1.0,
@@ -120,6 +136,7 @@ impl LayoutDom {
         log::debug!("LayoutDom::calculate_all()");
 
         self.clip_stack.clear();
+        self.escaped_clips.clear();
         self.interest_mouse.clear();
 
         let constraints = Constraints::tight(self.viewport().size());
@@ -153,6 +170,12 @@ impl LayoutDom {
 
         let size = dom_node.widget.layout(context, constraints);
 
+        // The widget's children are all laid out and positioned by now, so
+        // it can tell where its baseline is.
+        let baseline = dom_node
+            .widget
+            .baseline(BaselineContext { dom, layout: self });
+
         // If the widget called new_layer() during layout, it will be on top of
         // the mouse interest layer stack.
         let new_layer = self.interest_mouse.current_layer_root() == Some(id);
@@ -182,20 +205,31 @@ impl LayoutDom {
             self.clip_stack.last().copied()
         };
 
+        // If the widget called escape_clipping() during layout, the clip stack
+        // it set aside is on top of the escaped stack.
+        let escapes_clipping = self.escaped_clips.last().map(|(escaped, _)| *escaped) == Some(id);
+
         self.nodes.insert_at(
             id.index(),
             LayoutDomNode {
                 rect: Rect::from_pos_size(Vec2::ZERO, size),
+                constraints,
                 clipping_enabled,
                 new_layer,
                 clipped_by,
+                escapes_clipping,
                 event_interest,
+                baseline,
             },
         );
 
         if clipping_enabled {
             self.clip_stack.pop();
         }
+        if escapes_clipping {
+            let (_, clip_stack) = self.escaped_clips.pop().unwrap();
+            self.clip_stack = clip_stack;
+        }
 
         dom.exit(id);
         size
@@ -206,11 +240,51 @@ impl LayoutDom {
         self.clip_stack.push(dom.current());
     }
 
+    /// Stops clipping of the currently active widget and its children by any
+    /// of its ancestors, like for a popup that should be drawn over a
+    /// scrolling container. Must be called before
+    /// [`enable_clipping`][Self::enable_clipping].
+    pub fn escape_clipping(&mut self, dom: &Dom) {
+        let clip_stack = std::mem::take(&mut self.clip_stack);
+        self.escaped_clips.push((dom.current(), clip_stack));
+    }
+
     /// Put this widget and its children into a new layer.
     pub fn new_layer(&mut self, dom: &Dom) {
         self.interest_mouse.push_layer(dom.current());
     }
 
+    /// Put this widget and its children into a new layer that blocks mouse
+    /// input to every layer below it, like for a modal dialog. Layers created
+    /// after this one still receive input.
+    pub fn new_modal_layer(&mut self, dom: &Dom) {
+        self.interest_mouse.push_layer(dom.current());
+        self.interest_mouse.block_layers_below();
+    }
+
+    /// Collects the widgets interested in mouse events that are under the
+    /// given point, sorted by relative height, highest to lowest. This takes
+    /// layers and clipping into account, but not pointer capture.
+    #[profiling::function]
+    pub fn hit_test(&self, coords: Vec2, output: &mut Vec<WidgetId>) {
+        for (id, _interest) in self.interest_mouse.iter() {
+            let Some(layout_node) = self.get(id) else {
+                continue;
+            };
+
+            let mut rect = layout_node.rect;
+            let mut node = layout_node;
+            while let Some(parent) = node.clipped_by {
+                node = self.get(parent).unwrap();
+                rect = rect.constrain(node.rect);
+            }
+
+            if rect.contains_point(coords) {
+                output.push(id);
+            }
+        }
+    }
+
     /// Set the position of a widget.
     pub fn set_pos(&mut self, id: WidgetId, pos: Vec2) {
         if let Some(node) = self.nodes.get_mut(id.index()) {
@@ -9,6 +9,7 @@
 mod id;
 mod response;
 mod state;
+mod stats;
 mod types;
 
 pub mod context;
@@ -19,9 +20,11 @@ pub mod input;
 pub mod layout;
 pub mod navigation;
 pub mod paint;
+pub mod recording;
 pub mod widget;
 
 pub use self::id::*;
 pub use self::response::*;
 pub use self::state::*;
+pub use self::stats::FrameStats;
 pub use self::types::*;
@@ -2,6 +2,7 @@
 //! gamepads.
 
 use crate::dom::Dom;
+use crate::event::EventInterest;
 use crate::input::InputState;
 use crate::layout::LayoutDom;
 use crate::widget::NavigateContext;
@@ -30,8 +31,17 @@ pub(crate) fn navigate(
     input: &InputState,
     dir: NavDirection,
 ) -> Option<WidgetId> {
+    let trap = input.focus_trap();
     let mut current = input.selection();
 
+    // Focus can't leave a focus trap, and navigating from outside of one
+    // enters it instead.
+    if let Some(trap) = trap {
+        if !current.is_some_and(|id| is_within(dom, trap, id)) {
+            return first_focusable(dom, layout, trap, dir);
+        }
+    }
+
     while let Some(id) = current {
         let node = dom.get(id).unwrap();
         let ctx = NavigateContext { dom, layout, input };
@@ -40,8 +50,60 @@ pub(crate) fn navigate(
             return Some(new_id);
         }
 
+        // Navigating past either end of a focus trap wraps around.
+        if Some(id) == trap {
+            return first_focusable(dom, layout, id, dir);
+        }
+
         current = node.parent;
     }
 
     None
 }
+
+/// Tells whether `id` is `root` or one of its descendants.
+pub(crate) fn is_within(dom: &Dom, root: WidgetId, id: WidgetId) -> bool {
+    let mut current = Some(id);
+
+    while let Some(id) = current {
+        if id == root {
+            return true;
+        }
+
+        current = dom.get(id).and_then(|node| node.parent);
+    }
+
+    false
+}
+
+/// Finds the first focusable widget within `root` in the order that
+/// [`NavDirection::Next`] visits them, or the last one for
+/// [`NavDirection::Previous`].
+fn first_focusable(
+    dom: &Dom,
+    layout: &LayoutDom,
+    root: WidgetId,
+    dir: NavDirection,
+) -> Option<WidgetId> {
+    let mut focusable = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(id) = stack.pop() {
+        let Some(node) = dom.get(id) else {
+            continue;
+        };
+
+        let interest = layout.get(id).map(|node| node.event_interest);
+        if interest.is_some_and(|interest| interest.contains(EventInterest::FOCUS)) {
+            focusable.push(id);
+        }
+
+        stack.extend(node.children.iter().rev().copied());
+    }
+
+    match dir {
+        NavDirection::Next => focusable.first().copied(),
+        NavDirection::Previous => focusable.last().copied(),
+        _ => None,
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::AddAssign;
+
+use glam::Vec2;
+
+use super::{PaintCall, Pipeline};
+
+/// How many batches back a call is allowed to move to join a compatible one.
+/// Keeps batching linear for layers with many calls that can't be merged.
+const MAX_LOOKBACK: usize = 64;
+
+/// Statistics about the draw calls produced by a paint pass, before and after
+/// they were batched together.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchStats {
+    /// The number of calls produced while painting widgets.
+    pub calls_before: usize,
+
+    /// The number of calls left after batching.
+    pub calls_after: usize,
+
+    /// The number of times the texture changed between consecutive calls
+    /// before batching.
+    pub texture_switches_before: usize,
+
+    /// The number of times the texture changed between consecutive calls
+    /// after batching.
+    pub texture_switches_after: usize,
+}
+
+impl AddAssign for BatchStats {
+    fn add_assign(&mut self, other: Self) {
+        self.calls_before += other.calls_before;
+        self.calls_after += other.calls_after;
+        self.texture_switches_before += other.texture_switches_before;
+        self.texture_switches_after += other.texture_switches_after;
+    }
+}
+
+struct Batch {
+    call: PaintCall,
+    min: Vec2,
+    max: Vec2,
+}
+
+impl Batch {
+    fn new(call: PaintCall) -> Self {
+        let (min, max) = bounds(&call);
+        Self { call, min, max }
+    }
+
+    /// Custom calls are drawn by the application, which may depend on the
+    /// order they happen in, so nothing is ever moved across them.
+    fn is_barrier(&self) -> bool {
+        matches!(self.call.pipeline, Pipeline::Custom(_))
+    }
+
+    fn can_merge(&self, call: &PaintCall) -> bool {
+        !self.is_barrier()
+            && !matches!(call.pipeline, Pipeline::Custom(_))
+            && self.call.texture == call.texture
+            && self.call.pipeline == call.pipeline
+            && self.call.clip == call.clip
+    }
+
+    fn overlaps(&self, min: Vec2, max: Vec2) -> bool {
+        self.min.cmplt(max).all() && min.cmplt(self.max).all()
+    }
+
+    fn merge(&mut self, call: PaintCall, min: Vec2, max: Vec2) {
+        let base = self.call.vertices.len() as u32;
+        self.call
+            .indices
+            .extend(call.indices.iter().map(|index| index + base));
+        self.call.vertices.extend(call.vertices);
+
+        self.min = self.min.min(min);
+        self.max = self.max.max(max);
+    }
+}
+
+/// Merges compatible calls in the list together, moving calls earlier when
+/// they don't overlap anything they would be moved under.
+///
+/// Calls are only merged if they share a texture, pipeline, and clip rect.
+/// Geometry inside of a merged call keeps its original order.
+pub fn batch_calls(calls: &mut Vec<PaintCall>) -> BatchStats {
+    let mut stats = BatchStats {
+        calls_before: calls.len(),
+        texture_switches_before: texture_switches(calls),
+        ..Default::default()
+    };
+
+    let mut batches: Vec<Batch> = Vec::with_capacity(calls.len());
+
+    'calls: for call in calls.drain(..) {
+        let (min, max) = bounds(&call);
+
+        for batch in batches.iter_mut().rev().take(MAX_LOOKBACK) {
+            if batch.can_merge(&call) {
+                batch.merge(call, min, max);
+                continue 'calls;
+            }
+
+            if batch.is_barrier() || batch.overlaps(min, max) {
+                break;
+            }
+        }
+
+        batches.push(Batch::new(call));
+    }
+
+    calls.extend(batches.into_iter().map(|batch| batch.call));
+
+    stats.calls_after = calls.len();
+    stats.texture_switches_after = texture_switches(calls);
+    stats
+}
+
+fn bounds(call: &PaintCall) -> (Vec2, Vec2) {
+    call.vertices.iter().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), vertex| (min.min(vertex.position), max.max(vertex.position)),
+    )
+}
+
+fn texture_switches(calls: &[PaintCall]) -> usize {
+    calls
+        .windows(2)
+        .filter(|pair| pair[0].texture != pair[1].texture)
+        .count()
+}
@@ -1,5 +1,6 @@
 use std::ops::Deref;
 
+use super::batch::{batch_calls, BatchStats};
 use super::PaintCall;
 
 /// Contains all of the draw calls for a single layer of the UI.
@@ -14,6 +15,38 @@ impl PaintLayer {
     pub fn new() -> Self {
         Self { calls: Vec::new() }
     }
+
+    /// Merge compatible calls in this layer, reordering calls that don't
+    /// overlap to reduce the number of draw calls and texture switches.
+    pub fn batch(&mut self) -> BatchStats {
+        batch_calls(&mut self.calls)
+    }
+}
+
+/// A set of draw calls that should be rendered to an offscreen target and then
+/// composited onto their layer as a single image.
+#[derive(Debug)]
+pub struct PaintGroup {
+    /// The draw calls that make up the contents of this group.
+    pub calls: Vec<PaintCall>,
+
+    /// The opacity that the group should be composited with.
+    pub opacity: f32,
+}
+
+impl PaintGroup {
+    /// Create a new, empty paint group.
+    pub fn new(opacity: f32) -> Self {
+        Self {
+            calls: Vec::new(),
+            opacity,
+        }
+    }
+
+    /// Merge compatible calls in this group, like [`PaintLayer::batch`].
+    pub fn batch(&mut self) -> BatchStats {
+        batch_calls(&mut self.calls)
+    }
 }
 
 /// Contains all of the paint layers that should be drawn, as well as
@@ -39,6 +72,15 @@ impl PaintLayers {
         self.layer_stack.clear();
     }
 
+    /// Batch the calls of every layer, returning the combined statistics.
+    pub fn batch(&mut self) -> BatchStats {
+        let mut stats = BatchStats::default();
+        for layer in &mut self.layers {
+            stats += layer.batch();
+        }
+        stats
+    }
+
     /// Returns a reference to the currently active layer, if there is one.
     pub fn current(&self) -> Option<&PaintLayer> {
         self.layer_stack
@@ -46,6 +88,11 @@ impl PaintLayers {
             .and_then(|index| self.layers.get(*index))
     }
 
+    /// Returns the index of the currently active layer, if there is one.
+    pub fn current_index(&self) -> Option<usize> {
+        self.layer_stack.last().copied()
+    }
+
     /// Returns mutable access to the currently active layer, if there is one.
     pub fn current_mut(&mut self) -> Option<&mut PaintLayer> {
         self.layer_stack
@@ -1,11 +1,13 @@
 //! Defines primitives for painting widgets, including the Paint DOM.
 
+mod batch;
 mod layers;
 mod paint_dom;
 mod primitives;
 mod rect;
 mod texture;
 
+pub use self::batch::BatchStats;
 pub use self::layers::*;
 pub use self::paint_dom::*;
 pub use self::primitives::*;
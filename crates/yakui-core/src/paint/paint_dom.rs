@@ -2,18 +2,19 @@ use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
-use glam::Vec2;
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use glam::{Vec2, Vec4};
+use parking_lot::RwLock;
 use thunderdome::Arena;
 
 use crate::dom::Dom;
 use crate::geometry::Rect;
-use crate::id::{ManagedTextureId, WidgetId};
+use crate::id::{ManagedTextureId, TextureId, WidgetId};
 use crate::layout::LayoutDom;
 use crate::paint::{PaintCall, Pipeline};
 use crate::widget::PaintContext;
 
-use super::layers::PaintLayers;
+use super::batch::BatchStats;
+use super::layers::{PaintGroup, PaintLayers};
 use super::primitives::{PaintMesh, Vertex};
 use super::texture::{Texture, TextureChange};
 
@@ -38,7 +39,37 @@ pub struct PaintDom {
     limits: Option<PaintLimits>,
 
     layers: PaintLayers,
+    groups: Vec<PaintGroup>,
     clip_stack: Vec<Rect>,
+    opacity_stack: Vec<Opacity>,
+    group_stack: Vec<ActiveGroup>,
+    batch_stats: BatchStats,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Opacity {
+    /// The alpha multiplier applied to vertices added to the current target.
+    vertex: f32,
+
+    /// The opacity that geometry will actually be displayed with, including
+    /// the opacity of any groups it is contained in.
+    total: f32,
+}
+
+impl Opacity {
+    const OPAQUE: Self = Self {
+        vertex: 1.0,
+        total: 1.0,
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ActiveGroup {
+    index: usize,
+
+    /// The layer that the group was started in. Only geometry from this layer
+    /// is captured by the group.
+    layer: Option<usize>,
 }
 
 /// Stores textures for one or more `PaintDom` instances.
@@ -46,6 +77,8 @@ pub struct PaintDom {
 pub struct Textures {
     storage: Arena<Texture>,
     texture_edits: HashMap<ManagedTextureId, TextureChange>,
+    versions: HashMap<ManagedTextureId, u64>,
+    next_version: u64,
 }
 
 impl Textures {
@@ -56,6 +89,7 @@ impl Textures {
 
         let id = ManagedTextureId::new(texture);
         self.texture_edits.insert(id, TextureChange::Added);
+        self.bump_version(id);
         id
     }
 
@@ -63,6 +97,7 @@ impl Textures {
     pub fn remove(&mut self, id: ManagedTextureId) {
         self.storage.remove(id.index());
         self.texture_edits.insert(id, TextureChange::Removed);
+        self.versions.remove(&id);
     }
 
     /// Retrieve a texture by its ID, if it exists.
@@ -78,6 +113,22 @@ impl Textures {
     /// Mark a texture as modified so that changes can be detected.
     pub fn mark_modified(&mut self, id: ManagedTextureId) {
         self.texture_edits.insert(id, TextureChange::Modified);
+        self.bump_version(id);
+    }
+
+    /// Returns a number that changes whenever the texture is added or
+    /// modified, or `None` if the texture doesn't exist.
+    ///
+    /// Edits are cleared whenever any `PaintDom` sharing this storage starts a
+    /// frame. Renderers drawing several forked `PaintDom`s should compare
+    /// versions instead of relying on [`Textures::edits`].
+    pub fn version(&self, id: ManagedTextureId) -> Option<u64> {
+        self.versions.get(&id).copied()
+    }
+
+    fn bump_version(&mut self, id: ManagedTextureId) {
+        self.next_version += 1;
+        self.versions.insert(id, self.next_version);
     }
 
     /// Returns an iterator over all textures known to the Paint DOM.
@@ -108,7 +159,11 @@ impl PaintDom {
             limits: None,
 
             layers: PaintLayers::new(),
+            groups: Vec::new(),
             clip_stack: Vec::new(),
+            opacity_stack: Vec::new(),
+            group_stack: Vec::new(),
+            batch_stats: BatchStats::default(),
         }
     }
 
@@ -134,6 +189,8 @@ impl PaintDom {
     pub fn start(&mut self) {
         self.textures_mut().texture_edits.clear();
         self.clip_stack.clear();
+        self.opacity_stack.clear();
+        self.group_stack.clear();
     }
 
     /// Returns the size of the surface that is being painted onto.
@@ -150,6 +207,38 @@ impl PaintDom {
         self.unscaled_viewport = viewport;
     }
 
+    /// Returns the number of physical pixels per logical pixel of the surface
+    /// being painted onto.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Rounds a position in logical pixels to the nearest edge between
+    /// physical pixels on the surface.
+    pub fn snap_to_pixel(&self, pos: Vec2) -> Vec2 {
+        let offset = self.unscaled_viewport.pos();
+        ((pos * self.scale_factor + offset).round() - offset) / self.scale_factor
+    }
+
+    /// Snaps the corners of a rectangle to physical pixels so that its edges
+    /// are crisp. Rectangles with a size keep at least one physical pixel of
+    /// it on each axis.
+    pub fn snap_rect(&self, rect: Rect) -> Rect {
+        let min = self.snap_to_pixel(rect.pos());
+        let mut max = self.snap_to_pixel(rect.max());
+        let pixel = 1.0 / self.scale_factor;
+
+        if rect.size().x > 0.0 {
+            max.x = max.x.max(min.x + pixel);
+        }
+
+        if rect.size().y > 0.0 {
+            max.y = max.y.max(min.y + pixel);
+        }
+
+        Rect::from_pos_size(min, max - min)
+    }
+
     pub(crate) fn set_scale_factor(&mut self, scale_factor: f32) {
         self.scale_factor = scale_factor;
     }
@@ -162,11 +251,22 @@ impl PaintDom {
         profiling::scope!("PaintDom::paint");
 
         let layout_node = layout.get(id).unwrap();
+        let escaped_clips = layout_node
+            .escapes_clipping
+            .then(|| std::mem::take(&mut self.clip_stack));
         if layout_node.clipping_enabled {
             self.push_clip(layout_node.rect);
         }
         if layout_node.new_layer {
             self.layers.push();
+
+            // Groups only capture geometry from the layer they were started
+            // in, so anything escaping into a new layer is faded per-vertex.
+            let total = self.current_opacity().total;
+            self.opacity_stack.push(Opacity {
+                vertex: total,
+                total,
+            });
         }
 
         dom.enter(id);
@@ -184,8 +284,12 @@ impl PaintDom {
         if layout_node.clipping_enabled {
             self.pop_clip();
         }
+        if let Some(clip_stack) = escaped_clips {
+            self.clip_stack = clip_stack;
+        }
         if layout_node.new_layer {
             self.layers.pop();
+            self.opacity_stack.pop();
         }
     }
 
@@ -195,7 +299,26 @@ impl PaintDom {
         log::debug!("PaintDom:paint_all()");
 
         self.layers.clear();
+        self.groups.clear();
         self.paint(dom, layout, dom.root());
+        self.batch();
+    }
+
+    /// Merge compatible calls in all layers and groups.
+    fn batch(&mut self) {
+        profiling::scope!("PaintDom::batch");
+
+        let mut stats = self.layers.batch();
+        for group in &mut self.groups {
+            stats += group.batch();
+        }
+        self.batch_stats = stats;
+    }
+
+    /// Returns statistics about how many draw calls were saved by batching in
+    /// the most recent paint pass.
+    pub fn batch_stats(&self) -> BatchStats {
+        self.batch_stats
     }
 
     /// Add a texture to the Paint DOM, returning an ID that can be used to
@@ -229,26 +352,190 @@ impl PaintDom {
         &self.layers
     }
 
+    /// Returns the list of offscreen groups referenced by calls using
+    /// [`Pipeline::Group`].
+    pub fn groups(&self) -> &[PaintGroup] {
+        &self.groups
+    }
+
+    /// Returns the opacity that geometry added right now will be displayed
+    /// with.
+    pub fn opacity(&self) -> f32 {
+        self.current_opacity().total
+    }
+
+    /// Multiply the alpha of all following geometry by the given opacity.
+    ///
+    /// Overlapping geometry is faded individually, so it can show through
+    /// itself. Use [`PaintDom::push_group`] to fade everything as a single
+    /// image.
+    pub fn push_opacity(&mut self, opacity: f32) {
+        let current = self.current_opacity();
+        self.opacity_stack.push(Opacity {
+            vertex: current.vertex * opacity,
+            total: current.total * opacity,
+        });
+    }
+
+    /// Pop the most recent opacity, restoring the previous one.
+    pub fn pop_opacity(&mut self) {
+        let top = self.opacity_stack.pop();
+        debug_assert!(
+            top.is_some(),
+            "cannot call pop_opacity without a corresponding push_opacity call"
+        );
+    }
+
+    /// Start an offscreen group. All following geometry in the current layer
+    /// is rendered to an offscreen target, which is composited with the given
+    /// opacity once the group is popped.
+    pub fn push_group(&mut self, opacity: f32) {
+        let index = self.groups.len();
+        self.groups.push(PaintGroup::new(opacity));
+        self.group_stack.push(ActiveGroup {
+            index,
+            layer: self.layers.current_index(),
+        });
+
+        let total = self.current_opacity().total * opacity;
+        self.opacity_stack.push(Opacity { vertex: 1.0, total });
+    }
+
+    /// Finish the most recent offscreen group, adding a call to composite it.
+    pub fn pop_group(&mut self) {
+        let top = self.group_stack.pop();
+        debug_assert!(
+            top.is_some(),
+            "cannot call pop_group without a corresponding push_group call"
+        );
+        self.opacity_stack.pop();
+
+        let Some(top) = top else {
+            return;
+        };
+
+        let group = &self.groups[top.index];
+        let mut positions = group
+            .calls
+            .iter()
+            .flat_map(|call| &call.vertices)
+            .map(|vertex| vertex.position);
+
+        let Some(first) = positions.next() else {
+            return;
+        };
+        let (min, max) = positions.fold((first, first), |(min, max), pos| {
+            (min.min(pos), max.max(pos))
+        });
+
+        let opacity = group.opacity * self.current_opacity().vertex;
+        let vertices = [
+            Vec2::new(min.x, min.y),
+            Vec2::new(min.x, max.y),
+            Vec2::new(max.x, max.y),
+            Vec2::new(max.x, min.y),
+        ]
+        .map(|pos| Vertex {
+            opacity,
+            ..Vertex::new(pos, pos, Vec4::ONE)
+        });
+
+        self.append(
+            None,
+            Pipeline::Group(top.index),
+            vertices,
+            [0, 1, 2, 3, 0, 2],
+        );
+    }
+
+    /// Add a region that the application will paint into itself, using the
+    /// custom paint hook of its renderer. The region is given in logical
+    /// pixels and respects the current layer, clip, and group.
+    ///
+    /// `handle` is opaque to yakui and is passed back to the renderer.
+    pub fn add_custom(&mut self, rect: Rect, handle: u64) {
+        let vertices = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 0.0),
+        ]
+        .map(|uv| Vertex::new(rect.pos() + rect.size() * uv, uv, Vec4::ONE));
+
+        let mut mesh = PaintMesh::new(vertices, [0, 1, 2, 3, 0, 2]);
+        mesh.pipeline = Pipeline::Custom(handle);
+        self.add_mesh(mesh);
+    }
+
     /// Add a mesh to be painted.
     pub fn add_mesh<V, I>(&mut self, mesh: PaintMesh<V, I>)
     where
         V: IntoIterator<Item = Vertex>,
-        I: IntoIterator<Item = u16>,
+        I: IntoIterator<Item = u32>,
     {
         profiling::scope!("PaintDom::add_mesh");
 
         let texture_id = mesh.texture.map(|(index, _rect)| index);
+        let pipeline = mesh.pipeline;
+        let scale_factor = self.scale_factor;
+        let offset = self.unscaled_viewport.pos();
+        let surface_size = self.surface_size;
+        let opacity = self.current_opacity().vertex;
+
+        let vertices = mesh.vertices.into_iter().map(|mut vertex| {
+            let mut pos = vertex.position * scale_factor;
+            pos += offset;
+
+            // Currently, we only round the vertices of geometry fed to the text
+            // pipeline because rounding all geometry causes hairline cracks in
+            // some geometry, like rounded rectangles.
+            //
+            // See: https://github.com/SecondHalfGames/yakui/issues/153
+            if pipeline == Pipeline::Text {
+                pos = pos.round();
+            }
+
+            pos /= surface_size;
+
+            vertex.position = pos;
+            vertex.opacity *= opacity;
+            vertex
+        });
 
-        let layer = self
-            .layers
-            .current_mut()
-            .expect("an active layer is required to call add_mesh");
+        self.append(texture_id, pipeline, vertices, mesh.indices);
+    }
 
+    /// Append already transformed geometry to the current layer or group,
+    /// merging it into the previous call if possible.
+    fn append<V, I>(
+        &mut self,
+        texture_id: Option<TextureId>,
+        pipeline: Pipeline,
+        vertices: V,
+        indices: I,
+    ) where
+        V: IntoIterator<Item = Vertex>,
+        I: IntoIterator<Item = u32>,
+    {
         let current_clip = self.clip_stack.last().copied();
-        let call = match layer.calls.last_mut() {
+        let layer_index = self.layers.current_index();
+
+        let calls = match self.group_stack.last() {
+            Some(group) if group.layer == layer_index => &mut self.groups[group.index].calls,
+            _ => {
+                &mut self
+                    .layers
+                    .current_mut()
+                    .expect("an active layer is required to call add_mesh")
+                    .calls
+            }
+        };
+
+        let call = match calls.last_mut() {
             Some(call)
-                if call.texture == texture_id
-                    && call.pipeline == mesh.pipeline
+                if !matches!(pipeline, Pipeline::Custom(_))
+                    && call.texture == texture_id
+                    && call.pipeline == pipeline
                     && call.clip == current_clip =>
             {
                 call
@@ -256,41 +543,27 @@ impl PaintDom {
             _ => {
                 let mut call = PaintCall::new();
                 call.texture = texture_id;
-                call.pipeline = mesh.pipeline;
+                call.pipeline = pipeline;
                 call.clip = current_clip;
 
-                layer.calls.push(call);
-                layer.calls.last_mut().unwrap()
+                calls.push(call);
+                calls.last_mut().unwrap()
             }
         };
 
-        let indices = mesh
-            .indices
-            .into_iter()
-            .map(|index| index + call.vertices.len() as u16);
-        call.indices.extend(indices);
-
-        let vertices = mesh.vertices.into_iter().map(|mut vertex| {
-            let mut pos = vertex.position * self.scale_factor;
-            pos += self.unscaled_viewport.pos();
-
-            // Currently, we only round the vertices of geometry fed to the text
-            // pipeline because rounding all geometry causes hairline cracks in
-            // some geometry, like rounded rectangles.
-            //
-            // See: https://github.com/SecondHalfGames/yakui/issues/153
-            if mesh.pipeline == Pipeline::Text {
-                pos = pos.round();
-            }
-
-            pos /= self.surface_size;
-
-            vertex.position = pos;
-            vertex
-        });
+        let base = call.vertices.len() as u32;
+        call.indices
+            .extend(indices.into_iter().map(|index| index + base));
         call.vertices.extend(vertices);
     }
 
+    fn current_opacity(&self) -> Opacity {
+        self.opacity_stack
+            .last()
+            .copied()
+            .unwrap_or(Opacity::OPAQUE)
+    }
+
     /// Use the given region as the clipping rect for all following paint calls.
     fn push_clip(&mut self, region: Rect) {
         let mut unscaled = Rect::from_pos_size(
@@ -14,7 +14,7 @@ pub struct PaintMesh<V, I> {
 impl<V, I> PaintMesh<V, I>
 where
     V: IntoIterator<Item = Vertex>,
-    I: IntoIterator<Item = u16>,
+    I: IntoIterator<Item = u32>,
 {
     /// Create a new `PaintMesh` with the default pipeline, no texture, and the
     /// given vertices and indices.
@@ -32,7 +32,7 @@ where
 #[allow(missing_docs)]
 pub struct PaintCall {
     pub vertices: Vec<Vertex>,
-    pub indices: Vec<u16>,
+    pub indices: Vec<u32>,
     pub texture: Option<TextureId>,
     pub pipeline: Pipeline,
     pub clip: Option<Rect>,
@@ -58,6 +58,12 @@ pub struct Vertex {
     pub position: Vec2,
     pub texcoord: Vec2,
     pub color: Vec4,
+
+    /// Multiplies the final alpha of the vertex, including any texture it
+    /// samples. This is kept apart from `color` so that it also applies to
+    /// glyphs from color fonts, which the text pipeline marks with a
+    /// transparent vertex color.
+    pub opacity: f32,
 }
 
 impl Vertex {
@@ -72,6 +78,7 @@ impl Vertex {
             position: position.into(),
             texcoord: texcoord.into(),
             color: color.into(),
+            opacity: 1.0,
         }
     }
 }
@@ -86,4 +93,22 @@ pub enum Pipeline {
 
     /// Pipeline for drawing text: vertices and a coverage glyph texture.
     Text,
+
+    /// Pipeline for compositing an offscreen group, identified by its index in
+    /// [`PaintDom::groups`][super::PaintDom::groups].
+    ///
+    /// Calls using this pipeline are quads covering the group's bounds. Their
+    /// texture coordinates are positions on the surface and their vertex
+    /// opacity is the opacity to composite the group with. Renderers that can't
+    /// render offscreen may draw the group's calls inline instead, multiplying
+    /// their vertex opacity by the same value.
+    Group(usize),
+
+    /// Pipeline for geometry drawn by the application itself, identified by an
+    /// opaque handle that is passed back to the renderer's custom paint hook.
+    ///
+    /// Calls using this pipeline are a single quad covering the region to
+    /// paint into, with texture coordinates from `(0, 0)` to `(1, 1)`. They are
+    /// never merged with other calls.
+    Custom(u64),
 }
@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::geometry::{Color, Rect};
+use crate::geometry::{Brush, Color, Rect};
 use crate::TextureId;
 
 use super::{PaintDom, PaintMesh, Pipeline, Vertex};
@@ -14,7 +14,7 @@ const RECT_POS: [[f32; 2]; 4] = [
 ];
 
 #[rustfmt::skip]
-const RECT_INDEX: [u16; 6] = [
+const RECT_INDEX: [u32; 6] = [
     0, 1, 2,
     3, 0, 2,
 ];
@@ -22,7 +22,9 @@ const RECT_INDEX: [u16; 6] = [
 #[allow(missing_docs)]
 pub struct PaintRect {
     pub rect: Rect,
-    pub color: Color,
+
+    /// Fills the rectangle. Solid colors convert into brushes with `.into()`.
+    pub brush: Brush,
     pub texture: Option<(TextureId, Rect)>,
     pub pipeline: Pipeline,
 }
@@ -37,17 +39,26 @@ impl PaintRect {
     pub fn new(rect: Rect) -> Self {
         Self {
             rect,
-            color: Color::WHITE,
+            brush: Color::WHITE.into(),
             texture: None,
             pipeline: Pipeline::Main,
         }
     }
 
     /// Add this rectangle to the PaintDom to be drawn this frame.
+    ///
+    /// The edges of the rectangle are snapped to physical pixels so that they
+    /// stay crisp.
     pub fn add(&self, output: &mut PaintDom) {
-        let size = self.rect.size();
-        let pos = self.rect.pos();
-        let color = self.color.to_linear();
+        let rect = output.snap_rect(self.rect);
+
+        if self.brush.as_solid().is_none() {
+            return self.add_gradient(output, rect);
+        }
+
+        let size = rect.size();
+        let pos = rect.pos();
+        let color = self.brush.color_at(rect, pos);
         let texture_rect = match self.texture {
             Some((_index, rect)) => rect,
             None => Rect::from_pos_size(Vec2::ZERO, Vec2::ONE),
@@ -67,4 +78,52 @@ impl PaintRect {
 
         output.add_mesh(mesh);
     }
+
+    fn add_gradient(&self, output: &mut PaintDom, rect: Rect) {
+        let brush = &self.brush;
+        let texture_rect = match self.texture {
+            Some((_index, rect)) => rect,
+            None => Rect::from_pos_size(Vec2::ZERO, Vec2::ONE),
+        };
+
+        let (xs, ys) = brush.grid_lines(rect, rect);
+
+        let mut vertices = Vec::with_capacity(xs.len() * ys.len());
+        for &y in &ys {
+            for &x in &xs {
+                let pos = Vec2::new(x, y);
+                let relative = (pos - rect.pos()) / rect.size();
+
+                vertices.push(Vertex::new(
+                    pos,
+                    relative * texture_rect.size() + texture_rect.pos(),
+                    brush.color_at(rect, pos),
+                ));
+            }
+        }
+
+        let columns = xs.len() as u32;
+        let mut indices = Vec::with_capacity((xs.len() - 1) * (ys.len() - 1) * 6);
+        for row in 0..ys.len() as u32 - 1 {
+            for column in 0..columns - 1 {
+                let top_left = row * columns + column;
+                let bottom_left = top_left + columns;
+
+                indices.extend_from_slice(&[
+                    top_left,
+                    bottom_left,
+                    bottom_left + 1,
+                    top_left + 1,
+                    top_left,
+                    bottom_left + 1,
+                ]);
+            }
+        }
+
+        let mut mesh = PaintMesh::new(vertices, indices);
+        mesh.texture = self.texture;
+        mesh.pipeline = self.pipeline;
+
+        output.add_mesh(mesh);
+    }
 }
@@ -0,0 +1,164 @@
+//! Record the input given to yakui so that it can be replayed later, like for
+//! reproducing a bug without a window.
+//!
+//! Start a recording with [`Yakui::start_recording`] before the first frame
+//! and stop it with [`Yakui::stop_recording`]. With the `serde` feature
+//! enabled, the resulting [`Recording`] can be saved in any format supported
+//! by serde:
+//!
+//! ```rust,ignore
+//! let recording = yak.stop_recording().unwrap();
+//! let file = std::fs::File::create("bug.json")?;
+//! serde_json::to_writer(file, &recording)?;
+//! ```
+//!
+//! Replaying a recording into a fresh [`Yakui`] that builds the same UI
+//! reproduces every frame:
+//!
+//! ```rust
+//! # use yakui_core::{event::Event, geometry::Vec2, Yakui};
+//! # fn build_ui() {}
+//! let mut yak = Yakui::new();
+//! yak.start_recording();
+//!
+//! yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+//! yak.start();
+//! build_ui();
+//! yak.finish();
+//!
+//! let recording = yak.stop_recording().unwrap();
+//! assert_eq!(recording.frames.len(), 1);
+//!
+//! let mut replayed = Yakui::new();
+//! recording.replay(&mut replayed, build_ui);
+//! ```
+
+use std::time::Duration;
+
+use crate::event::Event;
+use crate::geometry::{Rect, Vec2};
+use crate::Yakui;
+
+/// The input given to yakui over a number of frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recording {
+    /// Every frame that was started while recording, in order.
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// The input given to yakui before a single frame was started.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedFrame {
+    /// The events passed to [`Yakui::handle_event`] since the previous frame
+    /// was started.
+    pub events: Vec<RecordedEvent>,
+
+    /// The size of the surface when the frame was started.
+    pub surface_size: Vec2,
+
+    /// The unscaled viewport when the frame was started.
+    pub viewport: Rect,
+
+    /// The scale factor when the frame was started.
+    pub scale_factor: f32,
+
+    /// The time when the frame was started, if yakui had a time source.
+    pub time: Option<Duration>,
+}
+
+/// An event given to yakui while recording.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent {
+    /// The event itself.
+    pub event: Event,
+
+    /// The time when the event was handled, if yakui had a time source.
+    pub time: Option<Duration>,
+}
+
+impl Recording {
+    /// Replays every frame of the recording into the given [`Yakui`], calling
+    /// `build` to build the UI of each frame. Every frame is painted after it
+    /// is laid out, like it would be by a renderer.
+    ///
+    /// For the replay to be faithful, `yak` should be in the same state that
+    /// the recorded one was in when the recording started, which usually means
+    /// a new `Yakui`.
+    pub fn replay(&self, yak: &mut Yakui, mut build: impl FnMut()) {
+        for frame in &self.frames {
+            frame.apply(yak);
+
+            yak.start();
+            build();
+            yak.finish();
+            yak.paint();
+        }
+    }
+}
+
+impl RecordedFrame {
+    /// Sends the recorded events to the given [`Yakui`] and restores the
+    /// surface size, viewport, scale factor, and time. After this, the frame
+    /// is ready to be started.
+    ///
+    /// Recorded times replace the time source of `yak`, so that timed input
+    /// like double-clicks is reproduced exactly.
+    pub fn apply(&self, yak: &mut Yakui) {
+        for recorded in &self.events {
+            if let Some(time) = recorded.time {
+                yak.set_time(time);
+            }
+
+            yak.handle_event(recorded.event.clone());
+        }
+
+        yak.set_surface_size(self.surface_size);
+        yak.set_unscaled_viewport(self.viewport);
+        yak.set_scale_factor(self.scale_factor);
+
+        if let Some(time) = self.time {
+            yak.set_time(time);
+        }
+    }
+}
+
+/// Collects input while a recording is in progress.
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    recording: Recording,
+    events: Vec<RecordedEvent>,
+}
+
+impl Recorder {
+    pub fn record_event(&mut self, event: &Event, time: Option<Duration>) {
+        self.events.push(RecordedEvent {
+            event: event.clone(),
+            time,
+        });
+    }
+
+    pub fn record_frame(
+        &mut self,
+        surface_size: Vec2,
+        viewport: Rect,
+        scale_factor: f32,
+        time: Option<Duration>,
+    ) {
+        self.recording.frames.push(RecordedFrame {
+            events: std::mem::take(&mut self.events),
+            surface_size,
+            viewport,
+            scale_factor,
+            time,
+        });
+    }
+
+    /// Finishes the recording. Events that arrived after the last frame was
+    /// started are dropped, since no frame saw them.
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use crate::dom::Dom;
 use crate::event::{Event, EventResponse};
 use crate::geometry::{Rect, Vec2};
 use crate::id::ManagedTextureId;
-use crate::input::InputState;
+use crate::input::{ClickSettings, InputState};
 use crate::layout::LayoutDom;
 use crate::paint::{PaintDom, PaintLimits, Texture};
+use crate::recording::{Recorder, Recording};
+use crate::stats::{FrameStats, Timer};
 use crate::{context, WidgetId};
 
 /// The entrypoint for yakui.
@@ -14,6 +18,12 @@ pub struct Yakui {
     layout: LayoutDom,
     paint: PaintDom,
     input: InputState,
+    stats: FrameStats,
+    recorder: Option<Recorder>,
+
+    /// Measures time automatically until the application sets it with
+    /// [`Yakui::set_time`].
+    clock: Option<Timer>,
 }
 
 impl Yakui {
@@ -25,6 +35,9 @@ impl Yakui {
             layout: LayoutDom::new(),
             paint: PaintDom::new(),
             input: InputState::new(),
+            stats: FrameStats::default(),
+            recorder: None,
+            clock: Some(Timer::start()),
         }
     }
 
@@ -41,6 +54,11 @@ impl Yakui {
     pub fn handle_event(&mut self, event: Event) -> bool {
         log::debug!("State::handle_event({event:?})");
 
+        self.tick_clock();
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_event(&event, self.input.time());
+        }
+
         context::bind_dom(&self.dom);
 
         let response = self.input.handle_event(&self.dom, &self.layout, &event);
@@ -53,6 +71,69 @@ impl Yakui {
         response == EventResponse::Sink
     }
 
+    /// Moves the cursor to a UV coordinate on the surface yakui is rendered
+    /// onto, like the point where a ray hit an in-world screen showing this UI.
+    ///
+    /// UV coordinates go from `(0, 0)` in the top left to `(1, 1)` in the
+    /// bottom right of the surface. Passing `None` or a coordinate outside of
+    /// that range means that the cursor left the surface.
+    ///
+    /// Returns `true` if the event was sunk by yakui.
+    pub fn handle_cursor_uv(&mut self, uv: Option<Vec2>) -> bool {
+        let pos = uv
+            .filter(|uv| uv.cmpge(Vec2::ZERO).all() && uv.cmple(Vec2::ONE).all())
+            .map(|uv| uv * self.surface_size());
+
+        self.handle_event(Event::CursorMoved(pos))
+    }
+
+    /// Releases pointer capture if a widget holds it, sending that widget
+    /// [`WidgetEvent::PointerCaptureLost`][crate::event::WidgetEvent::PointerCaptureLost].
+    /// Useful when the application takes over the mouse, like when the window
+    /// loses focus mid-drag.
+    pub fn release_pointer_capture(&mut self) {
+        context::bind_dom(&self.dom);
+
+        self.input.capture_pointer(None);
+        self.input
+            .notify_pointer_capture_lost(&self.dom, &self.layout);
+
+        context::unbind_dom();
+    }
+
+    /// Sets the current time, used to time input like double-clicks and long
+    /// presses. Only differences between times matter, so any starting point
+    /// works.
+    ///
+    /// By default, yakui measures time itself, except on the web where it has
+    /// no clock. Once this is called, the application is responsible for
+    /// keeping the time up to date before handling events and starting frames.
+    pub fn set_time(&mut self, time: Duration) {
+        self.clock = None;
+        self.input.set_time(Some(time));
+    }
+
+    /// Returns the current time, or `None` if yakui has no time source.
+    pub fn time(&self) -> Option<Duration> {
+        self.input.time()
+    }
+
+    /// Sets how double-clicks and long presses are detected.
+    pub fn set_click_settings(&mut self, settings: ClickSettings) {
+        self.input.set_click_settings(settings);
+    }
+
+    /// Returns the settings used to detect double-clicks and long presses.
+    pub fn click_settings(&self) -> ClickSettings {
+        self.input.click_settings()
+    }
+
+    fn tick_clock(&mut self) {
+        if let Some(clock) = &self.clock {
+            self.input.set_time(clock.try_elapsed());
+        }
+    }
+
     /// Creates a texture for use within yakui.
     pub fn add_texture(&mut self, texture: Texture) -> ManagedTextureId {
         self.paint.add_texture(texture)
@@ -96,6 +177,16 @@ impl Yakui {
     ///
     /// When finished, call [`Dom::finish`].
     pub fn start(&mut self) {
+        self.tick_clock();
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_frame(
+                self.paint.surface_size(),
+                self.layout.unscaled_viewport(),
+                self.layout.scale_factor(),
+                self.input.time(),
+            );
+        }
+
         self.dom.start();
         self.input.start(&self.dom, &self.layout);
         self.paint.start();
@@ -112,8 +203,13 @@ impl Yakui {
 
         self.dom.finish();
         self.layout.sync_removals(&self.dom.removed_nodes());
+
+        let timer = Timer::start();
         self.layout
             .calculate_all(&self.dom, &self.input, &self.paint);
+        self.stats.layout_time = timer.elapsed();
+        self.stats.widgets = self.dom.len();
+
         self.input.finish(&self.dom, &self.layout);
     }
 
@@ -121,10 +217,39 @@ impl Yakui {
     /// access to the [`PaintDom`], which holds information about how to paint
     /// widgets.
     pub fn paint(&mut self) -> &PaintDom {
+        let timer = Timer::start();
         self.paint.paint_all(&self.dom, &self.layout);
+        self.stats.paint_time = timer.elapsed();
+
+        self.stats.count_paint(&self.paint);
+        self.dom.set_frame_stats(self.stats);
+
         &self.paint
     }
 
+    /// Returns statistics about the most recently finished and painted frame.
+    pub fn frame_stats(&self) -> FrameStats {
+        self.stats
+    }
+
+    /// Starts recording the events and frames given to yakui, discarding any
+    /// recording already in progress. See the [`recording`][crate::recording]
+    /// module for details.
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(Recorder::default());
+    }
+
+    /// Stops recording and returns what was recorded, or `None` if no
+    /// recording was in progress.
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recorder.take().map(Recorder::finish)
+    }
+
+    /// Tells whether a recording is in progress.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
     /// Returns access to the state's DOM.
     pub fn dom(&self) -> &Dom {
         &self.dom
@@ -0,0 +1,111 @@
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use crate::paint::{BatchStats, PaintDom, TextureChange};
+
+/// Statistics about the work done to build, lay out, and paint a frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameStats {
+    /// The number of widgets in the DOM, including the root widget.
+    pub widgets: usize,
+
+    /// Time spent calculating the layout of all widgets.
+    pub layout_time: Duration,
+
+    /// Time spent generating geometry for all widgets.
+    pub paint_time: Duration,
+
+    /// The number of [`PaintCall`][crate::paint::PaintCall]s in all layers and
+    /// groups.
+    pub paint_calls: usize,
+
+    /// The total number of vertices in all paint calls.
+    pub vertices: usize,
+
+    /// The total number of indices in all paint calls.
+    pub indices: usize,
+
+    /// The number of textures that were added or modified and need to be
+    /// uploaded by the renderer.
+    pub texture_uploads: usize,
+
+    /// The size in bytes of all textures that need to be uploaded.
+    pub texture_upload_bytes: usize,
+
+    /// How many paint calls were merged together by batching.
+    pub batching: BatchStats,
+}
+
+impl FrameStats {
+    /// Counts the geometry and texture uploads that a painted frame needs.
+    pub(crate) fn count_paint(&mut self, paint: &PaintDom) {
+        let calls = paint
+            .layers()
+            .iter()
+            .flat_map(|layer| &layer.calls)
+            .chain(paint.groups().iter().flat_map(|group| &group.calls));
+
+        self.paint_calls = 0;
+        self.vertices = 0;
+        self.indices = 0;
+        for call in calls {
+            self.paint_calls += 1;
+            self.vertices += call.vertices.len();
+            self.indices += call.indices.len();
+        }
+
+        let textures = paint.textures();
+        self.texture_uploads = 0;
+        self.texture_upload_bytes = 0;
+        for (id, change) in textures.edits() {
+            if change == TextureChange::Removed {
+                continue;
+            }
+
+            if let Some(texture) = textures.get(id) {
+                self.texture_uploads += 1;
+                self.texture_upload_bytes += texture.data().len();
+            }
+        }
+
+        self.batching = paint.batch_stats();
+    }
+}
+
+/// Measures how long a phase of the frame takes.
+///
+/// `Instant` isn't available on the web, so timings there are always zero.
+#[derive(Debug)]
+pub(crate) struct Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+impl Timer {
+    pub fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.start.elapsed();
+
+        #[cfg(target_arch = "wasm32")]
+        return Duration::ZERO;
+    }
+
+    /// Like [`Timer::elapsed`], but returns `None` where time can't be
+    /// measured.
+    pub fn try_elapsed(&self) -> Option<Duration> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Some(self.start.elapsed());
+
+        #[cfg(target_arch = "wasm32")]
+        return None;
+    }
+}
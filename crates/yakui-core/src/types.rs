@@ -4,6 +4,7 @@ use crate::geometry::{Constraints, Dim2, Vec2};
 
 /// Defines how an object participates in layout.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Flow {
     /// The widget participates in list, grid, and table layouts.
     ///
@@ -26,6 +27,7 @@ pub enum Flow {
 /// For example, a horizontal list's main axis is horizontal, and a vertical
 /// list's main axis is vertical.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MainAxisSize {
     /// Make the container fill all available space along its main axis.
     Max,
@@ -39,6 +41,7 @@ pub enum MainAxisSize {
 /// For example, a horizontal list's main axis is horizontal, and a vertical
 /// list's main axis is vertical.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MainAxisAlignment {
     /// Align items to the beginning of the container's main axis.
     ///
@@ -74,6 +77,7 @@ pub enum MainAxisAlignment {
 ///
 /// This occurs in a Grid when items of the same row are bigger than one self.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MainAxisAlignItems {
     /// Align item to the beginning of the cell main axis.
     ///
@@ -101,6 +105,7 @@ pub enum MainAxisAlignItems {
 /// For example, a horizontal list's cross axis is vertical, and a vertical
 /// list's cross axis is horizontal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CrossAxisAlignment {
     /// Align items to the beginning of the container's cross axis.
     ///
@@ -121,10 +126,19 @@ pub enum CrossAxisAlignment {
 
     /// Stretch items to fill the maximum size of the container's cross axis.
     Stretch,
+
+    /// Line up the first baselines of items, so that text of different sizes
+    /// sits on the same line. Items without a baseline are lined up by their
+    /// bottom edge.
+    ///
+    /// Only applies to left-to-right lists. Other layouts treat it like
+    /// [`CrossAxisAlignment::Start`].
+    Baseline,
 }
 
 /// Defines the direction that a container will lay out its children.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Lay out children from top to bottom.
     Down,
@@ -179,6 +193,7 @@ impl Direction {
 
 /// Defines alignment within a container.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alignment {
     x: f32,
     y: f32,
@@ -216,6 +231,7 @@ impl Alignment {
 
 /// Defines a reference point for a widget.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pivot {
     x: f32,
     y: f32,
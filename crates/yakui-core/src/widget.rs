@@ -40,6 +40,87 @@ impl LayoutContext<'_> {
         self.layout
             .calculate(self.dom, self.input, self.paint, widget, constraints)
     }
+
+    /// Returns a context for asking widgets about their intrinsic sizes
+    /// without laying them out.
+    pub fn intrinsic(&self) -> IntrinsicContext<'_> {
+        IntrinsicContext {
+            dom: self.dom,
+            input: self.input,
+            layout: self.layout,
+            paint: self.paint,
+        }
+    }
+}
+
+/// Information available to a widget when it is asked for its baseline, right
+/// after it has been laid out.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub struct BaselineContext<'dom> {
+    pub dom: &'dom Dom,
+    pub layout: &'dom LayoutDom,
+}
+
+impl BaselineContext<'_> {
+    /// Returns the baseline of one of the current widget's children, measured
+    /// from the top of the current widget.
+    pub fn child_baseline(&self, child: WidgetId) -> Option<f32> {
+        let node = self.layout.get(child)?;
+        Some(node.rect.pos().y + node.baseline?)
+    }
+}
+
+/// Information available to a widget when it is asked for its intrinsic size.
+///
+/// Intrinsic sizes are measured without committing any layout, so widgets can
+/// ask their children how big they would like to be before deciding what
+/// constraints to give them.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub struct IntrinsicContext<'dom> {
+    pub dom: &'dom Dom,
+    pub input: &'dom InputState,
+    pub layout: &'dom LayoutDom,
+    pub paint: &'dom PaintDom,
+}
+
+impl IntrinsicContext<'_> {
+    /// Query the given widget's minimum intrinsic width for the given height.
+    /// See [`Widget::min_intrinsic_width`].
+    pub fn min_intrinsic_width(&self, widget: WidgetId, height: f32) -> f32 {
+        self.query(widget, |node, ctx| node.min_intrinsic_width(ctx, height))
+    }
+
+    /// Query the given widget's maximum intrinsic width for the given height.
+    /// See [`Widget::max_intrinsic_width`].
+    pub fn max_intrinsic_width(&self, widget: WidgetId, height: f32) -> f32 {
+        self.query(widget, |node, ctx| node.max_intrinsic_width(ctx, height))
+    }
+
+    /// Query the given widget's minimum intrinsic height for the given width.
+    /// See [`Widget::min_intrinsic_height`].
+    pub fn min_intrinsic_height(&self, widget: WidgetId, width: f32) -> f32 {
+        self.query(widget, |node, ctx| node.min_intrinsic_height(ctx, width))
+    }
+
+    /// Query the given widget's maximum intrinsic height for the given width.
+    /// See [`Widget::max_intrinsic_height`].
+    pub fn max_intrinsic_height(&self, widget: WidgetId, width: f32) -> f32 {
+        self.query(widget, |node, ctx| node.max_intrinsic_height(ctx, width))
+    }
+
+    fn query<F>(&self, widget: WidgetId, query: F) -> f32
+    where
+        F: FnOnce(&dyn ErasedWidget, IntrinsicContext<'_>) -> f32,
+    {
+        self.dom.enter(widget);
+        let node = self.dom.get(widget).unwrap();
+        let size = query(node.widget.as_ref(), *self);
+        self.dom.exit(widget);
+
+        size
+    }
 }
 
 /// Information available to a widget during the paint phase.
@@ -65,6 +146,31 @@ pub struct EventContext<'dom> {
     pub input: &'dom InputState,
 }
 
+impl EventContext<'_> {
+    /// Captures the pointer for the current widget, so that it receives every
+    /// mouse move and button event until all mouse buttons are released, even
+    /// if the cursor leaves the widget or the window. Usually called when
+    /// handling a mouse button being pressed.
+    ///
+    /// Has no effect if no mouse button is held down.
+    pub fn capture_pointer(&self) {
+        self.input.capture_pointer(Some(self.dom.current()));
+    }
+
+    /// Releases pointer capture if the current widget holds it. The widget is
+    /// not sent [`WidgetEvent::PointerCaptureLost`].
+    pub fn release_pointer(&self) {
+        if self.has_pointer_capture() {
+            self.input.clear_pointer_capture();
+        }
+    }
+
+    /// Tells whether the current widget holds pointer capture.
+    pub fn has_pointer_capture(&self) -> bool {
+        self.input.pointer_capture() == Some(self.dom.current())
+    }
+}
+
 /// Information available to a widget when it is being queried for navigation.
 #[allow(missing_docs)]
 #[derive(Clone, Copy)]
@@ -175,6 +281,118 @@ pub trait Widget: 'static + fmt::Debug {
         constraints.constrain_min(size)
     }
 
+    /// Returns the smallest width this widget can have without its contents
+    /// overflowing, if it had the given height. `height` may be infinite.
+    ///
+    /// Like the rest of the intrinsic size queries, this must not commit any
+    /// layout. Answering it can visit the widget's whole subtree, so layouts
+    /// should only ask for intrinsic sizes when they can't do without them.
+    ///
+    /// The default implementation matches [`Widget::default_layout`] and
+    /// returns the largest answer of all of this widget's children.
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+    }
+
+    /// A convenience method that always answers
+    /// [`Widget::min_intrinsic_width`] the default way.
+    #[inline]
+    fn default_min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        node.children
+            .iter()
+            .map(|&child| ctx.min_intrinsic_width(child, height))
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the smallest width beyond which more width would not make this
+    /// widget any shorter, if it had the given height. For text, this is the
+    /// width of the text without any wrapping. `height` may be infinite.
+    ///
+    /// The default implementation returns the largest answer of all of this
+    /// widget's children.
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+    }
+
+    /// A convenience method that always answers
+    /// [`Widget::max_intrinsic_width`] the default way.
+    #[inline]
+    fn default_max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        node.children
+            .iter()
+            .map(|&child| ctx.max_intrinsic_width(child, height))
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the smallest height this widget can have without its contents
+    /// overflowing, if it had the given width. `width` may be infinite.
+    ///
+    /// The default implementation returns the largest answer of all of this
+    /// widget's children.
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width)
+    }
+
+    /// A convenience method that always answers
+    /// [`Widget::min_intrinsic_height`] the default way.
+    #[inline]
+    fn default_min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        node.children
+            .iter()
+            .map(|&child| ctx.min_intrinsic_height(child, width))
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the smallest height beyond which more height would not change
+    /// this widget's layout, if it had the given width. For most widgets, this
+    /// is the same as the minimum intrinsic height. `width` may be infinite.
+    ///
+    /// The default implementation returns the largest answer of all of this
+    /// widget's children.
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width)
+    }
+
+    /// A convenience method that always answers
+    /// [`Widget::max_intrinsic_height`] the default way.
+    #[inline]
+    fn default_max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        node.children
+            .iter()
+            .map(|&child| ctx.max_intrinsic_height(child, width))
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the distance from the top of this widget to the baseline of its
+    /// first line of text, if it has one. Containers can use it to line up
+    /// text of different sizes, like [`CrossAxisAlignment::Baseline`] does.
+    ///
+    /// This is called right after [`Widget::layout`], once this widget's
+    /// children have been positioned.
+    ///
+    /// The default implementation returns the baseline of the first child that
+    /// has one, so widgets that wrap or position text keep its baseline.
+    ///
+    /// [`CrossAxisAlignment::Baseline`]: crate::CrossAxisAlignment::Baseline
+    fn baseline(&self, ctx: BaselineContext<'_>) -> Option<f32> {
+        self.default_baseline(ctx)
+    }
+
+    /// A convenience method that always finds this widget's baseline the
+    /// default way.
+    #[inline]
+    fn default_baseline(&self, ctx: BaselineContext<'_>) -> Option<f32> {
+        let node = ctx.dom.get_current();
+        node.children
+            .iter()
+            .filter(|&&child| ctx.dom.get(child).unwrap().widget.flow() == Flow::Inline)
+            .find_map(|&child| ctx.child_baseline(child))
+    }
+
     /// Paint the widget based on its current state.
     ///
     /// The default implementation will paint all of the widget's children.
@@ -300,6 +518,21 @@ pub trait ErasedWidget: Any + fmt::Debug {
     /// See [`Widget::layout`].
     fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2;
 
+    /// See [`Widget::min_intrinsic_width`].
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32;
+
+    /// See [`Widget::max_intrinsic_width`].
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32;
+
+    /// See [`Widget::min_intrinsic_height`].
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32;
+
+    /// See [`Widget::max_intrinsic_height`].
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32;
+
+    /// See [`Widget::baseline`].
+    fn baseline(&self, ctx: BaselineContext<'_>) -> Option<f32>;
+
     /// See [`Widget::flex`].
     fn flex(&self) -> (u32, FlexFit);
 
@@ -330,6 +563,26 @@ where
         <T as Widget>::layout(self, ctx, constraints)
     }
 
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        <T as Widget>::min_intrinsic_width(self, ctx, height)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        <T as Widget>::max_intrinsic_width(self, ctx, height)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        <T as Widget>::min_intrinsic_height(self, ctx, width)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        <T as Widget>::max_intrinsic_height(self, ctx, width)
+    }
+
+    fn baseline(&self, ctx: BaselineContext<'_>) -> Option<f32> {
+        <T as Widget>::baseline(self, ctx)
+    }
+
     fn flex(&self) -> (u32, FlexFit) {
         <T as Widget>::flex(self)
     }
@@ -0,0 +1,82 @@
+use glam::{Vec2, Vec4};
+use yakui_core::geometry::{Brush, Color, Rect};
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::Yakui;
+
+#[derive(Debug)]
+struct GradientWidget;
+
+impl Widget for GradientWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let mut rect = PaintRect::new(Rect::from_pos_size(Vec2::ZERO, Vec2::splat(100.0)));
+        rect.brush = Brush::linear(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            [
+                (0.0, Color::BLACK),
+                (0.5, Color::WHITE),
+                (1.0, Color::BLACK),
+            ],
+        );
+        rect.add(ctx.paint);
+    }
+}
+
+#[test]
+fn gradient_rect_splits_at_stops() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    yak.dom().do_widget::<GradientWidget>(());
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].vertices.len(), 6);
+
+    for vertex in &calls[0].vertices {
+        let expected = if vertex.position.x == 0.5 {
+            Vec4::ONE
+        } else {
+            Vec4::new(0.0, 0.0, 0.0, 1.0)
+        };
+
+        assert_eq!(vertex.color, expected);
+    }
+}
+
+#[test]
+fn gradient_interpolates_in_linear_space() {
+    let bounds = Rect::from_pos_size(Vec2::ZERO, Vec2::splat(10.0));
+    let brush = Brush::linear(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        [(1.0, Color::WHITE), (0.0, Color::BLACK)],
+    );
+
+    let color = brush.color_at(bounds, Vec2::new(3.0, 5.0));
+    assert!((color - Vec4::new(0.5, 0.5, 0.5, 1.0)).length() < 1e-5);
+
+    let radial = Brush::radial(
+        Vec2::new(0.5, 0.5),
+        Vec2::new(0.5, 0.5),
+        [(0.0, Color::WHITE), (1.0, Color::BLACK)],
+    );
+
+    assert_eq!(radial.color_at(bounds, Vec2::new(5.0, 5.0)), Vec4::ONE);
+    assert_eq!(
+        radial.color_at(bounds, Vec2::new(10.0, 10.0)),
+        Vec4::new(0.0, 0.0, 0.0, 1.0)
+    );
+}
@@ -0,0 +1,465 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use glam::Vec2;
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::widget::{EventContext, LayoutContext, Widget};
+use yakui_core::Yakui;
+
+#[derive(Debug)]
+struct HoverWidget {
+    hovered: Rc<AtomicUsize>,
+}
+
+impl Widget for HoverWidget {
+    type Props<'a> = ();
+    type Response = Rc<AtomicUsize>;
+
+    fn new() -> Self {
+        Self {
+            hovered: Rc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.hovered.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::splat(50.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::MouseEnter => self.hovered.store(1, Ordering::SeqCst),
+            WidgetEvent::MouseLeave => self.hovered.store(0, Ordering::SeqCst),
+            _ => {}
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+#[test]
+fn cursor_from_surface_uv() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(200.0, 100.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    yak.start();
+    let hovered = yak.dom().do_widget::<HoverWidget>(());
+    yak.finish();
+
+    yak.handle_cursor_uv(Some(Vec2::new(0.1, 0.4)));
+    assert_eq!(hovered.load(Ordering::SeqCst), 1);
+
+    yak.handle_cursor_uv(Some(Vec2::new(0.5, 0.4)));
+    assert_eq!(hovered.load(Ordering::SeqCst), 0);
+
+    yak.handle_cursor_uv(Some(Vec2::new(0.1, 0.4)));
+    assert_eq!(hovered.load(Ordering::SeqCst), 1);
+
+    yak.handle_cursor_uv(Some(Vec2::new(-0.1, 0.4)));
+    assert_eq!(hovered.load(Ordering::SeqCst), 0);
+}
+
+#[derive(Debug)]
+struct PlaceWidget {
+    pos: Vec2,
+}
+
+impl Widget for PlaceWidget {
+    type Props<'a> = Vec2;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { pos: Vec2::ZERO }
+    }
+
+    fn update(&mut self, pos: Self::Props<'_>) -> Self::Response {
+        self.pos = pos;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::loose(constraints.max));
+            ctx.layout.set_pos(child, self.pos);
+        }
+
+        constraints.min
+    }
+}
+
+type EventLog = Rc<RefCell<Vec<String>>>;
+
+#[derive(Debug)]
+struct CaptureWidget {
+    log: EventLog,
+    capture: bool,
+}
+
+impl Widget for CaptureWidget {
+    type Props<'a> = bool;
+    type Response = EventLog;
+
+    fn new() -> Self {
+        Self {
+            log: EventLog::default(),
+            capture: false,
+        }
+    }
+
+    fn update(&mut self, capture: Self::Props<'_>) -> Self::Response {
+        self.capture = capture;
+        self.log.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_MOVE
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(50.0, 50.0)
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        let entry = match event {
+            WidgetEvent::MouseEnter => "enter".to_owned(),
+            WidgetEvent::MouseLeave => "leave".to_owned(),
+            WidgetEvent::MouseMoved(Some(pos)) => format!("move {} {}", pos.x, pos.y),
+            WidgetEvent::MouseButtonChanged { down, inside, .. } => {
+                if *down && self.capture {
+                    ctx.capture_pointer();
+                }
+                format!("button down={down} inside={inside}")
+            }
+            WidgetEvent::PointerCaptureLost => "lost".to_owned(),
+            WidgetEvent::FileHovered { path, position } => {
+                format!(
+                    "file hover {} {} {}",
+                    path.display(),
+                    position.x,
+                    position.y
+                )
+            }
+            WidgetEvent::FileHoverCancelled => "file cancel".to_owned(),
+            WidgetEvent::MouseScroll { modifiers, .. } => format!("scroll modifiers={modifiers:?}"),
+            WidgetEvent::FileDropped { path, position } => {
+                format!("file drop {} {} {}", path.display(), position.x, position.y)
+            }
+            _ => return EventResponse::Bubble,
+        };
+
+        self.log.borrow_mut().push(entry);
+        EventResponse::Sink
+    }
+}
+
+/// Places a [CaptureWidget] that captures the pointer at x = 0 and one that
+/// doesn't at x = 100, returning their event logs.
+fn capture_widgets() -> (EventLog, EventLog) {
+    let dom = yakui_core::context::dom();
+    let mut logs = Vec::new();
+    for (x, capture) in [(0.0, true), (100.0, false)] {
+        let place = dom.begin_widget::<PlaceWidget>(Vec2::new(x, 0.0)).id;
+        logs.push(dom.do_widget::<CaptureWidget>(capture).clone());
+        dom.end_widget::<PlaceWidget>(place);
+    }
+    (logs.remove(0), logs.remove(0))
+}
+
+#[test]
+fn pointer_capture_routes_events_to_capturing_widget() {
+    use yakui_core::event::Event;
+    use yakui_core::input::MouseButton;
+
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let (dragged, other) = capture_widgets();
+    yak.finish();
+
+    let button = |down| Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down,
+    };
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(button(true));
+    assert_eq!(*other.borrow(), ["move 10 10"]);
+    other.borrow_mut().clear();
+
+    // Moving over the other widget while captured neither hovers it nor sends
+    // it any events.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(120.0, 10.0))));
+    yak.handle_event(Event::CursorMoved(None));
+    assert!(yak.handle_event(button(false)));
+
+    assert_eq!(
+        *dragged.borrow(),
+        [
+            "move 10 10",
+            "enter",
+            "button down=true inside=true",
+            "move 120 10",
+            "leave",
+            "button down=false inside=false",
+        ]
+    );
+    assert!(other.borrow().is_empty());
+
+    // After release, hover goes back to normal.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(120.0, 10.0))));
+    assert_eq!(*other.borrow(), ["move 120 10", "enter"]);
+
+    // Taking capture away tells the widget.
+    dragged.borrow_mut().clear();
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(button(true));
+    yak.release_pointer_capture();
+    yak.handle_event(button(false));
+
+    assert_eq!(
+        *dragged.borrow(),
+        [
+            "move 10 10",
+            "enter",
+            "button down=true inside=true",
+            "lost",
+            "button down=false inside=true",
+        ]
+    );
+}
+
+#[test]
+fn dropped_files_go_to_widget_under_cursor() {
+    use std::path::PathBuf;
+    use yakui_core::event::Event;
+
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let (left, right) = capture_widgets();
+    yak.finish();
+
+    let path = PathBuf::from("notes.txt");
+
+    // Without a position, the last known cursor position is used.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    left.borrow_mut().clear();
+    right.borrow_mut().clear();
+    assert!(yak.handle_event(Event::FileHovered {
+        path: path.clone(),
+        position: None,
+    }));
+    assert_eq!(*left.borrow(), ["file hover notes.txt 10 10"]);
+
+    // Dropping somewhere else cancels the hover for the first widget.
+    left.borrow_mut().clear();
+    assert!(yak.handle_event(Event::FileDropped {
+        path: path.clone(),
+        position: Some(Vec2::new(120.0, 20.0)),
+    }));
+    assert_eq!(*left.borrow(), ["move 120 20", "leave", "file cancel"]);
+    assert_eq!(
+        *right.borrow(),
+        ["move 120 20", "enter", "file drop notes.txt 120 20"]
+    );
+
+    // Dropping over nothing bubbles the event to the application.
+    assert!(!yak.handle_event(Event::FileDropped {
+        path,
+        position: Some(Vec2::new(80.0, 80.0)),
+    }));
+}
+
+#[test]
+fn losing_window_focus_releases_buttons_and_modifiers() {
+    use yakui_core::event::Event;
+    use yakui_core::input::{Modifiers, MouseButton, ScrollDelta};
+
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let (dragged, other) = capture_widgets();
+    yak.finish();
+
+    yak.handle_event(Event::ModifiersChanged(Modifiers::SHIFT));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    dragged.borrow_mut().clear();
+    other.borrow_mut().clear();
+
+    // The capturing widget loses capture before any release, and every
+    // widget sees the release as happening outside of it.
+    assert!(!yak.handle_event(Event::WindowFocusChanged(false)));
+    assert_eq!(
+        *dragged.borrow(),
+        ["lost", "button down=false inside=false"]
+    );
+    assert_eq!(*other.borrow(), ["button down=false inside=false"]);
+
+    // Capture is gone, so other widgets get mouse events again, and no
+    // modifiers are held anymore.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(120.0, 10.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: ScrollDelta::Lines(Vec2::new(0.0, 1.0)),
+    });
+    assert_eq!(
+        *other.borrow(),
+        [
+            "button down=false inside=false",
+            "move 120 10",
+            "enter",
+            "scroll modifiers=Modifiers(0x0)",
+        ]
+    );
+}
+
+#[derive(Debug)]
+struct ClickWidget {
+    log: EventLog,
+}
+
+impl Widget for ClickWidget {
+    type Props<'a> = ();
+    type Response = EventLog;
+
+    fn new() -> Self {
+        Self {
+            log: EventLog::default(),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.log.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(50.0, 50.0)
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        let entry = match event {
+            WidgetEvent::MouseButtonChanged {
+                down, click_count, ..
+            } => format!("down={down} clicks={click_count}"),
+            WidgetEvent::MouseLongPress { position, .. } => {
+                format!("long press {} {}", position.x, position.y)
+            }
+            _ => return EventResponse::Bubble,
+        };
+
+        self.log.borrow_mut().push(entry);
+        EventResponse::Sink
+    }
+}
+
+#[test]
+fn clicks_are_counted_and_long_presses_detected() {
+    use std::time::Duration;
+    use yakui_core::event::Event;
+    use yakui_core::input::MouseButton;
+
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    yak.set_time(Duration::ZERO);
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let log = yakui_core::context::dom().do_widget::<ClickWidget>(());
+        yak.finish();
+        log
+    };
+    let log = frame(&mut yak);
+
+    let click = |yak: &mut Yakui, ms: u64, x: f32| {
+        yak.set_time(Duration::from_millis(ms));
+        yak.handle_event(Event::CursorMoved(Some(Vec2::new(x, 10.0))));
+        for down in [true, false] {
+            yak.handle_event(Event::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+            });
+        }
+    };
+
+    // Three quick clicks close together count up, then a slow one or one too
+    // far away starts over.
+    click(&mut yak, 0, 10.0);
+    click(&mut yak, 200, 12.0);
+    click(&mut yak, 400, 10.0);
+    click(&mut yak, 1000, 10.0);
+    click(&mut yak, 1100, 30.0);
+    assert_eq!(
+        *log.borrow(),
+        [
+            "down=true clicks=1",
+            "down=false clicks=1",
+            "down=true clicks=2",
+            "down=false clicks=2",
+            "down=true clicks=3",
+            "down=false clicks=3",
+            "down=true clicks=1",
+            "down=false clicks=1",
+            "down=true clicks=1",
+            "down=false clicks=1",
+        ]
+    );
+    log.borrow_mut().clear();
+
+    // Holding the button still is a long press once enough time has passed
+    // when a frame starts, and it's only reported once.
+    yak.set_time(Duration::from_millis(5000));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.set_time(Duration::from_millis(5200));
+    frame(&mut yak);
+    assert_eq!(*log.borrow(), ["down=true clicks=1"]);
+
+    yak.set_time(Duration::from_millis(5600));
+    frame(&mut yak);
+    yak.set_time(Duration::from_millis(6000));
+    frame(&mut yak);
+    assert_eq!(*log.borrow(), ["down=true clicks=1", "long press 30 10"]);
+    log.borrow_mut().clear();
+
+    // Moving too far while held cancels the long press.
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: false,
+    });
+    yak.set_time(Duration::from_millis(7000));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(40.0, 10.0))));
+    yak.set_time(Duration::from_millis(8000));
+    frame(&mut yak);
+    assert_eq!(*log.borrow(), ["down=false clicks=1", "down=true clicks=1"]);
+}
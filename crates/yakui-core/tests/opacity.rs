@@ -0,0 +1,77 @@
+use glam::Vec2;
+use yakui_core::geometry::{Color, Rect};
+use yakui_core::paint::{PaintRect, Pipeline};
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::Yakui;
+
+#[derive(Debug)]
+struct FadeWidget {
+    group: bool,
+}
+
+impl Widget for FadeWidget {
+    type Props<'a> = bool;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { group: false }
+    }
+
+    fn update(&mut self, group: Self::Props<'_>) -> Self::Response {
+        self.group = group;
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        if self.group {
+            ctx.paint.push_group(0.5);
+        } else {
+            ctx.paint.push_opacity(0.5);
+        }
+
+        let mut rect = PaintRect::new(Rect::from_pos_size(Vec2::ZERO, Vec2::splat(10.0)));
+        rect.brush = Color::WHITE.into();
+        rect.add(ctx.paint);
+
+        if self.group {
+            ctx.paint.pop_group();
+        } else {
+            ctx.paint.pop_opacity();
+        }
+    }
+}
+
+#[test]
+fn opacity_fades_geometry() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    yak.dom().do_widget::<FadeWidget>(false);
+    yak.finish();
+
+    let paint = yak.paint();
+    let calls = &paint.layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0]
+        .vertices
+        .iter()
+        .all(|vertex| vertex.opacity == 0.5 && vertex.color.w == 1.0));
+    assert!(paint.groups().is_empty());
+
+    yak.start();
+    yak.dom().do_widget::<FadeWidget>(true);
+    yak.finish();
+
+    // Grouped geometry is kept at full opacity and faded when composited.
+    let paint = yak.paint();
+    let calls = &paint.layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].pipeline, Pipeline::Group(0));
+    assert!(calls[0].vertices.iter().all(|vertex| vertex.opacity == 0.5));
+
+    let group = &paint.groups()[0];
+    assert_eq!(group.calls.len(), 1);
+    assert!(group.calls[0]
+        .vertices
+        .iter()
+        .all(|vertex| vertex.opacity == 1.0));
+}
@@ -0,0 +1,224 @@
+use glam::{Vec2, Vec4};
+use yakui_core::geometry::Rect;
+use yakui_core::paint::{BatchStats, PaintMesh, PaintRect, Pipeline, Vertex};
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::{TextureId, Yakui};
+
+#[derive(Debug)]
+struct CustomWidget;
+
+impl Widget for CustomWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let rect = Rect::from_pos_size(Vec2::ZERO, Vec2::splat(50.0));
+        ctx.paint.add_custom(rect, 7);
+        ctx.paint.add_custom(rect, 7);
+    }
+}
+
+#[test]
+fn custom_paint_calls_are_not_merged() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    yak.dom().do_widget::<CustomWidget>(());
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    assert_eq!(calls.len(), 2);
+
+    for call in calls {
+        assert_eq!(call.pipeline, Pipeline::Custom(7));
+        assert_eq!(call.vertices.len(), 4);
+        assert!(call
+            .vertices
+            .iter()
+            .all(|vertex| vertex.position.cmple(Vec2::splat(0.5)).all()));
+    }
+}
+
+#[derive(Debug)]
+struct SnapWidget;
+
+impl Widget for SnapWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let rect = Rect::from_pos_size(Vec2::splat(10.3), Vec2::splat(5.1));
+        PaintRect::new(rect).add(ctx.paint);
+    }
+}
+
+#[test]
+fn rects_snap_to_physical_pixels() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(200.0));
+    yak.set_scale_factor(2.0);
+
+    yak.start();
+    yak.dom().do_widget::<SnapWidget>(());
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    for vertex in &calls[0].vertices {
+        let physical = vertex.position * 200.0;
+
+        for axis in [physical.x, physical.y] {
+            assert!(
+                (axis - 21.0).abs() < 1e-3 || (axis - 31.0).abs() < 1e-3,
+                "{physical}"
+            );
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LargeMeshWidget;
+
+impl Widget for LargeMeshWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        // Two meshes that together need more vertices than fit in a u16, the
+        // first of which can't fit alone either.
+        for count in [70_000, 1_000] {
+            let vertices = (0..count).map(|i| {
+                let pos = Vec2::new(i as f32 / count as f32, 0.5);
+                Vertex::new(pos, Vec2::ZERO, Vec4::ONE)
+            });
+
+            ctx.paint.add_mesh(PaintMesh::new(vertices, 0..count));
+        }
+    }
+}
+
+#[test]
+fn large_meshes_keep_their_indices() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    yak.dom().do_widget::<LargeMeshWidget>(());
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+
+    let call = &calls[0];
+    assert_eq!(call.vertices.len(), 71_000);
+    assert_eq!(call.indices.len(), 71_000);
+
+    for (i, &index) in call.indices.iter().enumerate() {
+        assert_eq!(index, i as u32);
+    }
+}
+
+#[derive(Debug)]
+struct IconsWidget {
+    overlap: bool,
+}
+
+impl Widget for IconsWidget {
+    type Props<'a> = bool;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { overlap: false }
+    }
+
+    fn update(&mut self, overlap: Self::Props<'_>) -> Self::Response {
+        self.overlap = overlap;
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        // Alternate between two textures, like a row of icons with labels.
+        for i in 0..4 {
+            let x = if self.overlap { 0.0 } else { i as f32 * 20.0 };
+            let texture = TextureId::User(i % 2);
+            let rect = Rect::from_pos_size(Vec2::new(x, 0.0), Vec2::splat(10.0));
+
+            let mut rect = PaintRect::new(rect);
+            rect.texture = Some((texture, Rect::ONE));
+            rect.add(ctx.paint);
+        }
+    }
+}
+
+#[test]
+fn batching_merges_calls_that_do_not_overlap() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    yak.dom().do_widget::<IconsWidget>(false);
+    yak.finish();
+
+    let paint = yak.paint();
+    let calls = &paint.layers()[0].calls;
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].texture, Some(TextureId::User(0)));
+    assert_eq!(calls[1].texture, Some(TextureId::User(1)));
+    assert_eq!(
+        paint.batch_stats(),
+        BatchStats {
+            calls_before: 4,
+            calls_after: 2,
+            texture_switches_before: 3,
+            texture_switches_after: 1,
+        }
+    );
+
+    yak.start();
+    yak.dom().do_widget::<IconsWidget>(true);
+    yak.finish();
+
+    // Overlapping calls must keep their order.
+    let calls = &yak.paint().layers()[0].calls;
+    assert_eq!(calls.len(), 4);
+}
+
+#[test]
+fn frame_stats_count_paint_output() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    yak.dom().do_widget::<IconsWidget>(false);
+    yak.dom().do_widget::<SnapWidget>(());
+    yak.finish();
+    yak.paint();
+
+    let stats = yak.frame_stats();
+    assert_eq!(stats.widgets, 3);
+    assert_eq!(stats.paint_calls, 3);
+    assert_eq!(stats.vertices, 20);
+    assert_eq!(stats.indices, 30);
+    assert_eq!(stats.batching.calls_before, 5);
+
+    // Widgets see the statistics of the previous frame while building.
+    yak.start();
+    assert_eq!(yak.dom().frame_stats(), stats);
+    yak.finish();
+}
@@ -0,0 +1,95 @@
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use glam::Vec2;
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::Constraints;
+use yakui_core::widget::{EventContext, LayoutContext, Widget};
+use yakui_core::Yakui;
+
+#[derive(Debug)]
+struct KeyboardWidget {
+    count: Rc<AtomicUsize>,
+}
+
+impl Widget for KeyboardWidget {
+    type Props<'a> = ();
+    type Response = Rc<AtomicUsize>;
+
+    fn new() -> Self {
+        Self {
+            count: Rc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.count.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::FOCUSED_KEYBOARD
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        ctx.input.set_selection(Some(ctx.dom.current()));
+        Vec2::ZERO
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::TextInput(..) = event {
+            self.count.fetch_add(1, Ordering::SeqCst);
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+#[test]
+fn replaying_a_recording_reproduces_input() {
+    let build = |count: &mut Option<Rc<AtomicUsize>>| {
+        let response = yakui_core::context::dom().do_widget::<KeyboardWidget>(());
+        *count = Some(Rc::clone(&response));
+    };
+
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(320.0, 240.0));
+    yak.set_scale_factor(2.0);
+    yak.start_recording();
+    assert!(yak.is_recording());
+
+    let mut count = None;
+    for text in ["", "hi", "!"] {
+        for c in text.chars() {
+            yak.handle_event(yakui_core::event::Event::TextInput(c));
+        }
+
+        yak.start();
+        build(&mut count);
+        yak.finish();
+    }
+
+    let count = count.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+
+    // Left out of the recording, since no frame was started after it.
+    yak.handle_event(yakui_core::event::Event::TextInput('?'));
+
+    let recording = yak.stop_recording().unwrap();
+    assert!(!yak.is_recording());
+    assert_eq!(recording.frames.len(), 3);
+    assert_eq!(recording.frames[1].events.len(), 2);
+    assert_eq!(recording.frames[2].surface_size, Vec2::new(320.0, 240.0));
+    assert_eq!(recording.frames[2].scale_factor, 2.0);
+    assert!(recording.frames[2].time >= recording.frames[1].events[1].time);
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+
+    let mut replayed = Yakui::new();
+    replayed.start_recording();
+
+    let mut replayed_count = None;
+    recording.replay(&mut replayed, || build(&mut replayed_count));
+
+    assert_eq!(replayed_count.unwrap().load(Ordering::SeqCst), 3);
+    assert_eq!(replayed.scale_factor(), 2.0);
+    assert_eq!(replayed.stop_recording().unwrap(), recording);
+}
@@ -0,0 +1,192 @@
+use std::fmt::Debug;
+
+use glam::{UVec2, Vec2};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use yakui_core::event::{Event, EventInterest};
+use yakui_core::geometry::{Color, Constraints, Dim, Dim2, Rect, URect};
+use yakui_core::input::{ClickSettings, KeyCode, Modifiers, MouseButton, ScrollDelta};
+use yakui_core::layout::LayoutDomNode;
+use yakui_core::widget::Widget;
+use yakui_core::{Alignment, Flow, Pivot, Yakui};
+
+fn roundtrip<T>(value: &T, expected: serde_json::Value)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let encoded = serde_json::to_value(value).unwrap();
+    assert_eq!(encoded, expected);
+
+    let decoded: T = serde_json::from_value(encoded).unwrap();
+    assert_eq!(&decoded, value);
+}
+
+#[test]
+fn colors_are_hex_strings() {
+    roundtrip(&Color::rgb(255, 97, 55), json!("#ff6137"));
+    roundtrip(&Color::rgba(0, 16, 255, 128), json!("#0010ff80"));
+
+    let color: Color = serde_json::from_value(json!("FF6137")).unwrap();
+    assert_eq!(color, Color::rgb(255, 97, 55));
+
+    assert!(serde_json::from_value::<Color>(json!("#f80")).is_err());
+    assert!(serde_json::from_value::<Color>(json!("#gg0000")).is_err());
+    assert!(serde_json::from_value::<Color>(json!([255, 0, 0])).is_err());
+}
+
+#[test]
+fn geometry_roundtrips() {
+    roundtrip(
+        &Rect::from_pos_size(Vec2::new(1.0, 2.0), Vec2::new(30.0, 40.0)),
+        json!({ "pos": [1.0, 2.0], "size": [30.0, 40.0] }),
+    );
+    roundtrip(
+        &URect::from_pos_size(UVec2::new(1, 2), UVec2::new(3, 4)),
+        json!({ "pos": [1, 2], "size": [3, 4] }),
+    );
+    roundtrip(
+        &Dim2::new(Dim::pixels(10.0), Dim::percent(0.5)),
+        json!({
+            "x": { "pixels": 10.0, "percent": 0.0 },
+            "y": { "pixels": 0.0, "percent": 0.5 },
+        }),
+    );
+    roundtrip(
+        &Constraints::loose(Vec2::new(100.0, 50.0)),
+        json!({ "min": [0.0, 0.0], "max": [100.0, 50.0] }),
+    );
+    roundtrip(
+        &Constraints::none(),
+        json!({ "min": [0.0, 0.0], "max": ["inf", "inf"] }),
+    );
+    roundtrip(&Alignment::CENTER_RIGHT, json!({ "x": 1.0, "y": 0.5 }));
+    roundtrip(&Pivot::BOTTOM_LEFT, json!({ "x": 0.0, "y": 1.0 }));
+    roundtrip(
+        &Flow::Relative {
+            anchor: Alignment::TOP_LEFT,
+            offset: Dim2::ZERO,
+        },
+        json!({
+            "Relative": {
+                "anchor": { "x": 0.0, "y": 0.0 },
+                "offset": {
+                    "x": { "pixels": 0.0, "percent": 0.0 },
+                    "y": { "pixels": 0.0, "percent": 0.0 },
+                },
+            },
+        }),
+    );
+}
+
+#[test]
+fn input_roundtrips() {
+    roundtrip(&MouseButton::Two, json!("Two"));
+    roundtrip(&KeyCode::KeyA, json!("KeyA"));
+    roundtrip(
+        &(Modifiers::CONTROL | Modifiers::SHIFT),
+        json!("CONTROL | SHIFT"),
+    );
+    roundtrip(&EventInterest::MOUSE_INSIDE, json!("MOUSE_INSIDE"));
+    roundtrip(
+        &ClickSettings::default(),
+        json!({
+            "multi_click_time": { "secs": 0, "nanos": 500_000_000 },
+            "click_distance": 4.0,
+            "long_press_time": { "secs": 0, "nanos": 500_000_000 },
+        }),
+    );
+}
+
+#[test]
+fn events_roundtrip() {
+    let events = [
+        Event::ViewportChanged(Rect::from_pos_size(Vec2::ZERO, Vec2::new(800.0, 600.0))),
+        Event::CursorMoved(Some(Vec2::new(4.0, 5.0))),
+        Event::CursorMoved(None),
+        Event::MouseButtonChanged {
+            button: MouseButton::One,
+            down: true,
+        },
+        Event::MouseButtonChanged {
+            button: MouseButton::Other(8),
+            down: false,
+        },
+        Event::MouseScroll {
+            delta: ScrollDelta::Pixels(Vec2::new(0.0, -20.0)),
+        },
+        Event::MouseScroll {
+            delta: ScrollDelta::Lines(Vec2::new(1.0, 0.0)),
+        },
+        Event::KeyChanged {
+            key: KeyCode::Enter,
+            down: false,
+            modifiers: Some(Modifiers::ALT),
+        },
+        Event::ModifiersChanged(Modifiers::empty()),
+        Event::TextInput('é'),
+        Event::RequestFocus(None),
+        Event::FileDropped {
+            path: "photos/cat.png".into(),
+            position: Some(Vec2::new(4.0, 8.0)),
+        },
+        Event::WindowFocusChanged(false),
+    ];
+
+    for event in events {
+        let encoded = serde_json::to_string(&event).unwrap();
+        let decoded: Event = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(format!("{decoded:?}"), format!("{event:?}"));
+    }
+
+    let event: Event = serde_json::from_value(json!({
+        "MouseButtonChanged": { "button": "Three", "down": true },
+    }))
+    .unwrap();
+    assert!(matches!(
+        event,
+        Event::MouseButtonChanged {
+            button: MouseButton::Three,
+            down: true,
+        }
+    ));
+}
+
+#[derive(Debug)]
+struct FocusWidget;
+
+impl Widget for FocusWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+}
+
+#[test]
+fn layout_output_roundtrips() {
+    let mut yak = Yakui::new();
+    yak.start();
+    let id = yak.dom().do_widget::<FocusWidget>(()).id;
+    yak.finish();
+
+    let node = yak.layout_dom().get(id).unwrap();
+    let encoded = serde_json::to_value(node).unwrap();
+    assert_eq!(encoded["rect"], serde_json::to_value(node.rect).unwrap());
+    assert_eq!(encoded["clipped_by"], json!(null));
+
+    let decoded: LayoutDomNode = serde_json::from_value(encoded).unwrap();
+    assert_eq!(decoded.rect, node.rect);
+    assert_eq!(decoded.constraints, node.constraints);
+    assert_eq!(decoded.event_interest, node.event_interest);
+
+    let event = Event::RequestFocus(Some(id));
+    let encoded = serde_json::to_string(&event).unwrap();
+    let decoded: Event = serde_json::from_str(&encoded).unwrap();
+    assert!(matches!(decoded, Event::RequestFocus(Some(decoded)) if decoded == id));
+
+    assert!(serde_json::from_value::<Event>(json!({ "RequestFocus": 0 })).is_err());
+}
@@ -0,0 +1,39 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+use crate::protocol::{Command, Message};
+
+/// A connection to a [`RemoteServer`][crate::RemoteServer] running in another
+/// process.
+#[derive(Debug)]
+pub struct RemoteClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl RemoteClient {
+    /// Connect to a server at the given address.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let writer = TcpStream::connect(addr)?;
+        let reader = BufReader::new(writer.try_clone()?);
+
+        Ok(Self { reader, writer })
+    }
+
+    /// Send a command to the server.
+    pub fn send(&mut self, command: &Command) -> io::Result<()> {
+        let mut line = command.encode()?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())
+    }
+
+    /// Wait for the next message from the server.
+    pub fn recv(&mut self) -> io::Result<Message> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(Message::decode(&line)?)
+    }
+}
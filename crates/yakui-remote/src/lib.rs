@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+mod client;
+mod server;
+
+pub mod protocol;
+
+pub use self::client::RemoteClient;
+pub use self::server::RemoteServer;
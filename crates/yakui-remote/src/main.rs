@@ -0,0 +1,126 @@
+//! Command line client for `yakui-remote`.
+
+use std::collections::HashMap;
+use std::io;
+use std::process::ExitCode;
+
+use yakui_core::WidgetId;
+use yakui_remote::protocol::{Command, Frame, Message};
+use yakui_remote::RemoteClient;
+
+const USAGE: &str = "usage: yakui-remote <address> <tree | stats | send <command>...>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (Some(addr), Some(action)) = (args.first(), args.get(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let result = match action.as_str() {
+        "tree" => tree(addr),
+        "stats" => stats(addr),
+        "send" => send(addr, &args[2..].join(" ")),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn next_frame(client: &mut RemoteClient) -> io::Result<Frame> {
+    loop {
+        match client.recv()? {
+            Message::Frame(frame) => return Ok(frame),
+            Message::Error(message) => eprintln!("server error: {message}"),
+        }
+    }
+}
+
+fn tree(addr: &str) -> io::Result<()> {
+    let mut client = RemoteClient::connect(addr)?;
+    let frame = next_frame(&mut client)?;
+
+    let mut depths: HashMap<WidgetId, usize> = HashMap::new();
+    for node in &frame.nodes {
+        let depth = node
+            .parent
+            .and_then(|parent| depths.get(&parent))
+            .map_or(0, |depth| depth + 1);
+        depths.insert(node.id, depth);
+
+        let (pos, size) = (node.rect.pos(), node.rect.size());
+        println!(
+            "{:indent$}{} [{}] {}x{} @ {},{}",
+            "",
+            short_name(&node.type_name),
+            node.id.to_bits(),
+            size.x,
+            size.y,
+            pos.x,
+            pos.y,
+            indent = depth * 2,
+        );
+    }
+
+    Ok(())
+}
+
+fn stats(addr: &str) -> io::Result<()> {
+    let mut client = RemoteClient::connect(addr)?;
+
+    loop {
+        let frame = next_frame(&mut client)?;
+        let stats = frame.stats;
+
+        println!(
+            "frame {}: {} widgets, layout {:.2} ms, paint {:.2} ms, {} calls ({} before batching), {} vertices, {} texture uploads ({} bytes)",
+            frame.number,
+            stats.widgets,
+            stats.layout_time.as_secs_f64() * 1000.0,
+            stats.paint_time.as_secs_f64() * 1000.0,
+            stats.paint_calls,
+            stats.batching.calls_before,
+            stats.vertices,
+            stats.texture_uploads,
+            stats.texture_upload_bytes,
+        );
+    }
+}
+
+fn send(addr: &str, line: &str) -> io::Result<()> {
+    let command =
+        Command::decode(line).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+    let mut client = RemoteClient::connect(addr)?;
+    client.send(&Command::Interval(0))?;
+    client.send(&command)
+}
+
+/// Strips module paths from a type name, keeping generic arguments.
+fn short_name(name: &str) -> String {
+    let mut output = String::new();
+    let mut segment = String::new();
+
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            output.push_str(segment.rsplit("::").next().unwrap_or(""));
+            segment.clear();
+            output.push(c);
+        }
+    }
+
+    output.push_str(segment.rsplit("::").next().unwrap_or(""));
+    output
+}
@@ -0,0 +1,100 @@
+//! The protocol spoken between [`RemoteServer`] and [`RemoteClient`].
+//!
+//! Messages and commands are JSON values, one per line. The server sends
+//! [`Message`]s, and clients send [`Command`]s like
+//! `{"Highlight":4294967298}` or `{"Event":{"CursorMoved":[15.0,15.0]}}`.
+//!
+//! [`RemoteServer`]: crate::RemoteServer
+//! [`RemoteClient`]: crate::RemoteClient
+
+use serde::{Deserialize, Serialize};
+use yakui_core::event::Event;
+use yakui_core::geometry::Rect;
+use yakui_core::{FrameStats, WidgetId};
+
+/// A message sent from the server to its clients.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Message {
+    /// The state of the UI after a frame was painted.
+    Frame(Frame),
+
+    /// A command sent by the client couldn't be understood.
+    Error(String),
+}
+
+/// A snapshot of the UI taken after a frame was painted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    /// Counts up by one for every frame the server published.
+    pub number: u64,
+
+    /// The viewport of the UI in physical pixels.
+    pub viewport: Rect,
+
+    /// The scale factor used to lay out the UI.
+    pub scale_factor: f32,
+
+    /// Statistics about the work done to produce this frame.
+    pub stats: FrameStats,
+
+    /// Every widget in the DOM, with parents listed before their children.
+    pub nodes: Vec<Node>,
+}
+
+/// A widget in a [`Frame`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    /// The ID of the widget.
+    pub id: WidgetId,
+
+    /// The parent of the widget, or `None` for the root widget.
+    pub parent: Option<WidgetId>,
+
+    /// The layout rectangle of the widget in logical pixels.
+    pub rect: Rect,
+
+    /// The widget that clips this one, if any.
+    pub clipped_by: Option<WidgetId>,
+
+    /// The type name of the widget.
+    pub type_name: String,
+}
+
+/// A command sent from a client to the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Command {
+    /// Send a frame every given number of frames, or stop sending frames if
+    /// zero. Clients start with an interval of one.
+    Interval(u32),
+
+    /// Outline a widget in the UI, or stop outlining if `None`.
+    Highlight(Option<WidgetId>),
+
+    /// Inject an event into the UI as if it came from the window.
+    Event(Event),
+}
+
+impl Message {
+    /// Writes the message as a single line, without a line break.
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).expect("messages can always be serialized")
+    }
+
+    /// Reads a message from a single line.
+    pub fn decode(line: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(line)
+    }
+}
+
+impl Command {
+    /// Writes the command as a single line, without a line break. Fails if
+    /// the command contains a path that isn't valid UTF-8.
+    pub fn encode(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Reads a command from a single line.
+    pub fn decode(line: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(line)
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{context, WidgetId, Yakui};
+
+use crate::protocol::{Command, Frame, Message, Node};
+
+/// Clients that fall this far behind on reading frames are disconnected.
+const MAX_PENDING_BYTES: usize = 16 * 1024 * 1024;
+
+/// Clients that send a line longer than this are disconnected.
+const MAX_LINE_BYTES: usize = 1024 * 1024;
+
+const HIGHLIGHT_COLOR: Color = Color::rgb(255, 0, 255);
+
+/// A server that lets other processes inspect and drive a [`Yakui`] instance
+/// over TCP.
+///
+/// The server never blocks: sockets are polled whenever [`RemoteServer::update`]
+/// and [`RemoteServer::publish`] are called.
+#[derive(Debug)]
+pub struct RemoteServer {
+    listener: TcpListener,
+    clients: Vec<Client>,
+    frame: u64,
+    highlight: Option<WidgetId>,
+}
+
+#[derive(Debug)]
+struct Client {
+    stream: TcpStream,
+    addr: SocketAddr,
+    incoming: Vec<u8>,
+    outgoing: VecDeque<u8>,
+    interval: u32,
+    closed: bool,
+}
+
+impl RemoteServer {
+    /// Start listening for clients on the given address. Use a loopback
+    /// address like `127.0.0.1:7878` to keep the UI from being reachable by
+    /// other machines.
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            clients: Vec::new(),
+            frame: 0,
+            highlight: None,
+        })
+    }
+
+    /// Returns the address that the server is listening on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the widget that a client asked to highlight, if any.
+    pub fn highlighted(&self) -> Option<WidgetId> {
+        self.highlight
+    }
+
+    /// Accepts new clients and applies the commands they sent.
+    ///
+    /// Should be called once per frame alongside handling window events,
+    /// before [`Yakui::start`].
+    pub fn update(&mut self, yak: &mut Yakui) {
+        profiling::scope!("RemoteServer::update");
+
+        self.accept();
+
+        let mut commands = Vec::new();
+        self.clients.retain_mut(|client| match client.read_lines() {
+            Ok(lines) => {
+                for line in lines {
+                    match Command::decode(&line) {
+                        Ok(Command::Interval(frames)) => client.interval = frames,
+                        Ok(command) => commands.push(command),
+                        Err(err) => client.send(&Message::Error(format!("{err}: {line}"))),
+                    }
+                }
+
+                if client.closed {
+                    log::info!("Remote client {} disconnected", client.addr);
+                }
+                !client.closed
+            }
+            Err(err) => {
+                log::info!("Remote client {} disconnected: {err}", client.addr);
+                false
+            }
+        });
+
+        for command in commands {
+            match command {
+                Command::Highlight(id) => self.highlight = id,
+                Command::Event(event) => {
+                    yak.handle_event(event);
+                }
+                Command::Interval(_) => unreachable!(),
+            }
+        }
+    }
+
+    /// Sends the current state of the UI to all clients that want this frame.
+    ///
+    /// Should be called after [`Yakui::paint`], so that the frame statistics
+    /// are complete.
+    pub fn publish(&mut self, yak: &Yakui) {
+        profiling::scope!("RemoteServer::publish");
+
+        self.frame += 1;
+        let number = self.frame;
+
+        let wanted =
+            |client: &Client| client.interval > 0 && number.is_multiple_of(client.interval as u64);
+        if !self.clients.iter().any(wanted) {
+            self.flush();
+            return;
+        }
+
+        let message = Message::Frame(snapshot(yak, number));
+        let mut encoded = message.encode();
+        encoded.push('\n');
+
+        for client in &mut self.clients {
+            if wanted(client) {
+                client.outgoing.extend(encoded.as_bytes());
+            }
+        }
+
+        self.flush();
+    }
+
+    /// Outlines the highlighted widget, if there is one.
+    ///
+    /// Should be called at the end of building the UI, so that the outline is
+    /// drawn on top. The outline is drawn in its own layer and takes up no
+    /// space.
+    #[track_caller]
+    pub fn show_highlight(&self) {
+        context::dom().do_widget::<HighlightWidget>(self.highlight);
+    }
+
+    fn accept(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, addr)) => {
+                    if let Err(err) = stream.set_nonblocking(true) {
+                        log::warn!("Could not set up remote client {addr}: {err}");
+                        continue;
+                    }
+
+                    log::info!("Remote client {addr} connected");
+                    self.clients.push(Client {
+                        stream,
+                        addr,
+                        incoming: Vec::new(),
+                        outgoing: VecDeque::new(),
+                        interval: 1,
+                        closed: false,
+                    });
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) => {
+                    log::warn!("Could not accept remote client: {err}");
+                    break;
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        self.clients.retain_mut(|client| {
+            if client.outgoing.len() > MAX_PENDING_BYTES {
+                log::warn!("Remote client {} fell too far behind", client.addr);
+                return false;
+            }
+
+            match client.write_pending() {
+                Ok(()) => true,
+                Err(err) => {
+                    log::info!("Remote client {} disconnected: {err}", client.addr);
+                    false
+                }
+            }
+        });
+    }
+}
+
+impl Client {
+    fn send(&mut self, message: &Message) {
+        let mut encoded = message.encode();
+        encoded.push('\n');
+        self.outgoing.extend(encoded.as_bytes());
+    }
+
+    /// Reads all available data, returning any complete lines. Lines sent
+    /// right before the client hung up are still returned. Fails if the
+    /// client sends a line longer than [`MAX_LINE_BYTES`].
+    fn read_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut buffer = [0; 4096];
+        let mut lines = Vec::new();
+
+        loop {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => {
+                    self.closed = true;
+                    break;
+                }
+                Ok(len) => {
+                    self.incoming.extend_from_slice(&buffer[..len]);
+                    self.split_lines(&mut lines);
+
+                    if self.incoming.len() > MAX_LINE_BYTES {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, "line too long"));
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Moves every complete line out of the incoming buffer, leaving only a
+    /// partial line behind.
+    fn split_lines(&mut self, lines: &mut Vec<String>) {
+        while let Some(end) = self.incoming.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.incoming.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+
+            if !line.trim().is_empty() {
+                lines.push(line.to_owned());
+            }
+        }
+    }
+
+    /// Writes as much pending data as the socket will take without blocking.
+    fn write_pending(&mut self) -> io::Result<()> {
+        while !self.outgoing.is_empty() {
+            let (front, _) = self.outgoing.as_slices();
+
+            match self.stream.write(front) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(len) => {
+                    self.outgoing.drain(..len);
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn snapshot(yak: &Yakui, number: u64) -> Frame {
+    let dom = yak.dom();
+    let layout = yak.layout_dom();
+    let mut nodes = Vec::new();
+
+    let mut stack = vec![dom.root()];
+    while let Some(id) = stack.pop() {
+        let Some(node) = dom.get(id) else {
+            continue;
+        };
+        let layout_node = layout.get(id);
+
+        nodes.push(Node {
+            id,
+            parent: node.parent,
+            rect: layout_node.map(|node| node.rect).unwrap_or(Rect::ZERO),
+            clipped_by: layout_node.and_then(|node| node.clipped_by),
+            type_name: node.widget.type_name().to_owned(),
+        });
+
+        stack.extend(node.children.iter().rev());
+    }
+
+    Frame {
+        number,
+        viewport: layout.unscaled_viewport(),
+        scale_factor: layout.scale_factor(),
+        stats: yak.frame_stats(),
+        nodes,
+    }
+}
+
+#[derive(Debug)]
+struct HighlightWidget {
+    target: Option<WidgetId>,
+}
+
+impl Widget for HighlightWidget {
+    type Props<'a> = Option<WidgetId>;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { target: None }
+    }
+
+    fn update(&mut self, target: Self::Props<'_>) -> Self::Response {
+        self.target = target;
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.new_layer(ctx.dom);
+        constraints.constrain_min(Vec2::ZERO)
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let Some(node) = self.target.and_then(|id| ctx.layout.get(id)) else {
+            return;
+        };
+
+        let (pos, size) = (node.rect.pos(), node.rect.size());
+        let width = 2.0;
+        let edges = [
+            Rect::from_pos_size(pos, Vec2::new(size.x, width)),
+            Rect::from_pos_size(
+                Vec2::new(pos.x, pos.y + size.y - width),
+                Vec2::new(size.x, width),
+            ),
+            Rect::from_pos_size(pos, Vec2::new(width, size.y)),
+            Rect::from_pos_size(
+                Vec2::new(pos.x + size.x - width, pos.y),
+                Vec2::new(width, size.y),
+            ),
+        ];
+
+        for edge in edges {
+            let mut rect = PaintRect::new(edge);
+            rect.brush = HIGHLIGHT_COLOR.into();
+            rect.add(ctx.paint);
+        }
+    }
+}
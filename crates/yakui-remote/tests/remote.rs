@@ -0,0 +1,203 @@
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use yakui_core::event::Event;
+use yakui_core::geometry::{Constraints, Rect, Vec2};
+use yakui_core::input::{KeyCode, MouseButton, ScrollDelta};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{context, FrameStats, Yakui};
+use yakui_remote::protocol::{Command, Frame, Message, Node};
+use yakui_remote::{RemoteClient, RemoteServer};
+
+#[derive(Debug)]
+struct FixedWidget;
+
+impl Widget for FixedWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, _ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        constraints.constrain_min(Vec2::new(40.0, 20.0))
+    }
+}
+
+fn build(yak: &mut Yakui, server: &RemoteServer) {
+    yak.start();
+    context::dom().do_widget::<FixedWidget>(());
+    server.show_highlight();
+    yak.finish();
+    yak.paint();
+}
+
+#[test]
+fn frames_roundtrip() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let root = context::dom().root();
+    context::dom().do_widget::<FixedWidget>(());
+    yak.finish();
+    yak.paint();
+
+    let child = yak.dom().get(root).unwrap().children[0];
+    let message = Message::Frame(Frame {
+        number: 7,
+        viewport: yak.layout_dom().unscaled_viewport(),
+        scale_factor: 1.5,
+        stats: FrameStats {
+            widgets: 2,
+            layout_time: Duration::from_micros(250),
+            paint_calls: 3,
+            ..Default::default()
+        },
+        nodes: vec![
+            Node {
+                id: root,
+                parent: None,
+                rect: Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)),
+                clipped_by: None,
+                type_name: "yakui_core::dom::root::RootWidget".to_owned(),
+            },
+            Node {
+                id: child,
+                parent: Some(root),
+                rect: Rect::from_pos_size(Vec2::new(1.5, 2.0), Vec2::new(40.0, 20.0)),
+                clipped_by: Some(root),
+                type_name: "remote::FixedWidget<Option<u32>>".to_owned(),
+            },
+        ],
+    });
+
+    let encoded = message.encode();
+    assert!(!encoded.contains('\n'));
+    assert_eq!(Message::decode(&encoded).unwrap(), message);
+}
+
+#[test]
+fn commands_roundtrip() {
+    let commands = [
+        Command::Interval(5),
+        Command::Highlight(None),
+        Command::Event(Event::CursorMoved(Some(Vec2::new(10.0, 20.5)))),
+        Command::Event(Event::MouseButtonChanged {
+            button: MouseButton::Other(9),
+            down: true,
+        }),
+        Command::Event(Event::MouseScroll {
+            delta: ScrollDelta::Lines(Vec2::new(1.5, 0.0)),
+        }),
+        Command::Event(Event::KeyChanged {
+            key: KeyCode::Enter,
+            down: false,
+            modifiers: None,
+        }),
+        Command::Event(Event::TextInput('a')),
+        Command::Event(Event::FileDropped {
+            path: PathBuf::from("/tmp/my photo.png"),
+            position: Some(Vec2::new(12.0, 30.0)),
+        }),
+        Command::Event(Event::WindowFocusChanged(false)),
+    ];
+
+    for command in commands {
+        let encoded = command.encode().unwrap();
+        assert!(!encoded.contains('\n'));
+        assert_eq!(Command::decode(&encoded).unwrap(), command);
+    }
+
+    assert_eq!(
+        Command::decode(r#"{"Event":{"CursorMoved":[15.0,15.0]}}"#).unwrap(),
+        Command::Event(Event::CursorMoved(Some(Vec2::new(15.0, 15.0))))
+    );
+    assert!(Command::decode("highlight none").is_err());
+    assert!(Command::decode(r#"{"Teleport":[1,2]}"#).is_err());
+}
+
+#[test]
+fn server_streams_frames_and_applies_commands() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+
+    let mut server = RemoteServer::bind("127.0.0.1:0").unwrap();
+    let mut client = RemoteClient::connect(server.local_addr().unwrap()).unwrap();
+
+    build(&mut yak, &server);
+    let root = yak.dom().root();
+    let child = yak.dom().get(root).unwrap().children[0];
+
+    let viewport = Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0));
+    client
+        .send(&Command::Event(Event::ViewportChanged(viewport)))
+        .unwrap();
+    client.send(&Command::Highlight(Some(child))).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while server.highlighted().is_none() {
+        assert!(Instant::now() < deadline, "server never received commands");
+        std::thread::sleep(Duration::from_millis(5));
+        server.update(&mut yak);
+    }
+
+    assert_eq!(server.highlighted(), Some(child));
+
+    build(&mut yak, &server);
+    server.publish(&yak);
+
+    let Message::Frame(frame) = client.recv().unwrap() else {
+        panic!("expected a frame");
+    };
+
+    assert_eq!(frame.number, 1);
+    assert_eq!(frame.viewport.size(), Vec2::new(200.0, 100.0));
+    assert_eq!(frame.nodes[0].id, root);
+
+    let node = frame.nodes.iter().find(|node| node.id == child).unwrap();
+    assert_eq!(node.parent, Some(root));
+    assert_eq!(node.rect, yak.layout_dom().get(child).unwrap().rect);
+    assert!(node.type_name.ends_with("FixedWidget"));
+    assert_eq!(frame.stats.widgets, yak.dom().len());
+}
+
+#[test]
+fn server_disconnects_clients_sending_overlong_lines() {
+    let mut yak = Yakui::new();
+    let mut server = RemoteServer::bind("127.0.0.1:0").unwrap();
+
+    let mut stream = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    let mut reader = stream.try_clone().unwrap();
+    reader
+        .set_read_timeout(Some(Duration::from_millis(5)))
+        .unwrap();
+
+    // The writes fail once the server hangs up, so they go on another thread.
+    let writer = std::thread::spawn(move || {
+        let chunk = vec![b'x'; 64 * 1024];
+        while stream.write_all(&chunk).is_ok() {}
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        assert!(Instant::now() < deadline, "server never disconnected");
+        server.update(&mut yak);
+
+        match reader.read(&mut [0; 64]) {
+            Ok(0) => break,
+            Err(err)
+                if err.kind() == ErrorKind::WouldBlock || err.kind() == ErrorKind::TimedOut => {}
+            Err(_) => break,
+            Ok(_) => panic!("server sent data to a client that sent no commands"),
+        }
+    }
+
+    writer.join().unwrap();
+}
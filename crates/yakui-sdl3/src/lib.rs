@@ -1,19 +1,24 @@
 mod keys;
 
+use std::ffi::CStr;
+use std::path::PathBuf;
+
 use sdl3::event::{Event as SdlEvent, WindowEvent};
 use sdl3::mouse::MouseButton as SdlMouseButton;
+use sdl3::sys::events::{SDL_Event, SDL_EventType};
 use sdl3::sys::keyboard::{SDL_StartTextInput, SDL_StopTextInput};
 use sdl3::sys::video::SDL_GetWindowDisplayScale;
 use sdl3::video::Window;
 use yakui_core::event::Event;
 use yakui_core::geometry::{Rect, UVec2, Vec2};
-use yakui_core::input::MouseButton;
+use yakui_core::input::{MouseButton, ScrollDelta};
 
 use self::keys::{from_sdl_modifiers, from_sdl_scancode};
 
 pub struct YakuiSdl3 {
     init: Option<InitState>,
     text_input_enabled: bool,
+    dropped_file: bool,
 }
 
 struct InitState {
@@ -21,6 +26,13 @@ struct InitState {
     scale: f32,
 }
 
+/// One step of files being dragged onto the window.
+enum DropEvent {
+    Hover,
+    File(PathBuf),
+    Complete,
+}
+
 fn scale_factor(window: &Window) -> f32 {
     unsafe { SDL_GetWindowDisplayScale(window.raw()) }
 }
@@ -33,6 +45,7 @@ impl YakuiSdl3 {
         Self {
             init: Some(InitState { size, scale }),
             text_input_enabled: false,
+            dropped_file: false,
         }
     }
 
@@ -50,12 +63,34 @@ impl YakuiSdl3 {
         }
     }
 
+    /// Like [`YakuiSdl3::handle_event`], but takes a raw SDL event.
+    ///
+    /// Events from the sdl3 crate leave out where files are dragged and
+    /// dropped, so applications that poll raw events and pass them here get
+    /// files dropped under the cursor instead of at its last known position.
+    pub fn handle_raw_event(&mut self, state: &mut yakui_core::Yakui, event: &SDL_Event) -> bool {
+        let event_type = SDL_EventType(unsafe { event.r#type });
+        let drop_event = match event_type {
+            SDL_EventType::DROP_BEGIN | SDL_EventType::DROP_POSITION => DropEvent::Hover,
+            SDL_EventType::DROP_FILE if unsafe { !event.drop.data.is_null() } => {
+                let path = unsafe { CStr::from_ptr(event.drop.data) }.to_string_lossy();
+                DropEvent::File(path.into_owned().into())
+            }
+            SDL_EventType::DROP_COMPLETE => DropEvent::Complete,
+            _ => return self.handle_event(state, &SdlEvent::from_ll(*event)),
+        };
+
+        // SDL doesn't report a position when a drag starts.
+        let drop = unsafe { event.drop };
+        let position =
+            (event_type != SDL_EventType::DROP_BEGIN).then_some(Vec2::new(drop.x, drop.y));
+
+        self.init(state);
+        self.handle_drop(state, drop_event, position)
+    }
+
     pub fn handle_event(&mut self, state: &mut yakui_core::Yakui, event: &SdlEvent) -> bool {
-        if let Some(init) = self.init.take() {
-            state.set_surface_size(init.size.as_vec2());
-            state.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, init.size.as_vec2()));
-            state.set_scale_factor(init.scale);
-        }
+        self.init(state);
 
         match event {
             SdlEvent::Window { win_event, .. } => {
@@ -70,6 +105,9 @@ impl YakuiSdl3 {
 
                     WindowEvent::MouseLeave => state.handle_event(Event::CursorMoved(None)),
 
+                    WindowEvent::FocusGained => state.handle_event(Event::WindowFocusChanged(true)),
+                    WindowEvent::FocusLost => state.handle_event(Event::WindowFocusChanged(false)),
+
                     // FIXME: scale factor changed
                     _ => false,
                 }
@@ -81,22 +119,16 @@ impl YakuiSdl3 {
             }
 
             SdlEvent::MouseButtonDown { mouse_btn, .. } => {
-                let button = match mouse_btn {
-                    SdlMouseButton::Left => MouseButton::One,
-                    SdlMouseButton::Right => MouseButton::Two,
-                    SdlMouseButton::Middle => MouseButton::Three,
-                    _ => return false,
+                let Some(button) = from_sdl_button(*mouse_btn) else {
+                    return false;
                 };
 
                 state.handle_event(Event::MouseButtonChanged { button, down: true })
             }
 
             SdlEvent::MouseButtonUp { mouse_btn, .. } => {
-                let button = match mouse_btn {
-                    SdlMouseButton::Left => MouseButton::One,
-                    SdlMouseButton::Right => MouseButton::Two,
-                    SdlMouseButton::Middle => MouseButton::Three,
-                    _ => return false,
+                let Some(button) = from_sdl_button(*mouse_btn) else {
+                    return false;
                 };
 
                 state.handle_event(Event::MouseButtonChanged {
@@ -105,15 +137,24 @@ impl YakuiSdl3 {
                 })
             }
 
-            SdlEvent::MouseWheel { x, y, .. } => {
-                // Observed logical pixels per scroll wheel increment in Windows on Chrome
-                const LINE_HEIGHT: f32 = 100.0 / 3.0;
+            // SDL reports scrolling in wheel notches, even for trackpads.
+            SdlEvent::MouseWheel { x, y, .. } => state.handle_event(Event::MouseScroll {
+                delta: ScrollDelta::Lines(Vec2::new(*x, -*y)),
+            }),
 
-                state.handle_event(Event::MouseScroll {
-                    delta: Vec2::new(*x, -*y) * LINE_HEIGHT,
-                })
+            SdlEvent::DropBegin { .. } => self.handle_drop(state, DropEvent::Hover, None),
+
+            // The sdl3 crate doesn't know about drag position events yet.
+            SdlEvent::Unknown { type_, .. } if *type_ == SDL_EventType::DROP_POSITION.0 => {
+                self.handle_drop(state, DropEvent::Hover, None)
+            }
+
+            SdlEvent::DropFile { filename, .. } => {
+                self.handle_drop(state, DropEvent::File(filename.into()), None)
             }
 
+            SdlEvent::DropComplete { .. } => self.handle_drop(state, DropEvent::Complete, None),
+
             SdlEvent::TextInput { text, .. } => {
                 for c in text.chars() {
                     state.handle_event(Event::TextInput(c));
@@ -153,4 +194,54 @@ impl YakuiSdl3 {
             _ => false,
         }
     }
+
+    fn init(&mut self, state: &mut yakui_core::Yakui) {
+        if let Some(init) = self.init.take() {
+            state.set_surface_size(init.size.as_vec2());
+            state.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, init.size.as_vec2()));
+            state.set_scale_factor(init.scale);
+        }
+    }
+
+    fn handle_drop(
+        &mut self,
+        state: &mut yakui_core::Yakui,
+        drop_event: DropEvent,
+        position: Option<Vec2>,
+    ) -> bool {
+        match drop_event {
+            // SDL only tells us which files are being dragged once they're
+            // dropped.
+            DropEvent::Hover => state.handle_event(Event::FileHovered {
+                path: PathBuf::new(),
+                position,
+            }),
+
+            DropEvent::File(path) => {
+                self.dropped_file = true;
+                state.handle_event(Event::FileDropped { path, position })
+            }
+
+            // A drag that ends without any files dropped left the window or
+            // was cancelled.
+            DropEvent::Complete => {
+                if std::mem::take(&mut self.dropped_file) {
+                    false
+                } else {
+                    state.handle_event(Event::FileHoverCancelled)
+                }
+            }
+        }
+    }
+}
+
+fn from_sdl_button(button: SdlMouseButton) -> Option<MouseButton> {
+    match button {
+        SdlMouseButton::Left => Some(MouseButton::One),
+        SdlMouseButton::Right => Some(MouseButton::Two),
+        SdlMouseButton::Middle => Some(MouseButton::Three),
+        SdlMouseButton::X1 => Some(MouseButton::Back),
+        SdlMouseButton::X2 => Some(MouseButton::Forward),
+        SdlMouseButton::Unknown => None,
+    }
 }
@@ -1,9 +1,13 @@
 use std::collections::VecDeque;
 use std::fmt::Write as _;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 
 use yakui_core::dom::Dom;
 use yakui_core::geometry::{Rect, Vec2};
 use yakui_core::layout::LayoutDom;
+use yakui_core::recording::Recording;
 
 pub extern crate insta;
 pub extern crate yakui_core;
@@ -41,6 +45,29 @@ macro_rules! run {
     };
 }
 
+/// Replays a [`Recording`] into a new `Yakui`, evaluating the body to build
+/// each frame, then snapshots the final frame like [`run!`].
+#[macro_export]
+macro_rules! replay {
+    ($recording:expr, $body:expr) => {
+        let mut settings = ::yakui_test::insta::Settings::clone_current();
+        settings.set_prepend_module_to_snapshot(false);
+        let _guard = settings.bind_to_scope();
+
+        let recording: ::yakui_test::yakui_core::recording::Recording = $recording;
+        let mut state = ::yakui_test::yakui_core::Yakui::new();
+        recording.replay(&mut state, || $body);
+
+        ::yakui_test::literally_snapshot!(state);
+
+        let dom = state.dom();
+        let layout = state.layout_dom();
+        let view = ::yakui_test::view(dom, layout);
+
+        ::yakui_test::insta::assert_snapshot!(view);
+    };
+}
+
 #[macro_export]
 #[cfg(not(feature = "images"))]
 macro_rules! literally_snapshot {
@@ -77,6 +104,20 @@ impl Test {
     }
 }
 
+/// Loads a recording saved as JSON, like one captured with
+/// [`Yakui::start_recording`][yakui_core::Yakui::start_recording] and saved
+/// with `serde_json`. Tests run from the root of the crate being tested, so
+/// relative paths start there.
+pub fn load_recording(path: impl AsRef<Path>) -> Recording {
+    let path = path.as_ref();
+
+    let file = File::open(path)
+        .unwrap_or_else(|err| panic!("could not open recording {}: {err}", path.display()));
+
+    serde_json::from_reader(BufReader::new(file))
+        .unwrap_or_else(|err| panic!("could not read recording {}: {err}", path.display()))
+}
+
 pub fn view(dom: &Dom, layout: &LayoutDom) -> String {
     let mut output = String::new();
     let mut to_visit = VecDeque::new();
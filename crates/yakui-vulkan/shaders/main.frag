@@ -5,6 +5,7 @@
 
 layout (location = 0) in vec4 in_color;
 layout (location = 1) in vec2 in_uv;
+layout (location = 2) in float in_opacity;
 layout (location = 0) out vec4 out_color;
 
 layout(set = 0, binding = 0) uniform sampler2D textures[1000];
@@ -16,10 +17,7 @@ layout(push_constant) uniform push_constants {
 void main() {
     if (texture_id == NO_TEXTURE) {
         out_color = in_color;
-        return;
-    } 
-    
-    if (workflow == WORKFLOW_TEXT) {
+    } else if (workflow == WORKFLOW_TEXT) {
         vec4 coverage = texture(textures[texture_id], in_uv);
 
         if (in_color.a > 0.0) {
@@ -33,4 +31,8 @@ void main() {
         vec4 user_texture = texture(textures[texture_id], in_uv);
         out_color = in_color * user_texture;
     }
+
+    // Color glyphs are marked with a transparent vertex color, so opacity
+    // comes from its own input to fade them as well.
+    out_color.a *= in_opacity;
 }
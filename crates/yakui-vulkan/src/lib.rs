@@ -38,15 +38,17 @@ pub struct YakuiVulkan {
     index_buffer: Buffer<u32>,
     /// A single vertex buffer, shared between all draw calls
     vertex_buffer: Buffer<Vertex>,
-    /// Have we synced the first textures from yakui?
-    initial_textures_synced: bool,
     /// Textures owned by yakui
     yakui_managed_textures: HashMap<ManagedTextureId, VulkanTexture>,
+    /// The version of each yakui texture that was last uploaded
+    yakui_managed_versions: HashMap<ManagedTextureId, Option<u64>>,
     /// Textures owned by the user
     user_textures: thunderdome::Arena<VulkanTexture>,
     /// A wrapper around descriptor set functionality
     descriptors: Descriptors,
     uploads: UploadQueue,
+    /// Callbacks for regions painted by the application
+    custom_callbacks: HashMap<u64, CustomCallback>,
 }
 
 /// Vulkan configuration
@@ -68,8 +70,28 @@ struct DrawCall {
     clip: Option<yakui_core::geometry::Rect>,
     texture_id: u32,
     workflow: Workflow,
+    /// Set for regions painted by a custom callback: the handle, normalized bounds, and opacity
+    custom: Option<(u64, yakui_core::geometry::Rect, f32)>,
 }
 
+/// Describes a region that the application asked to paint into itself with
+/// [`yakui_core::paint::PaintDom::add_custom`].
+#[derive(Clone, Debug)]
+pub struct CustomDraw {
+    /// The handle that the region was added with
+    pub handle: u64,
+    /// The region to paint into, in physical pixels
+    pub rect: yakui_core::geometry::Rect,
+    /// The clipping rect that has been applied as the scissor, if any
+    pub clip: Option<yakui_core::geometry::Rect>,
+    /// The opacity that the region should be painted with
+    pub opacity: f32,
+    /// The resolution passed to [`YakuiVulkan::paint`], in physical pixels
+    pub resolution: UVec2,
+}
+
+type CustomCallback = Box<dyn FnMut(vk::CommandBuffer, &CustomDraw) + Send>;
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 /// Push constant used to determine texture and workflow
@@ -106,6 +128,12 @@ impl From<yakui_core::paint::Pipeline> for Workflow {
         match p {
             yakui_core::paint::Pipeline::Main => Workflow::Main,
             yakui_core::paint::Pipeline::Text => Workflow::Text,
+
+            // Groups are drawn inline and custom regions are drawn by callbacks,
+            // so these are never used for drawing.
+            yakui_core::paint::Pipeline::Group(_) | yakui_core::paint::Pipeline::Custom(_) => {
+                Workflow::Main
+            }
         }
     }
 }
@@ -116,6 +144,7 @@ struct Vertex {
     position: yakui_core::geometry::Vec2,
     texcoord: yakui_core::geometry::Vec2,
     color: yakui_core::geometry::Vec4,
+    opacity: f32,
 }
 
 impl From<&YakuiVertex> for Vertex {
@@ -124,6 +153,7 @@ impl From<&YakuiVertex> for Vertex {
             position: y.position,
             texcoord: y.texcoord,
             color: y.color,
+            opacity: y.opacity,
         }
     }
 }
@@ -223,6 +253,13 @@ impl YakuiVulkan {
                 format: vk::Format::R32G32B32A32_SFLOAT,
                 offset: bytemuck::offset_of!(Vertex, color) as _,
             },
+            // opacity
+            vk::VertexInputAttributeDescription {
+                location: 3,
+                binding: 0,
+                format: vk::Format::R32_SFLOAT,
+                offset: bytemuck::offset_of!(Vertex, opacity) as _,
+            },
         ];
 
         let vertex_input_state_info = vk::PipelineVertexInputStateCreateInfo::default()
@@ -334,8 +371,9 @@ impl YakuiVulkan {
             vertex_buffer,
             user_textures: Default::default(),
             yakui_managed_textures: Default::default(),
-            initial_textures_synced: false,
+            yakui_managed_versions: Default::default(),
             uploads: UploadQueue::new(),
+            custom_callbacks: Default::default(),
         }
     }
 
@@ -383,6 +421,11 @@ impl YakuiVulkan {
 
     /// Paint the yakui GUI using the provided [`VulkanContext`]
     ///
+    /// `cmd` may be rendering into any image, like an offscreen one that's later sampled to show
+    /// the GUI on a surface in a 3D world. `resolution` should match the surface size given to
+    /// yakui. Vertex and index buffers are shared between all calls to `paint`, so use a separate
+    /// [`YakuiVulkan`] for each surface painted within the same frame.
+    ///
     /// ## Safety
     /// - `vulkan_context` must be the same as the one used to create this [`YakuiVulkan`] instance
     /// - `cmd` must be in rendering state, with viewport and scissor dynamic states set.
@@ -404,26 +447,34 @@ impl YakuiVulkan {
         self.render(vulkan_context, resolution, cmd, &draw_calls);
     }
 
-    /// Render the draw calls we've built up
-    fn render(
-        &self,
-        vulkan_context: &VulkanContext,
-        resolution: vk::Extent2D,
-        command_buffer: vk::CommandBuffer,
-        draw_calls: &[DrawCall],
-    ) {
-        let device = vulkan_context.device;
+    /// Register a callback that paints regions added with
+    /// [`yakui_core::paint::PaintDom::add_custom`] using the given handle, replacing any existing
+    /// callback for it.
+    ///
+    /// The callback receives the command buffer in rendering state, with the scissor for the region
+    /// already applied. It can change any state it wants; the pipeline, buffers, descriptor sets,
+    /// viewport, and scissor are restored afterwards.
+    pub fn set_custom_callback<F>(&mut self, handle: u64, callback: F)
+    where
+        F: FnMut(vk::CommandBuffer, &CustomDraw) + Send + 'static,
+    {
+        self.custom_callbacks.insert(handle, Box::new(callback));
+    }
 
-        let surface_size = UVec2::new(resolution.width, resolution.height);
+    /// Remove the callback registered for the given handle. Regions using it will no longer be
+    /// painted.
+    pub fn remove_custom_callback(&mut self, handle: u64) {
+        self.custom_callbacks.remove(&handle);
+    }
 
+    /// Bind the pipeline, buffers and descriptor sets used by every draw call
+    fn bind_state(&self, device: &ash::Device, command_buffer: vk::CommandBuffer) {
         unsafe {
             device.cmd_bind_pipeline(
                 command_buffer,
                 vk::PipelineBindPoint::GRAPHICS,
                 self.graphics_pipeline,
             );
-            let default_scissor = [resolution.into()];
-
             device.cmd_bind_vertex_buffers(command_buffer, 0, &[self.vertex_buffer.handle], &[0]);
             device.cmd_bind_index_buffer(
                 command_buffer,
@@ -439,6 +490,25 @@ impl YakuiVulkan {
                 std::slice::from_ref(&self.descriptors.set),
                 &[],
             );
+        }
+    }
+
+    /// Render the draw calls we've built up
+    fn render(
+        &mut self,
+        vulkan_context: &VulkanContext,
+        resolution: vk::Extent2D,
+        command_buffer: vk::CommandBuffer,
+        draw_calls: &[DrawCall],
+    ) {
+        let device = vulkan_context.device;
+
+        let surface_size = UVec2::new(resolution.width, resolution.height);
+
+        unsafe {
+            self.bind_state(device, command_buffer);
+            let default_scissor = [resolution.into()];
+
             let mut last_clip = None;
             for draw_call in draw_calls {
                 if draw_call.clip != last_clip {
@@ -486,6 +556,46 @@ impl YakuiVulkan {
                     }
                 }
 
+                if let Some((handle, bounds, opacity)) = draw_call.custom {
+                    let Some(callback) = self.custom_callbacks.get_mut(&handle) else {
+                        continue;
+                    };
+
+                    let size = surface_size.as_vec2();
+                    callback(
+                        command_buffer,
+                        &CustomDraw {
+                            handle,
+                            rect: yakui_core::geometry::Rect::from_pos_size(
+                                bounds.pos() * size,
+                                bounds.size() * size,
+                            ),
+                            clip: draw_call.clip,
+                            opacity,
+                            resolution: surface_size,
+                        },
+                    );
+
+                    // The callback is free to change any state, so restore everything we use.
+                    self.bind_state(device, command_buffer);
+                    device.cmd_set_viewport(
+                        command_buffer,
+                        0,
+                        &[vk::Viewport {
+                            x: 0.0,
+                            y: 0.0,
+                            width: size.x,
+                            height: size.y,
+                            min_depth: 0.0,
+                            max_depth: 1.0,
+                        }],
+                    );
+                    device.cmd_set_scissor(command_buffer, 0, &default_scissor);
+                    last_clip = None;
+
+                    continue;
+                }
+
                 // Instead of using different pipelines for text and non-text rendering, we just
                 // pass the "workflow" down through a push constant and branch in the shader.
                 device.cmd_push_constants(
@@ -572,63 +682,47 @@ impl YakuiVulkan {
         vulkan_context: &VulkanContext,
         paint: &yakui_core::paint::PaintDom,
     ) {
-        use yakui_core::paint::TextureChange;
-        if !self.initial_textures_synced {
-            self.initial_textures_synced = true;
-            let textures = paint.textures();
-
-            for (id, texture) in textures.iter() {
-                let texture = VulkanTexture::from_yakui_texture(
-                    vulkan_context,
-                    &mut self.descriptors,
-                    texture,
-                    &mut self.uploads,
-                );
-                self.yakui_managed_textures.insert(id, texture);
-            }
-
-            return;
-        }
-
         let textures = paint.textures();
 
-        for (id, change) in textures.edits() {
-            match change {
-                TextureChange::Added => {
-                    let texture = textures.get(id).unwrap();
-                    let texture = VulkanTexture::from_yakui_texture(
-                        vulkan_context,
-                        &mut self.descriptors,
-                        texture,
-                        &mut self.uploads,
-                    );
-                    self.yakui_managed_textures.insert(id, texture);
+        // Texture edits are cleared whenever any PaintDom sharing this storage
+        // starts a frame, so we compare versions instead. This keeps us in sync
+        // when drawing several forked yakui instances.
+        let removed: Vec<_> = self
+            .yakui_managed_textures
+            .keys()
+            .copied()
+            .filter(|&id| textures.get(id).is_none())
+            .collect();
+
+        for id in removed {
+            self.yakui_managed_versions.remove(&id);
+            if let Some(removed) = self.yakui_managed_textures.remove(&id) {
+                unsafe {
+                    self.uploads.dispose(removed);
                 }
+            }
+        }
 
-                TextureChange::Removed => {
-                    if let Some(removed) = self.yakui_managed_textures.remove(&id) {
-                        unsafe {
-                            self.uploads.dispose(removed);
-                        }
-                    }
-                }
+        for (id, texture) in textures.iter() {
+            let version = textures.version(id);
+            if self.yakui_managed_versions.get(&id) == Some(&version) {
+                continue;
+            }
 
-                TextureChange::Modified => {
-                    if let Some(old) = self.yakui_managed_textures.remove(&id) {
-                        unsafe {
-                            self.uploads.dispose(old);
-                        }
-                    }
-                    let new = textures.get(id).unwrap();
-                    let texture = VulkanTexture::from_yakui_texture(
-                        vulkan_context,
-                        &mut self.descriptors,
-                        new,
-                        &mut self.uploads,
-                    );
-                    self.yakui_managed_textures.insert(id, texture);
+            if let Some(old) = self.yakui_managed_textures.remove(&id) {
+                unsafe {
+                    self.uploads.dispose(old);
                 }
             }
+
+            let texture = VulkanTexture::from_yakui_texture(
+                vulkan_context,
+                &mut self.descriptors,
+                texture,
+                &mut self.uploads,
+            );
+            self.yakui_managed_textures.insert(id, texture);
+            self.yakui_managed_versions.insert(id, version);
         }
     }
 
@@ -644,40 +738,14 @@ impl YakuiVulkan {
         let calls = paint.layers().iter().flat_map(|layer| &layer.calls);
 
         for call in calls {
-            let base = vertices.len() as u32;
-            let index_offset = indices.len() as u32;
-            let index_count = call.indices.len() as u32;
-
-            for index in &call.indices {
-                indices.push(*index as u32 + base);
-            }
-            for vertex in &call.vertices {
-                vertices.push(vertex.into())
-            }
-
-            let texture_id = call
-                .texture
-                .and_then(|id| match id {
-                    yakui_core::TextureId::Managed(managed) => {
-                        let texture = self.yakui_managed_textures.get(&managed)?;
-                        Some(texture.id)
-                    }
-                    yakui_core::TextureId::User(bits) => {
-                        let texture = self
-                            .user_textures
-                            .get(thunderdome::Index::from_bits(bits)?)?;
-                        Some(texture.id)
-                    }
-                })
-                .unwrap_or(NO_TEXTURE_ID);
-
-            draw_calls.push(DrawCall {
-                index_offset,
-                index_count,
-                clip: call.clip,
-                texture_id,
-                workflow: call.pipeline.into(),
-            });
+            self.push_draw_call(
+                paint,
+                call,
+                1.0,
+                &mut vertices,
+                &mut indices,
+                &mut draw_calls,
+            );
         }
 
         unsafe {
@@ -687,4 +755,87 @@ impl YakuiVulkan {
 
         draw_calls
     }
+
+    fn push_draw_call(
+        &self,
+        paint: &yakui_core::paint::PaintDom,
+        call: &yakui_core::paint::PaintCall,
+        opacity: f32,
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u32>,
+        draw_calls: &mut Vec<DrawCall>,
+    ) {
+        // We don't render groups offscreen, so their contents are drawn inline
+        // and faded per-vertex instead.
+        if let yakui_core::paint::Pipeline::Group(index) = call.pipeline {
+            let group_opacity = call.vertices.first().map_or(1.0, |vertex| vertex.opacity);
+
+            for group_call in &paint.groups()[index].calls {
+                self.push_draw_call(
+                    paint,
+                    group_call,
+                    opacity * group_opacity,
+                    vertices,
+                    indices,
+                    draw_calls,
+                );
+            }
+
+            return;
+        }
+
+        let base = vertices.len() as u32;
+        let index_offset = indices.len() as u32;
+        let index_count = call.indices.len() as u32;
+
+        for index in &call.indices {
+            indices.push(*index + base);
+        }
+        for vertex in &call.vertices {
+            let mut vertex: Vertex = vertex.into();
+            vertex.opacity *= opacity;
+            vertices.push(vertex);
+        }
+
+        let custom = match call.pipeline {
+            yakui_core::paint::Pipeline::Custom(handle) => {
+                let mut min = yakui_core::geometry::Vec2::MAX;
+                let mut max = yakui_core::geometry::Vec2::MIN;
+                for vertex in &call.vertices {
+                    min = min.min(vertex.position);
+                    max = max.max(vertex.position);
+                }
+
+                let opacity = call.vertices.first().map_or(1.0, |v| v.opacity) * opacity;
+                let bounds = yakui_core::geometry::Rect::from_pos_size(min, max - min);
+                Some((handle, bounds, opacity))
+            }
+            _ => None,
+        };
+
+        let texture_id = call
+            .texture
+            .and_then(|id| match id {
+                yakui_core::TextureId::Managed(managed) => {
+                    let texture = self.yakui_managed_textures.get(&managed)?;
+                    Some(texture.id)
+                }
+                yakui_core::TextureId::User(bits) => {
+                    let texture = self
+                        .user_textures
+                        .get(thunderdome::Index::from_bits(bits)?)?;
+                    Some(texture.id)
+                }
+            })
+            .unwrap_or(NO_TEXTURE_ID);
+
+        draw_calls.push(DrawCall {
+            index_offset,
+            index_count,
+            clip: call.clip,
+            texture_id,
+            workflow: call.pipeline.into(),
+            custom,
+        });
+    }
 }
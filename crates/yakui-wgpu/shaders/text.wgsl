@@ -2,12 +2,14 @@ struct VertexInput {
     @location(0) position: vec2<f32>,
     @location(1) texcoord: vec2<f32>,
     @location(2) color: vec4<f32>,
+    @location(3) opacity: f32,
 };
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) texcoord: vec2<f32>,
     @location(1) color: vec4<f32>,
+    @location(2) opacity: f32,
 };
 
 @group(0) @binding(0) var coverage_texture: texture_2d<f32>;
@@ -26,6 +28,7 @@ fn vs_main(
     out.position = vec4<f32>(adjusted, 0.0, 1.0);
     out.texcoord = in.texcoord;
     out.color = in.color;
+    out.opacity = in.opacity;
     return out;
 }
 
@@ -33,11 +36,13 @@ fn vs_main(
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let coverage = textureSample(coverage_texture, coverage_sampler, in.texcoord);
 
+    // Color glyphs are marked with a transparent vertex color, so opacity
+    // comes from its own attribute to fade them as well.
     if in.color.a > 0.0 {
-        let alpha = max(max(coverage.r, coverage.g), coverage.b) * in.color.a * coverage.a;
+        let alpha = max(max(coverage.r, coverage.g), coverage.b) * in.color.a * coverage.a * in.opacity;
 
         return vec4(in.color.rgb * alpha, alpha);
     } else {
-        return coverage;
+        return coverage * in.opacity;
     }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use glam::UVec2;
+use yakui_core::geometry::Rect;
+
+/// Describes a region that the application asked to paint into itself with
+/// `PaintDom::add_custom`.
+#[derive(Debug, Clone)]
+pub struct CustomDraw {
+    /// The handle that the region was added with.
+    pub handle: u64,
+
+    /// The region to paint into, in physical pixels.
+    pub rect: Rect,
+
+    /// The clipping rect that has been applied as the scissor rect, if any.
+    pub clip: Option<Rect>,
+
+    /// The opacity that the region should be painted with.
+    pub opacity: f32,
+
+    /// The size of the texture being rendered into.
+    pub target_size: UVec2,
+
+    /// The format of the texture being rendered into.
+    pub format: wgpu::TextureFormat,
+
+    /// The sample count of the render pass. Offscreen groups are always
+    /// rendered with a sample count of 1.
+    pub sample_count: u32,
+}
+
+pub(crate) type CustomCallback = Box<dyn FnMut(&mut wgpu::RenderPass<'_>, &CustomDraw) + Send>;
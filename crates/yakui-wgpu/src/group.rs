@@ -0,0 +1,58 @@
+use glam::UVec2;
+
+use crate::bindgroup_cache;
+use crate::samplers::Samplers;
+
+/// An offscreen texture that a paint group is rendered into before being
+/// composited onto the surface.
+pub(crate) struct GroupTarget {
+    pub size: UVec2,
+    pub format: wgpu::TextureFormat,
+    pub view: wgpu::TextureView,
+    pub bind_group: wgpu::BindGroup,
+}
+
+impl GroupTarget {
+    pub fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        samplers: &Samplers,
+        size: UVec2,
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("yakui Group Texture"),
+            size: wgpu::Extent3d {
+                width: size.x.max(1),
+                height: size.y.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            view_formats: &[],
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = bindgroup_cache::bindgroup(
+            device,
+            layout,
+            samplers,
+            &view,
+            wgpu::FilterMode::Nearest,
+            wgpu::FilterMode::Nearest,
+            wgpu::MipmapFilterMode::Nearest,
+            wgpu::AddressMode::ClampToEdge,
+        );
+
+        Self {
+            size,
+            format,
+            view,
+            bind_group,
+        }
+    }
+}
@@ -3,7 +3,10 @@
 
 mod bindgroup_cache;
 mod buffer;
+mod custom;
+mod group;
 mod pipeline_cache;
+mod render_texture;
 mod samplers;
 mod texture;
 
@@ -16,20 +19,26 @@ use buffer::Buffer;
 use bytemuck::{Pod, Zeroable};
 use glam::UVec2;
 use thunderdome::{Arena, Index};
-use yakui_core::geometry::{Rect, Vec2, Vec4};
-use yakui_core::paint::{PaintDom, PaintLimits, Pipeline, Texture, TextureChange, TextureFormat};
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::paint::{PaintCall, PaintDom, PaintLimits, Pipeline, Texture, TextureFormat};
 use yakui_core::{ManagedTextureId, TextureId};
 
 use self::bindgroup_cache::TextureBindgroupCache;
 use self::bindgroup_cache::TextureBindgroupCacheEntry;
+use self::custom::CustomCallback;
+use self::group::GroupTarget;
 use self::pipeline_cache::PipelineCache;
 use self::samplers::Samplers;
 use self::texture::{GpuManagedTexture, GpuTexture};
 
+pub use self::custom::CustomDraw;
+pub use self::render_texture::RenderTexture;
+
 pub struct YakuiWgpu {
     limits: PaintLimits,
     main_pipeline: PipelineCache,
     text_pipeline: PipelineCache,
+    composite_pipeline: PipelineCache,
 
     premul_pipeline: wgpu::RenderPipeline,
     premul_bind_group_layout: wgpu::BindGroupLayout,
@@ -38,10 +47,14 @@ pub struct YakuiWgpu {
     textures: Arena<GpuTexture>,
     managed_textures: HashMap<ManagedTextureId, GpuManagedTexture>,
     texture_bindgroup_cache: TextureBindgroupCache,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    group_targets: Vec<GroupTarget>,
 
     vertices: Buffer,
     indices: Buffer,
     commands: Vec<DrawCommand>,
+    group_commands: Vec<Vec<DrawCommand>>,
+    custom_callbacks: HashMap<u64, CustomCallback>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,7 +70,9 @@ pub struct SurfaceInfo<'a> {
 struct Vertex {
     pos: Vec2,
     texcoord: Vec2,
-    color: Vec4,
+    // glam's Vec4 is 16-byte aligned, which would pad this struct out.
+    color: [f32; 4],
+    opacity: f32,
 }
 
 impl Vertex {
@@ -68,6 +83,7 @@ impl Vertex {
             0 => Float32x2,
             1 => Float32x2,
             2 => Float32x4,
+            3 => Float32,
         ],
     };
 }
@@ -118,6 +134,14 @@ impl YakuiWgpu {
 
         let text_pipeline = PipelineCache::new(pipeline_layout);
 
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("yakui Composite Pipeline Layout"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+
+        let composite_pipeline = PipelineCache::new(pipeline_layout);
+
         let samplers = Samplers::new(device);
 
         let premul_bind_group_layout =
@@ -209,19 +233,44 @@ impl YakuiWgpu {
             limits,
             main_pipeline,
             text_pipeline,
+            composite_pipeline,
             samplers,
             premul_pipeline,
             premul_bind_group_layout,
             textures: Arena::new(),
             managed_textures: HashMap::new(),
 
-            texture_bindgroup_cache: TextureBindgroupCache::new(layout, default_bindgroup),
+            texture_bindgroup_cache: TextureBindgroupCache::new(layout.clone(), default_bindgroup),
+            texture_bind_group_layout: layout,
+            group_targets: Vec::new(),
             vertices: Buffer::new(wgpu::BufferUsages::VERTEX),
             indices: Buffer::new(wgpu::BufferUsages::INDEX),
             commands: Vec::new(),
+            group_commands: Vec::new(),
+            custom_callbacks: HashMap::new(),
         }
     }
 
+    /// Registers a callback that paints regions added with
+    /// [`PaintDom::add_custom`] using the given handle, replacing any existing
+    /// callback for it.
+    ///
+    /// The callback receives the render pass with the scissor rect for the
+    /// region already applied. It can change any state it wants; yakui will
+    /// restore its own state afterwards.
+    pub fn set_custom_callback<F>(&mut self, handle: u64, callback: F)
+    where
+        F: FnMut(&mut wgpu::RenderPass<'_>, &CustomDraw) + Send + 'static,
+    {
+        self.custom_callbacks.insert(handle, Box::new(callback));
+    }
+
+    /// Removes the callback registered for the given handle. Regions using it
+    /// will no longer be painted.
+    pub fn remove_custom_callback(&mut self, handle: u64) {
+        self.custom_callbacks.remove(&handle);
+    }
+
     /// Creates a `TextureId` from an existing wgpu texture that then be used by
     /// any yakui widgets.
     pub fn add_texture(
@@ -278,6 +327,43 @@ impl YakuiWgpu {
         encoder.finish()
     }
 
+    /// Clears the given texture and paints into it, like [`YakuiWgpu::paint`].
+    ///
+    /// Every surface painted before the returned command buffer is submitted
+    /// shares the same vertex buffers. Use a separate `YakuiWgpu` for each
+    /// surface, or submit between calls.
+    #[must_use = "YakuiWgpu::paint_to_texture returns a command buffer which MUST be submitted to wgpu."]
+    pub fn paint_to_texture(
+        &mut self,
+        state: &mut yakui_core::Yakui,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        target: &RenderTexture,
+        clear: wgpu::Color,
+    ) -> wgpu::CommandBuffer {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("yakui Render Texture Encoder"),
+        });
+
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("yakui Render Texture Clear Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target.view(),
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            ..Default::default()
+        });
+
+        self.paint_with_encoder(state, device, queue, &mut encoder, target.surface_info());
+
+        encoder.finish()
+    }
+
     pub fn paint_with_encoder(
         &mut self,
         state: &mut yakui_core::Yakui,
@@ -300,12 +386,60 @@ impl YakuiWgpu {
 
         self.update_buffers(device, paint);
 
+        if paint.surface_size() == Vec2::ZERO {
+            return;
+        }
+
+        let surface_size = paint.surface_size().as_uvec2();
+        self.update_group_targets(device, paint.groups().len(), surface_size, surface.format);
+
         let vertices = self.vertices.upload(device, queue);
         let indices = self.indices.upload(device, queue);
-        let commands = &self.commands;
 
-        if paint.surface_size() == Vec2::ZERO {
-            return;
+        // Groups can contain other groups, which always come after them in the
+        // list. Rendering them in reverse makes sure that nested groups are
+        // ready by the time they're composited.
+        for (index, commands) in self.group_commands.iter().enumerate().rev() {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("yakui Group Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.group_targets[index].view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+
+            let mut ctx = PassContext {
+                pipelines: Pipelines {
+                    main: self
+                        .main_pipeline
+                        .get(device, surface.format, 1, make_main_pipeline),
+                    text: self
+                        .text_pipeline
+                        .get(device, surface.format, 1, make_text_pipeline),
+                    composite: self.composite_pipeline.get(
+                        device,
+                        surface.format,
+                        1,
+                        make_composite_pipeline,
+                    ),
+                },
+                bind_groups: &self.texture_bindgroup_cache,
+                group_targets: &self.group_targets,
+                custom_callbacks: &mut self.custom_callbacks,
+                vertices,
+                indices,
+                target_size: surface_size,
+                format: surface.format,
+                sample_count: 1,
+            };
+
+            draw_commands(&mut render_pass, commands, &mut ctx);
         }
 
         {
@@ -323,70 +457,63 @@ impl YakuiWgpu {
                 ..Default::default()
             });
 
-            render_pass.set_vertex_buffer(0, vertices.slice(..));
-            render_pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
+            let mut ctx = PassContext {
+                pipelines: Pipelines {
+                    main: self.main_pipeline.get(
+                        device,
+                        surface.format,
+                        surface.sample_count,
+                        make_main_pipeline,
+                    ),
+                    text: self.text_pipeline.get(
+                        device,
+                        surface.format,
+                        surface.sample_count,
+                        make_text_pipeline,
+                    ),
+                    composite: self.composite_pipeline.get(
+                        device,
+                        surface.format,
+                        surface.sample_count,
+                        make_composite_pipeline,
+                    ),
+                },
+                bind_groups: &self.texture_bindgroup_cache,
+                group_targets: &self.group_targets,
+                custom_callbacks: &mut self.custom_callbacks,
+                vertices,
+                indices,
+                target_size: surface_size,
+                format: surface.format,
+                sample_count: surface.sample_count,
+            };
+
+            draw_commands(&mut render_pass, &self.commands, &mut ctx);
+        }
+    }
 
-            let mut last_clip = None;
+    fn update_group_targets(
+        &mut self,
+        device: &wgpu::Device,
+        count: usize,
+        size: UVec2,
+        format: wgpu::TextureFormat,
+    ) {
+        profiling::scope!("update_group_targets");
 
-            let main_pipeline = self.main_pipeline.get(
-                device,
-                surface.format,
-                surface.sample_count,
-                make_main_pipeline,
-            );
+        // Targets are reused between frames, so only textures that don't match
+        // the surface anymore need to be recreated.
+        self.group_targets
+            .retain(|target| target.size == size && target.format == format);
 
-            let text_pipeline = self.text_pipeline.get(
+        while self.group_targets.len() < count {
+            self.group_targets.push(GroupTarget::new(
                 device,
-                surface.format,
-                surface.sample_count,
-                make_text_pipeline,
-            );
-
-            for command in commands {
-                match command.pipeline {
-                    Pipeline::Main => render_pass.set_pipeline(main_pipeline),
-                    Pipeline::Text => render_pass.set_pipeline(text_pipeline),
-                }
-
-                if command.clip != last_clip {
-                    last_clip = command.clip;
-
-                    let surface = paint.surface_size().as_uvec2();
-
-                    match command.clip {
-                        Some(rect) => {
-                            let pos = rect.pos().as_uvec2();
-                            let size = rect.size().as_uvec2();
-
-                            let max = (pos + size).min(surface);
-                            let size = UVec2::new(
-                                max.x.saturating_sub(pos.x),
-                                max.y.saturating_sub(pos.y),
-                            );
-
-                            // If the scissor rect isn't valid, we can skip this
-                            // entire draw call.
-                            if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0
-                            {
-                                continue;
-                            }
-
-                            render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
-                        }
-                        None => {
-                            render_pass.set_scissor_rect(0, 0, surface.x, surface.y);
-                        }
-                    }
-                }
-
-                let bindgroup = command
-                    .bind_group_entry
-                    .map(|entry| self.texture_bindgroup_cache.get(&entry))
-                    .unwrap_or(&self.texture_bindgroup_cache.default);
-
-                render_pass.set_bind_group(0, bindgroup, &[]);
-                render_pass.draw_indexed(command.index_range.clone(), 0, 0..1);
-            }
+                &self.texture_bind_group_layout,
+                &self.samplers,
+                size,
+                format,
+            ));
         }
     }
 
@@ -396,83 +523,96 @@ impl YakuiWgpu {
         self.vertices.clear();
         self.indices.clear();
         self.commands.clear();
+        self.group_commands.clear();
         self.texture_bindgroup_cache.clear();
 
-        let commands = paint
-            .layers()
-            .iter()
-            .flat_map(|layer| &layer.calls)
-            .map(|call| {
-                let vertices = call.vertices.iter().map(|vertex| Vertex {
-                    pos: vertex.position,
-                    texcoord: vertex.texcoord,
-                    color: vertex.color,
-                });
-
-                let base = self.vertices.len() as u32;
-                let indices = call.indices.iter().map(|&index| base + index as u32);
-
-                let start = self.indices.len() as u32;
-                let end = start + indices.len() as u32;
-
-                self.vertices.extend(vertices);
-                self.indices.extend(indices);
-
-                let bind_group_entry = call
-                    .texture
-                    .and_then(|id| match id {
-                        TextureId::Managed(managed) => {
-                            let texture = self.managed_textures.get(&managed)?;
-                            Some((
-                                id,
-                                &texture.view,
-                                texture.min_filter,
-                                texture.mag_filter,
-                                wgpu::MipmapFilterMode::Nearest,
-                                texture.address_mode,
-                            ))
-                        }
-                        TextureId::User(bits) => {
-                            let index = Index::from_bits(bits)?;
-                            let texture = self.textures.get(index)?;
-                            Some((
-                                id,
-                                &texture.view,
-                                texture.min_filter,
-                                texture.mag_filter,
-                                texture.mipmap_filter,
-                                texture.address_mode,
-                            ))
-                        }
-                    })
-                    .map(
-                        |(id, view, min_filter, mag_filter, mipmap_filter, address_mode)| {
-                            let entry = TextureBindgroupCacheEntry {
-                                id,
-                                min_filter,
-                                mag_filter,
-                                mipmap_filter,
-                                address_mode,
-                            };
-                            self.texture_bindgroup_cache.update(
-                                device,
-                                entry,
-                                view,
-                                &self.samplers,
-                            );
-                            entry
-                        },
-                    );
+        for call in paint.layers().iter().flat_map(|layer| &layer.calls) {
+            let command = self.push_call(device, call);
+            self.commands.push(command);
+        }
+
+        for group in paint.groups() {
+            let commands = group
+                .calls
+                .iter()
+                .map(|call| self.push_call(device, call))
+                .collect();
+            self.group_commands.push(commands);
+        }
+    }
 
-                DrawCommand {
-                    index_range: start..end,
-                    bind_group_entry,
-                    pipeline: call.pipeline,
-                    clip: call.clip,
+    fn push_call(&mut self, device: &wgpu::Device, call: &PaintCall) -> DrawCommand {
+        let vertices = call.vertices.iter().map(|vertex| Vertex {
+            pos: vertex.position,
+            texcoord: vertex.texcoord,
+            color: vertex.color.to_array(),
+            opacity: vertex.opacity,
+        });
+
+        let base = self.vertices.len() as u32;
+        let indices = call.indices.iter().map(|&index| base + index);
+
+        let start = self.indices.len() as u32;
+        let end = start + indices.len() as u32;
+
+        self.vertices.extend(vertices);
+        self.indices.extend(indices);
+
+        let bind_group_entry = call
+            .texture
+            .and_then(|id| match id {
+                TextureId::Managed(managed) => {
+                    let texture = self.managed_textures.get(&managed)?;
+                    Some((
+                        id,
+                        &texture.view,
+                        texture.min_filter,
+                        texture.mag_filter,
+                        wgpu::MipmapFilterMode::Nearest,
+                        texture.address_mode,
+                    ))
                 }
-            });
+                TextureId::User(bits) => {
+                    let index = Index::from_bits(bits)?;
+                    let texture = self.textures.get(index)?;
+                    Some((
+                        id,
+                        &texture.view,
+                        texture.min_filter,
+                        texture.mag_filter,
+                        texture.mipmap_filter,
+                        texture.address_mode,
+                    ))
+                }
+            })
+            .map(
+                |(id, view, min_filter, mag_filter, mipmap_filter, address_mode)| {
+                    let entry = TextureBindgroupCacheEntry {
+                        id,
+                        min_filter,
+                        mag_filter,
+                        mipmap_filter,
+                        address_mode,
+                    };
+                    self.texture_bindgroup_cache
+                        .update(device, entry, view, &self.samplers);
+                    entry
+                },
+            );
 
-        self.commands.extend(commands);
+        let (bounds, opacity) = match call.pipeline {
+            Pipeline::Custom(_) => custom_region(call),
+            _ => (Rect::ZERO, 1.0),
+        };
+
+        DrawCommand {
+            index_range: start..end,
+            bind_group_entry,
+            pipeline: call.pipeline,
+            clip: call.clip,
+            bounds,
+            opacity,
+        }
     }
 
     fn update_textures(&mut self, device: &wgpu::Device, paint: &PaintDom, queue: &wgpu::Queue) {
@@ -480,54 +620,39 @@ impl YakuiWgpu {
 
         let textures = paint.textures();
 
-        {
-            for (id, texture) in textures.iter() {
-                self.managed_textures.entry(id).or_insert_with(|| {
-                    GpuManagedTexture::new(
+        // Texture edits are cleared whenever any PaintDom sharing this storage
+        // starts a frame, so we compare versions instead. This keeps us in sync
+        // when drawing several forked yakui instances.
+        self.managed_textures
+            .retain(|&id, _| textures.get(id).is_some());
+
+        for (id, texture) in textures.iter() {
+            let version = textures.version(id);
+
+            match self.managed_textures.get_mut(&id) {
+                Some(existing) if existing.version == version => {}
+                Some(existing) => {
+                    existing.update(
                         device,
                         queue,
                         texture,
                         &self.premul_pipeline,
                         &self.premul_bind_group_layout,
                         &self.samplers,
-                    )
-                });
-            }
-        }
-
-        for (id, change) in textures.edits() {
-            match change {
-                TextureChange::Added => {
-                    let texture = textures.get(id).unwrap();
-                    self.managed_textures.insert(
-                        id,
-                        GpuManagedTexture::new(
-                            device,
-                            queue,
-                            texture,
-                            &self.premul_pipeline,
-                            &self.premul_bind_group_layout,
-                            &self.samplers,
-                        ),
                     );
+                    existing.version = version;
                 }
-
-                TextureChange::Removed => {
-                    self.managed_textures.remove(&id);
-                }
-
-                TextureChange::Modified => {
-                    if let Some(existing) = self.managed_textures.get_mut(&id) {
-                        let texture = textures.get(id).unwrap();
-                        existing.update(
-                            device,
-                            queue,
-                            texture,
-                            &self.premul_pipeline,
-                            &self.premul_bind_group_layout,
-                            &self.samplers,
-                        );
-                    }
+                None => {
+                    let mut new = GpuManagedTexture::new(
+                        device,
+                        queue,
+                        texture,
+                        &self.premul_pipeline,
+                        &self.premul_bind_group_layout,
+                        &self.samplers,
+                    );
+                    new.version = version;
+                    self.managed_textures.insert(id, new);
                 }
             }
         }
@@ -539,6 +664,135 @@ struct DrawCommand {
     bind_group_entry: Option<TextureBindgroupCacheEntry>,
     pipeline: Pipeline,
     clip: Option<Rect>,
+
+    /// The normalized bounds and opacity of custom paint regions.
+    bounds: Rect,
+    opacity: f32,
+}
+
+struct Pipelines<'a> {
+    main: &'a wgpu::RenderPipeline,
+    text: &'a wgpu::RenderPipeline,
+    composite: &'a wgpu::RenderPipeline,
+}
+
+struct PassContext<'a> {
+    pipelines: Pipelines<'a>,
+    bind_groups: &'a TextureBindgroupCache,
+    group_targets: &'a [GroupTarget],
+    custom_callbacks: &'a mut HashMap<u64, CustomCallback>,
+    vertices: &'a wgpu::Buffer,
+    indices: &'a wgpu::Buffer,
+    target_size: UVec2,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+}
+
+impl PassContext<'_> {
+    fn bind_buffers(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+        render_pass.set_index_buffer(self.indices.slice(..), wgpu::IndexFormat::Uint32);
+    }
+}
+
+fn draw_commands(
+    render_pass: &mut wgpu::RenderPass<'_>,
+    commands: &[DrawCommand],
+    ctx: &mut PassContext<'_>,
+) {
+    let surface = ctx.target_size;
+    let mut last_clip = None;
+
+    ctx.bind_buffers(render_pass);
+
+    for command in commands {
+        match command.pipeline {
+            Pipeline::Main => render_pass.set_pipeline(ctx.pipelines.main),
+            Pipeline::Text => render_pass.set_pipeline(ctx.pipelines.text),
+            Pipeline::Group(_) => render_pass.set_pipeline(ctx.pipelines.composite),
+            Pipeline::Custom(_) => {}
+        }
+
+        if command.clip != last_clip {
+            last_clip = command.clip;
+
+            match command.clip {
+                Some(rect) => {
+                    let pos = rect.pos().as_uvec2();
+                    let size = rect.size().as_uvec2();
+
+                    let max = (pos + size).min(surface);
+                    let size = UVec2::new(max.x.saturating_sub(pos.x), max.y.saturating_sub(pos.y));
+
+                    // If the scissor rect isn't valid, we can skip this
+                    // entire draw call.
+                    if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0 {
+                        continue;
+                    }
+
+                    render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
+                }
+                None => {
+                    render_pass.set_scissor_rect(0, 0, surface.x, surface.y);
+                }
+            }
+        }
+
+        if let Pipeline::Custom(handle) = command.pipeline {
+            let Some(callback) = ctx.custom_callbacks.get_mut(&handle) else {
+                continue;
+            };
+
+            let draw = CustomDraw {
+                handle,
+                rect: Rect::from_pos_size(
+                    command.bounds.pos() * surface.as_vec2(),
+                    command.bounds.size() * surface.as_vec2(),
+                ),
+                clip: command.clip,
+                opacity: command.opacity,
+                target_size: surface,
+                format: ctx.format,
+                sample_count: ctx.sample_count,
+            };
+            callback(render_pass, &draw);
+
+            // The callback is free to change any state, so we need to restore
+            // everything we depend on.
+            render_pass.set_viewport(0.0, 0.0, surface.x as f32, surface.y as f32, 0.0, 1.0);
+            render_pass.set_scissor_rect(0, 0, surface.x, surface.y);
+            ctx.bind_buffers(render_pass);
+            last_clip = None;
+
+            continue;
+        }
+
+        let bindgroup = match command.pipeline {
+            Pipeline::Group(index) => &ctx.group_targets[index].bind_group,
+            _ => command
+                .bind_group_entry
+                .map(|entry| ctx.bind_groups.get(&entry))
+                .unwrap_or(&ctx.bind_groups.default),
+        };
+
+        render_pass.set_bind_group(0, bindgroup, &[]);
+        render_pass.draw_indexed(command.index_range.clone(), 0, 0..1);
+    }
+}
+
+fn custom_region(call: &PaintCall) -> (Rect, f32) {
+    let Some(first) = call.vertices.first() else {
+        return (Rect::ZERO, 1.0);
+    };
+
+    let (min, max) = call
+        .vertices
+        .iter()
+        .fold((first.position, first.position), |(min, max), vertex| {
+            (min.min(vertex.position), max.max(vertex.position))
+        });
+
+    (Rect::from_pos_size(min, max - min), first.opacity)
 }
 
 fn make_main_pipeline(
@@ -638,3 +892,52 @@ fn make_text_pipeline(
         cache: None,
     })
 }
+
+fn make_composite_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    let composite_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Composite Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/composite.wgsl").into()),
+    });
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("yakui Composite Pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: &composite_shader,
+            entry_point: None,
+            compilation_options: Default::default(),
+            buffers: &[Vertex::DESCRIPTOR],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &composite_shader,
+            entry_point: None,
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: None,
+            polygon_mode: wgpu::PolygonMode::Fill,
+            unclipped_depth: false,
+            conservative: false,
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: samples,
+            ..Default::default()
+        },
+        multiview_mask: None,
+        cache: None,
+    })
+}
@@ -1,6 +1,6 @@
 pub struct PipelineCache {
     layout: wgpu::PipelineLayout,
-    current: Option<Cached>,
+    cached: Vec<Cached>,
 }
 
 struct Cached {
@@ -13,7 +13,7 @@ impl PipelineCache {
     pub fn new(layout: wgpu::PipelineLayout) -> Self {
         Self {
             layout,
-            current: None,
+            cached: Vec::new(),
         }
     }
 
@@ -32,19 +32,27 @@ impl PipelineCache {
             u32,
         ) -> wgpu::RenderPipeline,
     {
-        match &mut self.current {
-            Some(existing) if existing.format == format && existing.samples == samples => (),
-            _ => {
+        // Offscreen groups are always single-sampled, so we may need to keep
+        // around a pipeline for them as well as one for the surface.
+        let index = self
+            .cached
+            .iter()
+            .position(|existing| existing.format == format && existing.samples == samples);
+
+        let index = match index {
+            Some(index) => index,
+            None => {
                 let pipeline = init(device, &self.layout, format, samples);
 
-                self.current = Some(Cached {
+                self.cached.push(Cached {
                     pipeline,
                     format,
                     samples,
                 });
+                self.cached.len() - 1
             }
-        }
+        };
 
-        &self.current.as_ref().unwrap().pipeline
+        &self.cached[index].pipeline
     }
 }
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use glam::UVec2;
+
+use crate::SurfaceInfo;
+
+/// A texture that yakui can be painted into instead of a window surface, like
+/// a screen inside of a 3D world.
+///
+/// The yakui instance drawn into it should have its surface size and viewport
+/// set to the size of the texture. Input can be driven with
+/// `Yakui::handle_cursor_uv` using the UV coordinate of a ray hit.
+pub struct RenderTexture {
+    texture: wgpu::Texture,
+    view: Arc<wgpu::TextureView>,
+    format: wgpu::TextureFormat,
+    size: UVec2,
+}
+
+impl RenderTexture {
+    pub fn new(device: &wgpu::Device, size: UVec2, format: wgpu::TextureFormat) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("yakui Render Texture"),
+            size: wgpu::Extent3d {
+                width: size.x.max(1),
+                height: size.y.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            view_formats: &[],
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        });
+
+        let view = Arc::new(texture.create_view(&wgpu::TextureViewDescriptor::default()));
+
+        Self {
+            texture,
+            view,
+            format,
+            size,
+        }
+    }
+
+    /// Recreates the texture if its size changed. Views returned before
+    /// resizing will keep pointing to the old texture.
+    pub fn resize(&mut self, device: &wgpu::Device, size: UVec2) {
+        if size != self.size {
+            *self = Self::new(device, size, self.format);
+        }
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// The view of the texture, which can be sampled by the rest of the scene
+    /// or passed to `YakuiWgpu::add_texture` to show it in another yakui
+    /// instance.
+    pub fn view(&self) -> &Arc<wgpu::TextureView> {
+        &self.view
+    }
+
+    pub fn surface_info(&self) -> SurfaceInfo<'_> {
+        SurfaceInfo {
+            format: self.format,
+            sample_count: 1,
+            color_attachment: &self.view,
+            resolve_target: None,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use glam::UVec2;
 use yakui_core::paint::{Texture, TextureFilter, TextureFormat};
 
 pub(crate) struct GpuManagedTexture {
+    /// The version of the yakui texture that was last uploaded.
+    pub version: Option<u64>,
     size: UVec2,
     format: TextureFormat,
     gpu_texture: wgpu::Texture,
@@ -81,6 +83,7 @@ impl GpuManagedTexture {
         let address_mode = wgpu_address_mode(texture.address_mode);
 
         Self {
+            version: None,
             size: texture.size(),
             format: texture.format(),
             gpu_texture,
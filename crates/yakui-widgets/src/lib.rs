@@ -21,6 +21,8 @@ pub mod clipboard;
 pub mod border;
 pub mod colors;
 pub mod font;
+pub mod path;
+pub mod shadow;
 pub mod shapes;
 pub mod shorthand;
 pub mod style;
@@ -0,0 +1,1047 @@
+//! Vector paths built from lines, curves and arcs, which can be filled or
+//! stroked onto a [`PaintDom`], like from inside a
+//! [`Canvas`][crate::widgets::Canvas].
+//!
+//! ```no_run
+//! # use yakui_core::geometry::{Color, Vec2};
+//! # use yakui_core::paint::PaintDom;
+//! # use yakui_widgets::path::{Path, Stroke, LineCap};
+//! # fn draw(paint: &mut PaintDom) {
+//! let mut wire = Path::new();
+//! wire.move_to(Vec2::new(0.0, 0.0)).cubic_to(
+//!     Vec2::new(100.0, 0.0),
+//!     Vec2::new(0.0, 100.0),
+//!     Vec2::new(100.0, 100.0),
+//! );
+//!
+//! wire.stroke(paint, &Stroke::new(3.0, Color::WHITE).cap(LineCap::Round));
+//! # }
+//! ```
+
+use std::f32::consts::PI;
+
+use yakui_core::geometry::{Brush, Rect, Vec2, Vec4};
+use yakui_core::paint::{PaintDom, PaintMesh, Vertex};
+
+use crate::auto_builders;
+
+/// How far flattened curves may stray from the real curve, in physical pixels.
+const TOLERANCE: f32 = 0.25;
+
+/// Epsilon used to discard degenerate geometry, in logical pixels.
+const EPSILON: f32 = 1e-4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo(Vec2),
+    LineTo(Vec2),
+    QuadTo(Vec2, Vec2),
+    CubicTo(Vec2, Vec2, Vec2),
+    Arc {
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+    },
+    Close,
+}
+
+/// Decides which parts of a self-intersecting or nested path are filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    #[default]
+    NonZero,
+    EvenOdd,
+}
+
+impl FillRule {
+    fn contains(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    #[default]
+    Miter,
+    Bevel,
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    #[default]
+    Butt,
+    Square,
+    Round,
+}
+
+/// Describes how the outline of a [`Path`] is drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub width: f32,
+    pub color: Brush,
+    pub join: LineJoin,
+    pub cap: LineCap,
+
+    /// Miter joins longer than this many times the stroke width are drawn as
+    /// bevel joins instead.
+    pub miter_limit: f32,
+
+    /// Alternating lengths of dashes and gaps. The line is solid if empty.
+    pub dashes: Vec<f32>,
+
+    /// How far into the dash pattern the line starts.
+    pub dash_offset: f32,
+}
+
+auto_builders!(Stroke {
+    width: f32,
+    color: Brush,
+    join: LineJoin,
+    cap: LineCap,
+    miter_limit: f32,
+    dashes: Vec<f32>,
+    dash_offset: f32,
+});
+
+impl Stroke {
+    pub fn new(width: f32, color: impl Into<Brush>) -> Self {
+        Self {
+            width,
+            color: color.into(),
+            join: LineJoin::default(),
+            cap: LineCap::default(),
+            miter_limit: 4.0,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+}
+
+/// A flattened piece of a path made only of straight lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<Vec2>,
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an open path going through all of the given points.
+    pub fn polyline<I: IntoIterator<Item = Vec2>>(points: I) -> Self {
+        let mut path = Self::new();
+        let mut points = points.into_iter();
+
+        if let Some(first) = points.next() {
+            path.move_to(first);
+        }
+
+        for point in points {
+            path.line_to(point);
+        }
+
+        path
+    }
+
+    /// Create a closed path going through all of the given points.
+    pub fn polygon<I: IntoIterator<Item = Vec2>>(points: I) -> Self {
+        let mut path = Self::polyline(points);
+        path.close();
+        path
+    }
+
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Start a new subpath at the given point.
+    pub fn move_to(&mut self, to: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo(to));
+        self
+    }
+
+    pub fn line_to(&mut self, to: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::LineTo(to));
+        self
+    }
+
+    /// Draw a quadratic Bézier curve to `to`.
+    pub fn quad_to(&mut self, control: Vec2, to: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::QuadTo(control, to));
+        self
+    }
+
+    /// Draw a cubic Bézier curve to `to`.
+    pub fn cubic_to(&mut self, control1: Vec2, control2: Vec2, to: Vec2) -> &mut Self {
+        self.commands
+            .push(PathCommand::CubicTo(control1, control2, to));
+        self
+    }
+
+    /// Draw a circular arc, connected to the current subpath with a straight
+    /// line if there is one. Angles are in radians, with positive angles
+    /// going clockwise on screen.
+    pub fn arc(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+    ) -> &mut Self {
+        self.commands.push(PathCommand::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        });
+        self
+    }
+
+    /// Close the current subpath with a straight line back to its start.
+    pub fn close(&mut self) -> &mut Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// Turn the path into straight lines that are never further than
+    /// `tolerance` from the real path.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Polyline> {
+        let tolerance = tolerance.max(EPSILON);
+        let mut lines = Vec::new();
+        let mut current = Polyline::default();
+
+        for command in &self.commands {
+            let last = current.points.last().copied();
+
+            match *command {
+                PathCommand::MoveTo(to) => {
+                    finish(&mut lines, &mut current);
+                    current.points.push(to);
+                }
+
+                PathCommand::LineTo(to) => push_point(&mut current, to),
+
+                PathCommand::QuadTo(control, to) => {
+                    let Some(from) = last else {
+                        push_point(&mut current, to);
+                        continue;
+                    };
+
+                    let dd = (from - 2.0 * control + to).length();
+                    let segments = curve_segments(dd / (4.0 * tolerance));
+
+                    for i in 1..=segments {
+                        let t = i as f32 / segments as f32;
+                        let mt = 1.0 - t;
+                        let point = mt * mt * from + 2.0 * mt * t * control + t * t * to;
+                        push_point(&mut current, point);
+                    }
+                }
+
+                PathCommand::CubicTo(control1, control2, to) => {
+                    let Some(from) = last else {
+                        push_point(&mut current, to);
+                        continue;
+                    };
+
+                    let dd = (from - 2.0 * control1 + control2)
+                        .length()
+                        .max((control1 - 2.0 * control2 + to).length());
+                    let segments = curve_segments(3.0 * dd / (4.0 * tolerance));
+
+                    for i in 1..=segments {
+                        let t = i as f32 / segments as f32;
+                        let mt = 1.0 - t;
+                        let point = mt * mt * mt * from
+                            + 3.0 * mt * mt * t * control1
+                            + 3.0 * mt * t * t * control2
+                            + t * t * t * to;
+                        push_point(&mut current, point);
+                    }
+                }
+
+                PathCommand::Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    sweep_angle,
+                } => {
+                    let segments = arc_segments(radius, sweep_angle, tolerance);
+
+                    for i in 0..=segments {
+                        let angle = start_angle + sweep_angle * i as f32 / segments as f32;
+                        let (y, x) = angle.sin_cos();
+                        push_point(&mut current, center + Vec2::new(x, y) * radius);
+                    }
+                }
+
+                PathCommand::Close => {
+                    if let Some(&first) = current.points.first() {
+                        current.closed = true;
+                        finish(&mut lines, &mut current);
+
+                        // Following commands continue from the start of the
+                        // closed subpath.
+                        current.points.push(first);
+                    }
+                }
+            }
+        }
+
+        finish(&mut lines, &mut current);
+        lines
+    }
+
+    /// Fill the inside of the path, as decided by `rule`. Open subpaths are
+    /// treated as if they were closed.
+    pub fn fill(&self, output: &mut PaintDom, rule: FillRule, color: impl Into<Brush>) {
+        let feather = 1.0 / output.scale_factor();
+        let lines = self.flatten(TOLERANCE * feather);
+
+        let contours: Vec<&[Vec2]> = lines
+            .iter()
+            .map(|line| line.points.as_slice())
+            .filter(|points| points.len() >= 3)
+            .collect();
+
+        let edges: Vec<(Vec2, Vec2)> = contours
+            .iter()
+            .flat_map(|points| {
+                (0..points.len()).map(|i| (points[i], points[(i + 1) % points.len()]))
+            })
+            .collect();
+
+        if edges.is_empty() {
+            return;
+        }
+
+        let brush = color.into();
+        let mut mesh = MeshBuilder::new(output, &brush, bounds(&lines));
+
+        let outside = sweep(&edges, rule, |[a, b, c, d]| {
+            mesh.quad([(a, 1.0), (b, 1.0), (c, 1.0), (d, 1.0)]);
+        });
+
+        // Fade out each edge over one physical pixel on the outside of the
+        // shape to anti-alias it. Edges with the shape on both sides or on
+        // neither side aren't on its outline, so they are left alone.
+        let mut first = 0;
+        for points in contours {
+            let count = points.len();
+            let outside = &outside[first..first + count];
+            first += count;
+
+            let normal = |i: usize| {
+                let dir = (points[(i + 1) % count] - points[i]).normalize_or_zero();
+                outside[i] * Vec2::new(dir.y, -dir.x)
+            };
+
+            for i in 0..count {
+                if outside[i] == 0.0 {
+                    continue;
+                }
+
+                let prev = (i + count - 1) % count;
+                let next = (i + 1) % count;
+
+                // Corners are only mitered with neighbors that fade out to
+                // the same side.
+                let start = if outside[prev] == outside[i] {
+                    miter_offset(normal(prev), normal(i), feather)
+                } else {
+                    normal(i) * feather
+                };
+                let end = if outside[next] == outside[i] {
+                    miter_offset(normal(i), normal(next), feather)
+                } else {
+                    normal(i) * feather
+                };
+
+                mesh.quad([
+                    (points[i], 1.0),
+                    (points[next], 1.0),
+                    (points[next] + end, 0.0),
+                    (points[i] + start, 0.0),
+                ]);
+            }
+        }
+
+        mesh.flush();
+    }
+
+    /// Draw the outline of the path.
+    pub fn stroke(&self, output: &mut PaintDom, stroke: &Stroke) {
+        if stroke.width <= 0.0 {
+            return;
+        }
+
+        let feather = 1.0 / output.scale_factor();
+        let lines = self.flatten(TOLERANCE * feather);
+
+        // Lines thinner than a pixel are drawn a pixel wide and faded out
+        // instead, which looks much better than a line that flickers.
+        let (width, alpha) = if stroke.width < feather {
+            (feather, stroke.width / feather)
+        } else {
+            (stroke.width, 1.0)
+        };
+
+        let lines: Vec<Polyline> = if stroke.dashes.iter().any(|&dash| dash > 0.0) {
+            lines
+                .iter()
+                .flat_map(|line| dash(line, &stroke.dashes, stroke.dash_offset))
+                .collect()
+        } else {
+            lines
+        };
+
+        let mut mesh = MeshBuilder::new(output, &stroke.color, bounds(&lines));
+        mesh.alpha = alpha;
+
+        let mut stroker = Stroker {
+            mesh,
+            inner: (width - feather).max(0.0) / 2.0,
+            outer: (width + feather) / 2.0,
+            feather,
+            join: stroke.join,
+            miter_limit: stroke.miter_limit,
+        };
+
+        for line in &lines {
+            stroker.polyline(line, stroke.cap);
+        }
+
+        stroker.mesh.flush();
+    }
+}
+
+fn push_point(line: &mut Polyline, point: Vec2) {
+    match line.points.last() {
+        Some(last) if last.distance_squared(point) < EPSILON * EPSILON => {}
+        _ => line.points.push(point),
+    }
+}
+
+fn finish(lines: &mut Vec<Polyline>, current: &mut Polyline) {
+    let mut line = std::mem::take(current);
+
+    if line.closed && line.points.len() > 1 {
+        let first = line.points[0];
+        if line.points.last().unwrap().distance_squared(first) < EPSILON * EPSILON {
+            line.points.pop();
+        }
+    }
+
+    if line.points.len() > 1 {
+        lines.push(line);
+    }
+}
+
+fn curve_segments(squared: f32) -> u32 {
+    (squared.sqrt().ceil() as u32).clamp(1, 256)
+}
+
+fn arc_segments(radius: f32, sweep_angle: f32, tolerance: f32) -> u32 {
+    if radius <= tolerance {
+        return 1;
+    }
+
+    let step = 2.0 * f32::acos(1.0 - tolerance / radius);
+    ((sweep_angle.abs() / step).ceil() as u32).clamp(1, 1024)
+}
+
+fn bounds(lines: &[Polyline]) -> Rect {
+    let points = lines.iter().flat_map(|line| &line.points);
+    let min = points
+        .clone()
+        .fold(Vec2::splat(f32::INFINITY), |a, &b| a.min(b));
+    let max = points.fold(Vec2::splat(f32::NEG_INFINITY), |a, &b| a.max(b));
+
+    if min.cmple(max).all() {
+        Rect::from_pos_size(min, max - min)
+    } else {
+        Rect::ZERO
+    }
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Returns where two edges cross, ignoring edges that only touch at their
+/// ends.
+fn intersect((a0, a1): (Vec2, Vec2), (b0, b1): (Vec2, Vec2)) -> Option<Vec2> {
+    let a = a1 - a0;
+    let b = b1 - b0;
+    let denominator = cross(a, b);
+
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+
+    let t = cross(b0 - a0, b) / denominator;
+    let u = cross(b0 - a0, a) / denominator;
+
+    if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 {
+        Some(a0 + a * t)
+    } else {
+        None
+    }
+}
+
+/// An edge of a filled path, oriented to go down the screen.
+#[derive(Debug, Clone, Copy)]
+struct SweepEdge {
+    top: Vec2,
+    bottom: Vec2,
+
+    /// `1` if the path goes down along this edge, `-1` if it goes up.
+    winding: i32,
+
+    /// The index of the edge in the path's list of edges.
+    index: usize,
+}
+
+impl SweepEdge {
+    fn x_at(&self, y: f32) -> f32 {
+        let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+        self.top.x + (self.bottom.x - self.top.x) * t
+    }
+
+    fn slope(&self) -> f32 {
+        (self.bottom.x - self.top.x) / (self.bottom.y - self.top.y)
+    }
+}
+
+/// Fills the shape outlined by `edges` by sweeping a line down the screen,
+/// passing each filled trapezoid to `span` with its corners in order.
+///
+/// The sweep keeps a list of the edges crossing the line, sorted left to
+/// right. The line stops at every vertex and wherever two neighboring edges
+/// cross, so in between, edges never cross and the spans between them are
+/// trapezoids.
+///
+/// Returns which side of each edge is outside of the shape, as a factor for
+/// the edge's normal `(dir.y, -dir.x)`, or `0.0` if the shape is on both sides
+/// of the edge or on neither.
+fn sweep(edges: &[(Vec2, Vec2)], rule: FillRule, mut span: impl FnMut([Vec2; 4])) -> Vec<f32> {
+    let mut sloped = Vec::new();
+    let mut horizontal = Vec::new();
+
+    for (index, &(from, to)) in edges.iter().enumerate() {
+        if (to.y - from.y).abs() < EPSILON {
+            horizontal.push(index);
+        } else if to.y > from.y {
+            sloped.push(SweepEdge {
+                top: from,
+                bottom: to,
+                winding: 1,
+                index,
+            });
+        } else {
+            sloped.push(SweepEdge {
+                top: to,
+                bottom: from,
+                winding: -1,
+                index,
+            });
+        }
+    }
+
+    sloped.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+    horizontal.sort_by(|&a, &b| edges[a].0.y.total_cmp(&edges[b].0.y));
+
+    // How much of each edge has the shape only on the side its normal points
+    // to, counted negatively, or only on the other side, counted positively.
+    let mut votes = vec![0.0; edges.len()];
+    let vote = |normal_inside: bool, other_inside: bool| match (normal_inside, other_inside) {
+        (true, false) => -1.0,
+        (false, true) => 1.0,
+        _ => 0.0,
+    };
+
+    let winding_left_of = |active: &[SweepEdge], y: f32, x: f32| -> i32 {
+        active
+            .iter()
+            .take_while(|edge| edge.x_at(y) < x)
+            .map(|edge| edge.winding)
+            .sum()
+    };
+
+    let mut active: Vec<SweepEdge> = Vec::new();
+    let mut above: Vec<SweepEdge> = Vec::new();
+    let mut next_sloped = 0;
+    let mut next_horizontal = 0;
+    let mut y = f32::NEG_INFINITY;
+
+    loop {
+        active.retain(|edge| edge.bottom.y > y + EPSILON);
+        while let Some(edge) = sloped.get(next_sloped).filter(|e| e.top.y <= y + EPSILON) {
+            active.push(*edge);
+            next_sloped += 1;
+        }
+
+        // Edges meeting at a vertex on the line are ordered by where they go
+        // below it.
+        active.sort_by(|a, b| {
+            a.x_at(y)
+                .total_cmp(&b.x_at(y))
+                .then(a.slope().total_cmp(&b.slope()))
+        });
+
+        // Horizontal edges on the line separate the band above it from the
+        // one below it. The normal of an edge going right points up.
+        while let Some(&index) = horizontal
+            .get(next_horizontal)
+            .filter(|&&i| edges[i].0.y <= y + EPSILON)
+        {
+            let (from, to) = edges[index];
+            let middle = (from.x + to.x) / 2.0;
+            let above_inside = rule.contains(winding_left_of(&above, y, middle));
+            let below_inside = rule.contains(winding_left_of(&active, y, middle));
+
+            votes[index] += (to.x - from.x).abs()
+                * if to.x > from.x {
+                    vote(above_inside, below_inside)
+                } else {
+                    vote(below_inside, above_inside)
+                };
+
+            next_horizontal += 1;
+        }
+
+        let next_vertex = [
+            sloped.get(next_sloped).map(|edge| edge.top.y),
+            horizontal.get(next_horizontal).map(|&i| edges[i].0.y),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(f32::min);
+
+        if active.is_empty() {
+            let Some(next_vertex) = next_vertex else {
+                break;
+            };
+
+            above.clear();
+            y = next_vertex;
+            continue;
+        }
+
+        let mut bottom = active
+            .iter()
+            .map(|edge| edge.bottom.y)
+            .fold(next_vertex.unwrap_or(f32::INFINITY), f32::min);
+
+        for pair in active.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if let Some(point) = intersect((a.top, a.bottom), (b.top, b.bottom)) {
+                if point.y > y + EPSILON {
+                    bottom = bottom.min(point.y);
+                }
+            }
+        }
+
+        // The normal of an edge going down points right.
+        let mut winding = 0;
+        for (i, edge) in active.iter().enumerate() {
+            let left_inside = rule.contains(winding);
+            winding += edge.winding;
+            let right_inside = rule.contains(winding);
+
+            let start = Vec2::new(edge.x_at(y), y);
+            let end = Vec2::new(edge.x_at(bottom), bottom);
+            votes[edge.index] += start.distance(end)
+                * if edge.winding > 0 {
+                    vote(right_inside, left_inside)
+                } else {
+                    vote(left_inside, right_inside)
+                };
+
+            if let Some(next) = active.get(i + 1).filter(|_| right_inside) {
+                span([
+                    start,
+                    Vec2::new(next.x_at(y), y),
+                    Vec2::new(next.x_at(bottom), bottom),
+                    end,
+                ]);
+            }
+        }
+
+        above.clone_from(&active);
+        y = bottom;
+    }
+
+    votes
+        .into_iter()
+        .map(|vote: f32| {
+            if vote.abs() < EPSILON {
+                0.0
+            } else {
+                vote.signum()
+            }
+        })
+        .collect()
+}
+
+/// Offset from a corner between two edges with the given outward normals, so
+/// that both edges are pushed out by `distance`.
+fn miter_offset(before: Vec2, after: Vec2, distance: f32) -> Vec2 {
+    let miter = (before + after).normalize_or_zero();
+    let cos = miter.dot(after);
+
+    if cos < 0.25 {
+        return after * distance;
+    }
+
+    miter * distance / cos
+}
+
+fn dash(line: &Polyline, pattern: &[f32], offset: f32) -> Vec<Polyline> {
+    // Like SVG, odd patterns are repeated to get an even number of entries.
+    let pattern: Vec<f32> = if pattern.len() % 2 == 1 {
+        pattern
+            .iter()
+            .chain(pattern)
+            .map(|dash| dash.max(0.0))
+            .collect()
+    } else {
+        pattern.iter().map(|dash| dash.max(0.0)).collect()
+    };
+
+    let total: f32 = pattern.iter().sum();
+    let mut index = 0;
+    let mut remaining = pattern[0];
+    let mut phase = offset.rem_euclid(total);
+
+    while phase > remaining {
+        phase -= remaining;
+        index = (index + 1) % pattern.len();
+        remaining = pattern[index];
+    }
+    remaining -= phase;
+
+    let mut points = line.points.clone();
+    if line.closed {
+        points.push(points[0]);
+    }
+
+    let mut dashes = Vec::new();
+    let mut current = Vec::new();
+    if index % 2 == 0 {
+        current.push(points[0]);
+    }
+
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let length = from.distance(to);
+        let dir = (to - from) / length;
+        let mut position = 0.0;
+
+        while length - position > remaining {
+            position += remaining;
+            let point = from + dir * position;
+
+            if index % 2 == 0 {
+                current.push(point);
+                dashes.push(Polyline {
+                    points: std::mem::take(&mut current),
+                    closed: false,
+                });
+            } else {
+                current = vec![point];
+            }
+
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+
+        remaining -= length - position;
+        if index % 2 == 0 {
+            current.push(to);
+        }
+    }
+
+    if index % 2 == 0 && current.len() > 1 {
+        dashes.push(Polyline {
+            points: current,
+            closed: false,
+        });
+    }
+
+    dashes.retain(|dash| dash.points.len() > 1);
+    dashes
+}
+
+struct Stroker<'a> {
+    mesh: MeshBuilder<'a>,
+
+    /// Half of the width of the fully opaque part of the stroke.
+    inner: f32,
+
+    /// Half of the width of the stroke including its faded edges.
+    outer: f32,
+
+    feather: f32,
+    join: LineJoin,
+    miter_limit: f32,
+}
+
+impl Stroker<'_> {
+    fn half_width(&self) -> f32 {
+        (self.inner + self.outer) / 2.0
+    }
+
+    fn polyline(&mut self, line: &Polyline, cap: LineCap) {
+        let mut points = line.points.clone();
+        if line.closed {
+            points.push(points[0]);
+        }
+
+        let segments = points.len() - 1;
+        for i in 0..segments {
+            let mut from = points[i];
+            let mut to = points[i + 1];
+            let dir = (to - from).normalize_or_zero();
+
+            if !line.closed && cap == LineCap::Square {
+                if i == 0 {
+                    from -= dir * self.half_width();
+                }
+                if i == segments - 1 {
+                    to += dir * self.half_width();
+                }
+            }
+
+            self.segment(from, to);
+
+            if i + 1 < segments {
+                let next_dir = (points[i + 2] - points[i + 1]).normalize_or_zero();
+                self.join(points[i + 1], dir, next_dir);
+            } else if line.closed {
+                let next_dir = (points[1] - points[0]).normalize_or_zero();
+                self.join(points[0], dir, next_dir);
+            }
+        }
+
+        if !line.closed {
+            let start_dir = (points[1] - points[0]).normalize_or_zero();
+            let end_dir = (points[segments] - points[segments - 1]).normalize_or_zero();
+
+            match cap {
+                LineCap::Round => {
+                    let start_normal = Vec2::new(-start_dir.y, start_dir.x);
+                    let end_normal = Vec2::new(end_dir.y, -end_dir.x);
+                    self.fan(points[0], start_normal, PI);
+                    self.fan(points[segments], end_normal, PI);
+                }
+                LineCap::Butt | LineCap::Square => {
+                    let extend = match cap {
+                        LineCap::Square => self.half_width(),
+                        _ => 0.0,
+                    };
+
+                    self.cap_edge(points[0] - start_dir * extend, -start_dir);
+                    self.cap_edge(points[segments] + end_dir * extend, end_dir);
+                }
+            }
+        }
+    }
+
+    fn segment(&mut self, from: Vec2, to: Vec2) {
+        let dir = (to - from).normalize_or_zero();
+        let normal = Vec2::new(-dir.y, dir.x);
+        let (inner, outer) = (normal * self.inner, normal * self.outer);
+
+        self.mesh.quad([
+            (from + inner, 1.0),
+            (to + inner, 1.0),
+            (to - inner, 1.0),
+            (from - inner, 1.0),
+        ]);
+
+        for side in [1.0, -1.0] {
+            self.mesh.quad([
+                (from + inner * side, 1.0),
+                (to + inner * side, 1.0),
+                (to + outer * side, 0.0),
+                (from + outer * side, 0.0),
+            ]);
+        }
+    }
+
+    fn join(&mut self, point: Vec2, before: Vec2, after: Vec2) {
+        let turn = cross(before, after);
+        if turn.abs() < EPSILON && before.dot(after) > 0.0 {
+            return;
+        }
+
+        // Normals on the outside of the turn, where the segments leave a gap.
+        let side = if turn > 0.0 { -1.0 } else { 1.0 };
+        let start = side * Vec2::new(-before.y, before.x);
+        let end = side * Vec2::new(-after.y, after.x);
+
+        match self.join {
+            LineJoin::Round => {
+                let sweep = start.angle_to(end);
+                self.fan(point, start, sweep);
+            }
+
+            LineJoin::Miter | LineJoin::Bevel => {
+                let miter = (start + end).normalize_or_zero();
+                let cos = miter.dot(start);
+                let within_limit = cos > EPSILON && 1.0 / cos <= self.miter_limit;
+
+                if self.join == LineJoin::Miter && within_limit {
+                    let tip = |distance: f32| point + miter * distance / cos;
+
+                    self.mesh.quad([
+                        (point, 1.0),
+                        (point + start * self.inner, 1.0),
+                        (tip(self.inner), 1.0),
+                        (point + end * self.inner, 1.0),
+                    ]);
+
+                    for normal in [start, end] {
+                        self.mesh.quad([
+                            (point + normal * self.inner, 1.0),
+                            (tip(self.inner), 1.0),
+                            (tip(self.outer), 0.0),
+                            (point + normal * self.outer, 0.0),
+                        ]);
+                    }
+                } else {
+                    self.mesh.triangle([
+                        (point, 1.0),
+                        (point + start * self.inner, 1.0),
+                        (point + end * self.inner, 1.0),
+                    ]);
+
+                    self.mesh.quad([
+                        (point + start * self.inner, 1.0),
+                        (point + end * self.inner, 1.0),
+                        (point + end * self.outer, 0.0),
+                        (point + start * self.outer, 0.0),
+                    ]);
+                }
+            }
+        }
+    }
+
+    /// Draw a round fan around `center`, starting at the direction `start`
+    /// and turning by `sweep` radians.
+    fn fan(&mut self, center: Vec2, start: Vec2, sweep: f32) {
+        let start_angle = start.y.atan2(start.x);
+        let segments = arc_segments(self.outer, sweep, TOLERANCE * self.feather);
+
+        let mut previous = start;
+        for i in 1..=segments {
+            let angle = start_angle + sweep * i as f32 / segments as f32;
+            let (y, x) = angle.sin_cos();
+            let current = Vec2::new(x, y);
+
+            self.mesh.triangle([
+                (center, 1.0),
+                (center + previous * self.inner, 1.0),
+                (center + current * self.inner, 1.0),
+            ]);
+
+            self.mesh.quad([
+                (center + previous * self.inner, 1.0),
+                (center + current * self.inner, 1.0),
+                (center + current * self.outer, 0.0),
+                (center + previous * self.outer, 0.0),
+            ]);
+
+            previous = current;
+        }
+    }
+
+    /// Fade out the flat end of a line at `point`, facing `dir`.
+    fn cap_edge(&mut self, point: Vec2, dir: Vec2) {
+        let normal = Vec2::new(-dir.y, dir.x);
+        let out = dir * self.feather;
+
+        self.mesh.quad([
+            (point + normal * self.inner, 1.0),
+            (point - normal * self.inner, 1.0),
+            (point - normal * self.inner + out, 0.0),
+            (point + normal * self.inner + out, 0.0),
+        ]);
+
+        for side in [1.0, -1.0] {
+            self.mesh.triangle([
+                (point + normal * self.inner * side, 1.0),
+                (point + normal * self.outer * side, 0.0),
+                (point + normal * self.inner * side + out, 0.0),
+            ]);
+        }
+    }
+}
+
+/// Collects triangles into a mesh.
+struct MeshBuilder<'a> {
+    output: &'a mut PaintDom,
+    brush: &'a Brush,
+    bounds: Rect,
+    alpha: f32,
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+}
+
+impl<'a> MeshBuilder<'a> {
+    fn new(output: &'a mut PaintDom, brush: &'a Brush, bounds: Rect) -> Self {
+        Self {
+            output,
+            brush,
+            bounds,
+            alpha: 1.0,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    fn push<const N: usize>(&mut self, points: [(Vec2, f32); N]) -> u32 {
+        let base = self.vertices.len() as u32;
+        for (pos, alpha) in points {
+            let mut color: Vec4 = self.brush.color_at(self.bounds, pos);
+            color.w *= alpha * self.alpha;
+            self.vertices.push(Vertex::new(pos, Vec2::ZERO, color));
+        }
+
+        base
+    }
+
+    fn triangle(&mut self, points: [(Vec2, f32); 3]) {
+        let base = self.push(points);
+        self.indices.extend_from_slice(&[base, base + 1, base + 2]);
+    }
+
+    fn quad(&mut self, points: [(Vec2, f32); 4]) {
+        let base = self.push(points);
+        self.indices
+            .extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    fn flush(&mut self) {
+        if self.indices.is_empty() {
+            return;
+        }
+
+        let vertices = std::mem::take(&mut self.vertices);
+        let indices = std::mem::take(&mut self.indices);
+        self.output.add_mesh(PaintMesh::new(vertices, indices));
+    }
+}
@@ -0,0 +1,38 @@
+use yakui_core::geometry::{Color, Vec2};
+
+use crate::auto_builders;
+
+/// A soft shadow drawn behind a rectangle or rounded rectangle, like CSS's
+/// `box-shadow`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    pub color: Color,
+
+    /// How far the shadow is moved from the shape casting it.
+    pub offset: Vec2,
+
+    /// How far the shadow fades out past its edges. A blur of zero gives the
+    /// shadow hard edges.
+    pub blur: f32,
+
+    /// How much bigger than the shape casting it the shadow is on each side.
+    pub spread: f32,
+}
+
+auto_builders!(Shadow {
+    color: Color,
+    offset: Vec2,
+    blur: f32,
+    spread: f32,
+});
+
+impl Shadow {
+    pub const fn new(color: Color, blur: f32) -> Self {
+        Self {
+            color,
+            offset: Vec2::ZERO,
+            blur,
+            spread: 0.0,
+        }
+    }
+}
@@ -1,11 +1,12 @@
-use std::f32::consts::TAU;
+use std::f32::consts::{SQRT_2, TAU};
 
-use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::geometry::{Brush, Color, Rect, Vec2, Vec4};
 use yakui_core::paint::{PaintDom, PaintMesh, PaintRect, Vertex};
 use yakui_core::TextureId;
 
 use crate::auto_builders;
 use crate::border::{Border, BorderRadius};
+use crate::shadow::Shadow;
 
 pub fn cross(output: &mut PaintDom, rect: Rect, color: Color) {
     static POSITIONS: [[f32; 2]; 12] = [
@@ -29,7 +30,7 @@ pub fn cross(output: &mut PaintDom, rect: Rect, color: Color) {
     ];
 
     #[rustfmt::skip]
-    static INDICES: [u16; 18] = [
+    static INDICES: [u32; 18] = [
         // '\' part of the X
         0, 2, 5,
         5, 7, 0,
@@ -80,7 +81,7 @@ pub fn outline(output: &mut PaintDom, rect: Rect, w: f32, color: Color) {
     ];
 
     #[rustfmt::skip]
-    static INDICES: [u16; 24] = [
+    static INDICES: [u32; 24] = [
         // Top
         0, 4, 11,
         11, 3, 0,
@@ -106,46 +107,222 @@ pub fn outline(output: &mut PaintDom, rect: Rect, w: f32, color: Color) {
     output.add_mesh(mesh);
 }
 
+/// Draw a soft shadow cast by a rectangle with the given corner radius.
+///
+/// The blur is computed per vertex as a Gaussian falloff from the edge of the
+/// shadow, so no extra render pass is needed.
+pub fn box_shadow(output: &mut PaintDom, rect: Rect, radius: BorderRadius, shadow: &Shadow) {
+    let spread = Vec2::splat(shadow.spread);
+    let rect = Rect::from_pos_size(
+        rect.pos() + shadow.offset - spread,
+        (rect.size() + spread * 2.0).max(Vec2::ZERO),
+    );
+
+    let half = rect.size() / 2.0;
+    let max_radius = half.x.min(half.y);
+
+    // Corners in the order they're visited going around the shape.
+    let radii = [
+        radius.top_right,
+        radius.top_left,
+        radius.bottom_left,
+        radius.bottom_right,
+    ]
+    .map(|radius| {
+        // Like CSS, square corners stay square when the shadow is spread.
+        if radius > 0.0 {
+            (radius + shadow.spread).clamp(0.0, max_radius)
+        } else {
+            0.0
+        }
+    });
+
+    let blur = shadow.blur.max(0.0);
+    if blur < 0.5 {
+        let radius = BorderRadius {
+            top_right: radii[0],
+            top_left: radii[1],
+            bottom_left: radii[2],
+            bottom_right: radii[3],
+        };
+
+        return RoundedRectangle::new(rect, radius)
+            .brush(shadow.color)
+            .add(output);
+    }
+
+    const RINGS: u32 = 8;
+    const CORNERS: [(Vec2, f32); 4] = [
+        (Vec2::new(1.0, -1.0), 0.0),
+        (Vec2::new(-1.0, -1.0), TAU / 4.0),
+        (Vec2::new(-1.0, 1.0), TAU / 2.0),
+        (Vec2::new(1.0, 1.0), 3.0 * TAU / 4.0),
+    ];
+
+    let center = rect.center();
+    let color = shadow.color.to_linear();
+
+    // A Gaussian with a standard deviation of half the blur, like CSS.
+    let scale = 1.0 / (blur / 2.0 * SQRT_2);
+
+    // Shadows smaller than their blur never reach full strength.
+    let strength = erf(half.x * scale) * erf(half.y * scale);
+
+    let create_vertex = |pos: Vec2| {
+        let distance = rounded_rect_distance(pos - center, half, radii);
+        let alpha = 0.5 * (1.0 - erf(distance * scale)) * strength;
+        Vertex::new(pos, [0.0, 0.0], color * Vec4::new(1.0, 1.0, 1.0, alpha))
+    };
+
+    let largest = radii.iter().copied().fold(0.0, f32::max) + 1.5 * blur;
+    let slices = f32::ceil(TAU / 8.0 / f32::acos(1.0 - 0.2 / largest)).clamp(1.0, 32.0) as u32;
+    let ring_size = 4 * (slices + 1);
+
+    let mut vertices = vec![create_vertex(center)];
+    let mut indices = Vec::new();
+
+    // Rings of vertices follow the shape's outline, grown or shrunk by a
+    // distance going from -blur to +1.5 * blur, where the shadow has faded
+    // out completely.
+    for ring in 0..=RINGS {
+        let distance = blur * (2.5 * ring as f32 / RINGS as f32 - 1.0);
+        let ring_half = (half + Vec2::splat(distance)).max(Vec2::ZERO);
+
+        for ((sign, start_angle), radius) in CORNERS.into_iter().zip(radii) {
+            let radius = (radius + distance).clamp(0.0, ring_half.x.min(ring_half.y));
+            let corner_center = center + sign * (ring_half - Vec2::splat(radius));
+
+            for i in 0..=slices {
+                let angle = start_angle + i as f32 / slices as f32 * TAU / 4.0;
+                let offset = radius * Vec2::new(angle.cos(), -angle.sin());
+                vertices.push(create_vertex(corner_center + offset));
+            }
+        }
+    }
+
+    let ring_vertex = |ring: u32, i: u32| 1 + ring * ring_size + i % ring_size;
+
+    for i in 0..ring_size {
+        indices.extend_from_slice(&[0, ring_vertex(0, i), ring_vertex(0, i + 1)]);
+    }
+
+    for ring in 0..RINGS {
+        for i in 0..ring_size {
+            indices.extend_from_slice(&[
+                ring_vertex(ring, i),
+                ring_vertex(ring + 1, i),
+                ring_vertex(ring + 1, i + 1),
+                ring_vertex(ring, i),
+                ring_vertex(ring + 1, i + 1),
+                ring_vertex(ring, i + 1),
+            ]);
+        }
+    }
+
+    output.add_mesh(PaintMesh::new(vertices, indices));
+}
+
+/// Signed distance from `pos`, relative to the center of a rounded rectangle,
+/// to its edge.
+fn rounded_rect_distance(pos: Vec2, half: Vec2, radii: [f32; 4]) -> f32 {
+    let radius = match (pos.x >= 0.0, pos.y < 0.0) {
+        (true, true) => radii[0],
+        (false, true) => radii[1],
+        (false, false) => radii[2],
+        (true, false) => radii[3],
+    };
+
+    let q = pos.abs() - half + Vec2::splat(radius);
+    q.max(Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius
+}
+
+/// Approximation of the error function, accurate to about 1e-7.
+fn erf(x: f32) -> f32 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let poly =
+        ((((1.0614054 * t - 1.4531521) * t + 1.4214138) * t - 0.28449672) * t + 0.2548296) * t;
+
+    (1.0 - poly * (-x * x).exp()).copysign(x)
+}
+
 pub struct Circle {
     pub center: Vec2,
     pub radius: f32,
-    pub color: Color,
+    pub brush: Brush,
 }
 
-auto_builders!(Circle { color: Color });
+auto_builders!(Circle { brush: Brush });
 
 impl Circle {
     pub fn new(center: Vec2, radius: f32) -> Self {
         Self {
             center,
             radius,
-            color: Color::WHITE,
+            brush: Brush::default(),
         }
     }
 
     pub fn add(&self, output: &mut PaintDom) {
-        let color = self.color.to_linear();
+        let bounds = Rect::from_pos_size(
+            self.center - Vec2::splat(self.radius),
+            Vec2::splat(self.radius * 2.0),
+        );
+        let create_vertex = |pos| Vertex::new(pos, [0.0, 0.0], self.brush.color_at(bounds, pos));
+
+        // The edge fades out over one physical pixel, centered on the circle's
+        // radius, to anti-alias it.
+        let feather = 1.0 / output.scale_factor();
+        let inner_radius = (self.radius - feather / 2.0).max(0.0);
+
         let mut vertices = Vec::new();
         let segments = f32::ceil(TAU / 2.0 / f32::acos(1.0 - 0.2 / self.radius));
 
+        // Gradients need rings of vertices between the center and the edge so
+        // that colors can change along the radius.
+        let rings = match self.brush {
+            Brush::Solid(_) => 1,
+            _ => 8,
+        };
+
+        vertices.push(create_vertex(self.center));
+
+        for ring in 1..=rings {
+            let radius = inner_radius * ring as f32 / rings as f32;
+
+            for i in 0..segments as u32 {
+                let angle = TAU * (i as f32) / segments;
+                let (y, x) = angle.sin_cos();
+                vertices.push(create_vertex(self.center + Vec2::new(x, y) * radius));
+            }
+        }
+
         for i in 0..segments as u32 {
             let angle = TAU * (i as f32) / segments;
             let (y, x) = angle.sin_cos();
-            let pos = self.center + Vec2::new(x, y) * self.radius;
-
-            vertices.push(Vertex::new(pos, [0.0, 0.0], color));
+            let mut vertex =
+                create_vertex(self.center + Vec2::new(x, y) * (inner_radius + feather));
+            vertex.color.w = 0.0;
+            vertices.push(vertex);
         }
 
-        vertices.push(Vertex::new(self.center, [0.0, 0.0], color));
-        let middle_vertex = (vertices.len() - 1) as u16;
-
         let mut indices = Vec::new();
-        let segments = segments as i16;
+        let segments = segments as i32;
+        let ring_vertex = |ring: i32, i: i32| (1 + ring * segments + i.rem_euclid(segments)) as u32;
+
+        for i in 0i32..segments {
+            indices.push(ring_vertex(0, i));
+            indices.push(ring_vertex(0, i - 1));
+            indices.push(0);
+        }
+
+        for ring in 1..=rings {
+            for i in 0i32..segments {
+                let (inner, inner_prev) = (ring_vertex(ring - 1, i), ring_vertex(ring - 1, i - 1));
+                let (outer, outer_prev) = (ring_vertex(ring, i), ring_vertex(ring, i - 1));
 
-        for i in 0i16..segments {
-            indices.push(i as u16);
-            indices.push((i - 1).rem_euclid(segments) as u16);
-            indices.push(middle_vertex);
+                indices
+                    .extend_from_slice(&[inner, inner_prev, outer_prev, outer_prev, outer, inner]);
+            }
         }
 
         let mesh = PaintMesh::new(vertices, indices);
@@ -153,57 +330,55 @@ impl Circle {
     }
 }
 
-#[rustfmt::skip]
-const RECT_POS: [[f32; 2]; 4] = [
-    [0.0, 0.0],
-    [0.0, 1.0],
-    [1.0, 1.0],
-    [1.0, 0.0]
-];
-
-#[rustfmt::skip]
-const RECT_INDEX: [u16; 6] = [
-    0, 1, 2,
-    3, 0, 2,
-];
-
 pub struct RoundedRectangle {
     pub rect: Rect,
-    pub color: Color,
+    pub brush: Brush,
     pub texture: Option<(TextureId, Rect)>,
     pub radius: BorderRadius,
     pub border: Option<Border>,
+    pub shadow: Option<Shadow>,
 }
 
 auto_builders!(RoundedRectangle {
-    color: Color,
+    brush: Brush,
     texture: Option<(TextureId, Rect)>,
     border: Option<Border>,
+    shadow: Option<Shadow>,
 });
 
 impl RoundedRectangle {
     pub fn new<T: Into<BorderRadius>>(rect: Rect, radius: T) -> Self {
         Self {
             rect,
-            color: Color::WHITE,
+            brush: Brush::default(),
             texture: None,
             radius: radius.into(),
             border: None,
+            shadow: None,
         }
     }
 
     pub fn add(&self, output: &mut PaintDom) {
+        if let Some(shadow) = &self.shadow {
+            box_shadow(output, self.rect, self.radius, shadow);
+        }
+
         // Draw border background first if there's a border
         if let Some(border) = &self.border {
             self.draw_border(output, border);
         }
 
+        // Straight edges are kept crisp by snapping them to physical pixels,
+        // while corners fade out over one physical pixel.
+        let outer_rect = output.snap_rect(self.rect);
+        let feather = 1.0 / output.scale_factor();
+
         let (rect, radius) = if let Some(border) = &self.border {
             let border_width = border.width;
-            let inner_rect = Rect::from_pos_size(
-                self.rect.pos() + Vec2::new(border_width, border_width),
-                self.rect.size() - Vec2::new(border_width * 2.0, border_width * 2.0),
-            );
+            let inner_rect = output.snap_rect(Rect::from_pos_size(
+                outer_rect.pos() + Vec2::new(border_width, border_width),
+                outer_rect.size() - Vec2::new(border_width * 2.0, border_width * 2.0),
+            ));
             let inner_radius = BorderRadius {
                 top_left: (self.radius.top_left - border_width).max(0.0),
                 top_right: (self.radius.top_right - border_width).max(0.0),
@@ -212,7 +387,7 @@ impl RoundedRectangle {
             };
             (inner_rect, inner_radius)
         } else {
-            (self.rect, self.radius)
+            (outer_rect, self.radius)
         };
 
         let BorderRadius {
@@ -235,12 +410,10 @@ impl RoundedRectangle {
         if top_left < 1.0 && top_right < 1.0 && bottom_left < 1.0 && bottom_right < 1.0 {
             let mut p = PaintRect::new(rect);
             p.texture = self.texture;
-            p.color = self.color;
+            p.brush = self.brush.clone();
             return p.add(output);
         }
 
-        let color = self.color.to_linear();
-
         let max_radius = top_left.max(top_right).max(bottom_left).max(bottom_right);
         let slices = f32::ceil(TAU / 8.0 / f32::acos(1.0 - 0.2 / max_radius)) as u32;
 
@@ -260,20 +433,39 @@ impl RoundedRectangle {
             (position - rect.pos()) * uv_factor + uv_offset
         };
 
-        let create_vertex = |pos| Vertex::new(pos, calc_uv(pos), color);
+        let create_vertex = |pos| Vertex::new(pos, calc_uv(pos), self.brush.color_at(rect, pos));
 
         let mut rectangle = |min: Vec2, max: Vec2| {
-            let base_vertex = vertices.len();
-
-            let size = max - min;
-            let rect_vertices = RECT_POS
-                .map(Vec2::from)
-                .map(|vert| create_vertex(vert * size + min));
-
-            let rect_indices = RECT_INDEX.map(|index| index + base_vertex as u16);
+            let base_vertex = vertices.len() as u32;
+
+            // Solid colors only need the corners, while gradients split the
+            // rectangle into a grid.
+            let (xs, ys) = self
+                .brush
+                .grid_lines(rect, Rect::from_pos_size(min, max - min));
+            let columns = xs.len() as u32;
+
+            for &y in &ys {
+                for &x in &xs {
+                    vertices.push(create_vertex(Vec2::new(x, y)));
+                }
+            }
 
-            vertices.extend(rect_vertices);
-            indices.extend(rect_indices);
+            for row in 0..ys.len() as u32 - 1 {
+                for column in 0..columns - 1 {
+                    let top_left = base_vertex + row * columns + column;
+                    let bottom_left = top_left + columns;
+
+                    indices.extend_from_slice(&[
+                        top_left,
+                        bottom_left,
+                        bottom_left + 1,
+                        top_left + 1,
+                        top_left,
+                        bottom_left + 1,
+                    ]);
+                }
+            }
         };
 
         // Top
@@ -329,24 +521,37 @@ impl RoundedRectangle {
                 return;
             }
 
-            let center_vertex = vertices.len();
-            vertices.push(create_vertex(center));
+            let inner_radius = (radius - feather / 2.0).max(0.0);
+            let outer_radius = inner_radius + feather;
 
-            let first_offset = radius * Vec2::new(start_angle.cos(), -start_angle.sin());
-            vertices.push(create_vertex(center + first_offset));
+            let center_vertex = vertices.len() as u32;
+            vertices.push(create_vertex(center));
 
-            for i in 1..=slices {
+            for i in 0..=slices {
                 let percent = i as f32 / slices as f32;
                 let angle = start_angle + percent * TAU / 4.0;
-                let offset = radius * Vec2::new(angle.cos(), -angle.sin());
-                let index = vertices.len();
-                vertices.push(create_vertex(center + offset));
-
-                indices.extend_from_slice(&[
-                    center_vertex as u16,
-                    (index - 1) as u16,
-                    index as u16,
-                ]);
+                let dir = Vec2::new(angle.cos(), -angle.sin());
+
+                let mut fringe = create_vertex(center + dir * outer_radius);
+                fringe.color.w = 0.0;
+
+                let index = vertices.len() as u32;
+                vertices.push(create_vertex(center + dir * inner_radius));
+                vertices.push(fringe);
+
+                if i > 0 {
+                    indices.extend_from_slice(&[
+                        center_vertex,
+                        index - 2,
+                        index,
+                        index - 2,
+                        index - 1,
+                        index + 1,
+                        index - 2,
+                        index + 1,
+                        index,
+                    ]);
+                }
             }
         };
 
@@ -371,10 +576,6 @@ impl RoundedRectangle {
             3.0 * TAU / 4.0,
         );
 
-        if let Some(border) = &self.border {
-            self.draw_border(output, border);
-        }
-
         let mut mesh = PaintMesh::new(vertices, indices);
         mesh.texture = self.texture;
         output.add_mesh(mesh);
@@ -383,7 +584,7 @@ impl RoundedRectangle {
     // Just draws a larger rectangle behind the main one... probably has issues with opacity?
     fn draw_border(&self, output: &mut PaintDom, border: &Border) {
         let mut border_shape = RoundedRectangle::new(self.rect, self.radius);
-        border_shape.color = border.color;
+        border_shape.brush = border.color.into();
         border_shape.add(output);
     }
 }
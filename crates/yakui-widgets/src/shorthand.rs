@@ -4,19 +4,23 @@
 //! widget if its defaults don't work for you.
 
 use std::borrow::Cow;
+use std::fmt;
 
-use yakui_core::geometry::{Color, Constraints, Dim2, Vec2};
+use yakui_core::geometry::{Brush, Color, Constraints, Dim2, Vec2};
+use yakui_core::input::Shortcut;
 use yakui_core::widget::PaintContext;
 use yakui_core::{Alignment, ManagedTextureId, Pivot, Response, TextureId};
 
 use crate::widgets::{
-    Align, AlignResponse, Button, ButtonResponse, Canvas, CanvasResponse, Checkbox,
-    CheckboxResponse, Circle, CircleResponse, ColoredBox, ColoredBoxResponse, ConstrainedBox,
-    ConstrainedBoxResponse, CountGrid, Divider, DividerResponse, Draggable, DraggableResponse,
-    Flexible, FlexibleResponse, Image, ImageResponse, List, ListResponse, MaxWidth,
-    MaxWidthResponse, NineSlice, Offset, OffsetResponse, Opaque, OpaqueResponse, Pad, PadResponse,
-    Reflow, ReflowResponse, Scrollable, ScrollableResponse, Slider, SliderResponse, Spacer, Stack,
-    StackResponse, State, StateResponse, Text, TextBox, TextBoxResponse, TextResponse,
+    Action, ActionResponse, Align, AlignResponse, Button, ButtonResponse, Canvas, CanvasResponse,
+    Checkbox, CheckboxResponse, Circle, CircleResponse, ColoredBox, ColoredBoxResponse,
+    ConstrainedBox, ConstrainedBoxResponse, CountGrid, Divider, DividerResponse, Draggable,
+    DraggableResponse, Flexible, FlexibleResponse, Grid, GridResponse, GridTrack, Image,
+    ImageResponse, List, ListResponse, MaxWidth, MaxWidthResponse, NineSlice, Offset,
+    OffsetResponse, Opacity, OpacityResponse, Opaque, OpaqueResponse, Pad, PadResponse, Reflow,
+    ReflowResponse, Scrollable, ScrollableResponse, Slider, SliderResponse, Spacer, Stack,
+    StackResponse, State, StateResponse, Text, TextBox, TextBoxResponse, TextResponse, Wrap,
+    WrapResponse,
 };
 
 /// See [List].
@@ -31,6 +35,21 @@ pub fn row<F: FnOnce()>(children: F) -> Response<ListResponse> {
     List::row().show(children)
 }
 
+/// See [Wrap].
+#[track_caller]
+pub fn wrap<F: FnOnce()>(children: F) -> Response<WrapResponse> {
+    Wrap::row().show(children)
+}
+
+/// See [Grid].
+#[track_caller]
+pub fn grid<F: FnOnce()>(
+    columns: impl Into<Vec<GridTrack>>,
+    children: F,
+) -> Response<GridResponse> {
+    Grid::new().columns(columns).show(children)
+}
+
 /// See [CountGrid].
 #[track_caller]
 pub fn countgrid_column<F: FnOnce()>(n_columns: usize, children: F) -> Response<ListResponse> {
@@ -63,20 +82,23 @@ pub fn button<S: Into<Cow<'static, str>>>(text: S) -> Response<ButtonResponse> {
 
 /// See [Circle].
 #[track_caller]
-pub fn colored_circle<S: Into<f32>>(color: Color, size: S) -> Response<CircleResponse> {
+pub fn colored_circle<C: Into<Brush>, S: Into<f32>>(color: C, size: S) -> Response<CircleResponse> {
     Circle::new().min_radius(size).color(color).show()
 }
 
 /// See [ColoredBox].
 #[track_caller]
-pub fn colored_box<S: Into<Vec2>>(color: Color, size: S) -> Response<ColoredBoxResponse> {
+pub fn colored_box<C: Into<Brush>, S: Into<Vec2>>(
+    color: C,
+    size: S,
+) -> Response<ColoredBoxResponse> {
     ColoredBox::sized(color, size.into()).show()
 }
 
 /// See [ColoredBox].
 #[track_caller]
-pub fn colored_box_container<F: FnOnce()>(
-    color: Color,
+pub fn colored_box_container<C: Into<Brush>, F: FnOnce()>(
+    color: C,
     children: F,
 ) -> Response<ColoredBoxResponse> {
     ColoredBox::container(color).show_children(children)
@@ -184,6 +206,12 @@ pub fn scroll_vertical(children: impl FnOnce()) -> Response<ScrollableResponse>
     Scrollable::vertical().show(children)
 }
 
+/// See [Scrollable].
+#[track_caller]
+pub fn scroll_horizontal(children: impl FnOnce()) -> Response<ScrollableResponse> {
+    Scrollable::horizontal().show(children)
+}
+
 /// See [Slider].
 #[track_caller]
 pub fn slider(value: f64, min: f64, max: f64) -> Response<SliderResponse> {
@@ -207,6 +235,12 @@ pub fn opaque(children: impl FnOnce()) -> Response<OpaqueResponse> {
     Opaque::new().show(children)
 }
 
+/// See [Opacity].
+#[track_caller]
+pub fn opacity(opacity: f32, children: impl FnOnce()) -> Response<OpacityResponse> {
+    Opacity::new(opacity).show(children)
+}
+
 /// See [Canvas].
 #[track_caller]
 pub fn canvas(paint: impl Fn(&mut PaintContext<'_>) + 'static) -> Response<CanvasResponse> {
@@ -219,6 +253,16 @@ pub fn max_width(max_width: f32, children: impl FnOnce()) -> Response<MaxWidthRe
     MaxWidth::new(max_width).show(children)
 }
 
+/// See [Action].
+#[track_caller]
+pub fn action<S>(shortcut: S) -> Response<ActionResponse>
+where
+    S: TryInto<Shortcut>,
+    S::Error: fmt::Display,
+{
+    Action::new().shortcut(shortcut).show()
+}
+
 /// See [Stack].
 #[track_caller]
 pub fn stack(children: impl FnOnce()) -> Response<StackResponse> {
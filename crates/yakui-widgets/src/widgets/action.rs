@@ -0,0 +1,178 @@
+use std::fmt;
+
+use yakui_core::event::{EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::input::{Shortcut, ShortcutScope};
+use yakui_core::widget::{EventContext, LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::{widget, widget_children};
+
+/**
+An action that can be triggered with keyboard shortcuts.
+
+Actions shown with [`Action::show`] are global: they trigger no matter which
+widget is focused. Actions shown with [`Action::show_scoped`] only trigger while
+one of their children is focused, and take priority over global actions, with
+the innermost action winning. Either kind only triggers if the focused widget
+didn't handle the key itself.
+
+Shortcuts can be sequences of chords, like `Mod+K Mod+S`, where `Mod` is Ctrl
+on Windows and Linux and Command on macOS.
+
+Responds with [ActionResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+if yakui::action("Mod+S").triggered {
+    println!("Saving...");
+}
+```
+
+Extended:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Action;
+
+let save = Action::new()
+    .shortcut("Mod+S")
+    .shortcut("Mod+K Mod+S")
+    .show();
+
+// Shows something like "Ctrl+S" or "⌘S"
+yakui::label(save.label.clone().unwrap_or_default());
+
+Action::new().shortcut("Escape").show_scoped(|| {
+    yakui::textbox("Escape is handled by the action while this is focused");
+});
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Action {
+    pub shortcuts: Vec<Shortcut>,
+}
+
+impl Action {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a shortcut that triggers this action. Accepts a [Shortcut], a
+    /// [KeyChord][yakui_core::input::KeyChord], or a string like
+    /// `"Ctrl+Shift+S"`.
+    ///
+    /// # Panics
+    /// Panics if the shortcut is a string that can't be parsed.
+    pub fn shortcut<S>(mut self, shortcut: S) -> Self
+    where
+        S: TryInto<Shortcut>,
+        S::Error: fmt::Display,
+    {
+        match shortcut.try_into() {
+            Ok(shortcut) => self.shortcuts.push(shortcut),
+            Err(err) => panic!("{err}"),
+        }
+        self
+    }
+
+    /// Show the action with shortcuts that are active everywhere.
+    #[track_caller]
+    pub fn show(self) -> Response<ActionResponse> {
+        widget::<ActionWidget>(ActionProps {
+            action: self,
+            scoped: false,
+        })
+    }
+
+    /// Show the action with shortcuts that are only active while one of its
+    /// children is focused.
+    #[track_caller]
+    pub fn show_scoped<F: FnOnce()>(self, children: F) -> Response<ActionResponse> {
+        widget_children::<ActionWidget, F>(
+            children,
+            ActionProps {
+                action: self,
+                scoped: true,
+            },
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ActionProps {
+    action: Action,
+    scoped: bool,
+}
+
+#[derive(Debug)]
+pub struct ActionWidget {
+    props: ActionProps,
+    triggered: bool,
+}
+
+#[derive(Debug)]
+pub struct ActionResponse {
+    /// Whether one of the action's shortcuts was pressed since the last
+    /// update.
+    pub triggered: bool,
+
+    /// The action's first shortcut as it should be displayed on this
+    /// platform, for use in menus and tooltips.
+    pub label: Option<String>,
+}
+
+impl Widget for ActionWidget {
+    type Props<'a> = ActionProps;
+    type Response = ActionResponse;
+
+    fn new() -> Self {
+        Self {
+            props: ActionProps {
+                action: Action::new(),
+                scoped: false,
+            },
+            triggered: false,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        ActionResponse {
+            triggered: std::mem::take(&mut self.triggered),
+            label: self
+                .props
+                .action
+                .shortcuts
+                .first()
+                .map(|shortcut| shortcut.to_string()),
+        }
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let id = ctx.dom.current();
+        let scope = if self.props.scoped {
+            ShortcutScope::Focus(id)
+        } else {
+            ShortcutScope::Global
+        };
+
+        for shortcut in &self.props.action.shortcuts {
+            ctx.input.register_shortcut(id, shortcut.clone(), scope);
+        }
+
+        self.default_layout(ctx, constraints)
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::ShortcutTriggered(_) => {
+                self.triggered = true;
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
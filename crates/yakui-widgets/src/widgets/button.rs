@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
-use yakui_core::geometry::Color;
+use yakui_core::geometry::{Brush, Color};
 use yakui_core::input::MouseButton;
 use yakui_core::widget::{EventContext, Widget};
 use yakui_core::{Alignment, Response};
 
 use crate::border::{Border, BorderRadius};
+use crate::shadow::Shadow;
 use crate::style::{TextAlignment, TextStyle};
 use crate::util::widget;
 use crate::widgets::Pad;
@@ -53,22 +54,25 @@ auto_builders!(Button {
 #[derive(Debug, Clone)]
 pub struct DynamicButtonStyle {
     pub text: TextStyle,
-    pub fill: Color,
+    pub fill: Brush,
     pub border: Option<Border>,
+    pub shadow: Option<Shadow>,
 }
 
 auto_builders!(DynamicButtonStyle {
     text: TextStyle,
-    fill: Color,
+    fill: Brush,
     border: Option<Border>,
+    shadow: Option<Shadow>,
 });
 
 impl Default for DynamicButtonStyle {
     fn default() -> Self {
         Self {
             text: TextStyle::label().align(TextAlignment::Center),
-            fill: Color::GRAY,
+            fill: Color::GRAY.into(),
             border: None,
+            shadow: None,
         }
     }
 }
@@ -88,21 +92,22 @@ impl Button {
 
     pub fn styled(text: impl Into<Cow<'static, str>>) -> Self {
         let style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3,
+            fill: colors::BACKGROUND_3.into(),
             text: TextStyle::label()
                 .color(Color::WHITE.adjust(0.6))
                 .align(TextAlignment::Center),
             border: Some(Border::new(colors::BACKGROUND_1, 1.0)),
+            shadow: None,
         };
 
         let hover_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(1.2),
+            fill: colors::BACKGROUND_3.adjust(1.2).into(),
             border: Some(Border::new(Color::WHITE.adjust(0.75), 1.0)),
             ..Default::default()
         };
 
         let down_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(0.8),
+            fill: colors::BACKGROUND_3.adjust(0.8).into(),
             border: Some(Border::new(Color::WHITE, 1.0)),
             ..Default::default()
         };
@@ -130,12 +135,26 @@ pub struct ButtonWidget {
     hovering: bool,
     mouse_down: bool,
     clicked: bool,
+    click_count: u32,
+    long_pressed: bool,
 }
 
 #[derive(Debug)]
 pub struct ButtonResponse {
     pub hovering: bool,
     pub clicked: bool,
+
+    /// How many times the button was clicked in quick succession, counting
+    /// this click. Zero if the button wasn't clicked.
+    pub click_count: u32,
+
+    /// Whether this click was the second one of a double-click. The first
+    /// click is still reported as a normal click.
+    pub double_clicked: bool,
+
+    /// Whether the button was held down long enough to count as a long press.
+    /// Releasing it afterwards still counts as a click.
+    pub long_pressed: bool,
 }
 
 impl Widget for ButtonWidget {
@@ -148,26 +167,31 @@ impl Widget for ButtonWidget {
             hovering: false,
             mouse_down: false,
             clicked: false,
+            click_count: 0,
+            long_pressed: false,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        let mut color = self.props.style.fill;
+        let mut color = self.props.style.fill.clone();
         let mut border = self.props.style.border;
+        let mut shadow = self.props.style.shadow;
         let mut text_style = self.props.style.text.clone();
 
         if self.mouse_down {
             let style = &self.props.down_style;
-            color = style.fill;
+            color = style.fill.clone();
             text_style = style.text.clone();
             border = style.border;
+            shadow = style.shadow;
         } else if self.hovering {
             let style = &self.props.hover_style;
-            color = style.fill;
+            color = style.fill.clone();
             text_style = style.text.clone();
             border = style.border;
+            shadow = style.shadow;
         }
 
         let align = match text_style.align {
@@ -178,6 +202,7 @@ impl Widget for ButtonWidget {
 
         let container = RoundRect::new(self.props.border_radius)
             .border(border)
+            .shadow(shadow)
             .color(color);
         container.show_children(|| {
             crate::pad(self.props.padding, || {
@@ -187,12 +212,15 @@ impl Widget for ButtonWidget {
             });
         });
 
-        let clicked = self.clicked;
-        self.clicked = false;
+        let clicked = std::mem::take(&mut self.clicked);
+        let click_count = std::mem::take(&mut self.click_count);
 
         Self::Response {
             hovering: self.hovering,
             clicked,
+            click_count,
+            double_clicked: click_count == 2,
+            long_pressed: std::mem::take(&mut self.long_pressed),
         }
     }
 
@@ -214,6 +242,7 @@ impl Widget for ButtonWidget {
                 button: MouseButton::One,
                 down,
                 inside,
+                click_count,
                 ..
             } => {
                 if *inside {
@@ -223,6 +252,7 @@ impl Widget for ButtonWidget {
                     } else if self.mouse_down {
                         self.mouse_down = false;
                         self.clicked = true;
+                        self.click_count = *click_count;
                         EventResponse::Sink
                     } else {
                         EventResponse::Bubble
@@ -235,6 +265,13 @@ impl Widget for ButtonWidget {
                     EventResponse::Bubble
                 }
             }
+            WidgetEvent::MouseLongPress {
+                button: MouseButton::One,
+                ..
+            } if self.mouse_down => {
+                self.long_pressed = true;
+                EventResponse::Sink
+            }
             _ => EventResponse::Bubble,
         }
     }
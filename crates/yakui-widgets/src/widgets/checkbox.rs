@@ -1,7 +1,7 @@
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Constraints, Vec2};
 use yakui_core::input::MouseButton;
-use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{EventContext, IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::shapes::RoundedRectangle;
@@ -86,7 +86,7 @@ impl Widget for CheckboxWidget {
         check_rect.set_pos(check_rect.pos() + padding / 2.0);
         check_rect.set_size(check_rect.size() - padding);
 
-        let bg = RoundedRectangle::new(layout_node.rect, 6.0).color(colors::BACKGROUND_3);
+        let bg = RoundedRectangle::new(layout_node.rect, 6.0).brush(colors::BACKGROUND_3);
         bg.add(ctx.paint);
 
         if self.props.checked {
@@ -98,6 +98,22 @@ impl Widget for CheckboxWidget {
         constraints.constrain_min(Vec2::splat(OUTER_SIZE))
     }
 
+    fn min_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        OUTER_SIZE
+    }
+
+    fn max_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        OUTER_SIZE
+    }
+
+    fn min_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        OUTER_SIZE
+    }
+
+    fn max_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        OUTER_SIZE
+    }
+
     fn event_interest(&self) -> EventInterest {
         EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
     }
@@ -1,5 +1,5 @@
-use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::geometry::{Brush, Constraints, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::util::{widget, widget_children};
@@ -13,19 +13,19 @@ Responds with [CircleResponse].
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Circle {
-    pub color: Color,
+    pub color: Brush,
     pub min_radius: f32,
 }
 
 auto_builders!(Circle {
-    color: Color,
+    color: Brush,
     min_radius: f32,
 });
 
 impl Circle {
     pub fn new() -> Self {
         Self {
-            color: Color::WHITE,
+            color: Brush::default(),
             min_radius: 0.0,
         }
     }
@@ -74,6 +74,26 @@ impl Widget for CircleWidget {
         input.constrain_min(size)
     }
 
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+            .max(self.props.min_radius)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+            .max(self.props.min_radius)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width)
+            .max(self.props.min_radius)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width)
+            .max(self.props.min_radius)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
@@ -82,7 +102,7 @@ impl Widget for CircleWidget {
         let radius = layout_node.rect.size().x.min(layout_node.rect.size().y) / 2.0;
 
         shapes::Circle::new(center, radius)
-            .color(self.props.color)
+            .brush(self.props.color.clone())
             .add(ctx.paint);
 
         for &child in &node.children {
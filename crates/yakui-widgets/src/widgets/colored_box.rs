@@ -1,6 +1,6 @@
-use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::geometry::{Brush, Constraints, Vec2};
 use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::auto_builders;
@@ -14,33 +14,33 @@ Responds with [ColoredBoxResponse].
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct ColoredBox {
-    pub color: Color,
+    pub color: Brush,
     pub min_size: Vec2,
 }
 
 auto_builders!(ColoredBox {
-    color: Color,
+    color: Brush,
     min_size: Vec2,
 });
 
 impl ColoredBox {
     pub fn empty() -> Self {
         Self {
-            color: Color::WHITE,
+            color: Brush::default(),
             min_size: Vec2::ZERO,
         }
     }
 
-    pub fn sized(color: Color, size: Vec2) -> Self {
+    pub fn sized(color: impl Into<Brush>, size: Vec2) -> Self {
         Self {
-            color,
+            color: color.into(),
             min_size: size,
         }
     }
 
-    pub fn container(color: Color) -> Self {
+    pub fn container(color: impl Into<Brush>) -> Self {
         Self {
-            color,
+            color: color.into(),
             min_size: Vec2::ZERO,
         }
     }
@@ -89,12 +89,32 @@ impl Widget for ColoredBoxWidget {
         input.constrain_min(size)
     }
 
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
         let mut rect = PaintRect::new(layout_node.rect);
-        rect.color = self.props.color;
+        rect.brush = self.props.color.clone();
         rect.add(ctx.paint);
 
         for &child in &node.children {
@@ -1,5 +1,5 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
 use yakui_core::Response;
 
 use crate::util::widget_children;
@@ -65,4 +65,28 @@ impl Widget for ConstrainedBoxWidget {
 
         input.constrain(constraints.constrain(size))
     }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let constraints = self.props.constraints;
+        let height = constraints.constrain_height(height);
+        constraints.constrain_width(self.default_min_intrinsic_width(ctx, height))
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let constraints = self.props.constraints;
+        let height = constraints.constrain_height(height);
+        constraints.constrain_width(self.default_max_intrinsic_width(ctx, height))
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let constraints = self.props.constraints;
+        let width = constraints.constrain_width(width);
+        constraints.constrain_height(self.default_min_intrinsic_height(ctx, width))
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let constraints = self.props.constraints;
+        let width = constraints.constrain_width(width);
+        constraints.constrain_height(self.default_max_intrinsic_height(ctx, width))
+    }
 }
@@ -1,7 +1,7 @@
 use crate::auto_builders;
 use crate::shapes::RoundedRectangle;
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::{Response, TextureId};
 
 use crate::util::{widget, widget_children};
@@ -93,6 +93,26 @@ impl Widget for CutOutWidget {
         input.constrain_min(size)
     }
 
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
@@ -105,13 +125,13 @@ impl Widget for CutOutWidget {
             );
 
             RoundedRectangle::new(layout_node.rect, self.props.radius)
-                .color(self.props.image_color)
+                .brush(self.props.image_color)
                 .texture((image, texture_rect))
                 .add(ctx.paint);
         }
 
         RoundedRectangle::new(layout_node.rect, self.props.radius)
-            .color(self.props.overlay_color)
+            .brush(self.props.overlay_color)
             .add(ctx.paint);
 
         for &child in &node.children {
@@ -1,6 +1,6 @@
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::auto_builders;
@@ -77,6 +77,22 @@ impl Widget for DividerWidget {
         )
     }
 
+    fn min_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn min_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        self.props.height
+    }
+
+    fn max_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        self.props.height
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         // We get the parent's width during the paint phase because
         // using constraints.max.x is often useless as it is often infinite.
@@ -100,7 +116,7 @@ impl Widget for DividerWidget {
         );
 
         let mut line_rect = PaintRect::new(Rect::from_pos_size(line_pos, line_size));
-        line_rect.color = self.props.color;
+        line_rect.brush = self.props.color.into();
         line_rect.add(ctx.paint);
     }
 }
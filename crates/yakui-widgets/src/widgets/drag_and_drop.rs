@@ -0,0 +1,548 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::layout::LayoutDom;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{context, Response, WidgetId};
+
+use crate::util::widget_children;
+
+/// How far the cursor has to move while pressed before a drag starts, in
+/// logical pixels.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+const PREVIEW_COLOR: Color = Color::rgba(255, 255, 255, 48);
+
+/**
+A widget that can be dragged onto a [DropTarget], carrying a payload of any
+type.
+
+Pressing the mouse on the source and moving it starts a drag. While dragging,
+a preview follows the cursor in its own layer. Releasing the mouse over a drop
+target that accepts the payload hands the payload to that target.
+
+The preview is positioned using the layout of the previous frame. It escapes
+any containers that clip the source, like [Scrollable][super::Scrollable], so
+it stays visible when dragged outside of them.
+
+Responds with [DragSourceResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::{DragSource, DropTarget};
+let mut slots = [Some("sword"), None];
+
+for index in 0..slots.len() {
+    let dropped = DropTarget::<usize>::new()
+        .show(|| match slots[index] {
+            Some(item) => {
+                DragSource::new(index).show(|| {
+                    yakui::label(item);
+                });
+            }
+            None => {
+                yakui::label("Empty");
+            }
+        })
+        .into_inner()
+        .dropped;
+
+    if let Some(from) = dropped {
+        slots.swap(from, index);
+    }
+}
+```
+*/
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DragSource {
+    payload: Box<dyn Any>,
+}
+
+impl DragSource {
+    /// Create a new drag source that carries the given payload. The payload
+    /// given on the frame that a drag starts is the one that is delivered.
+    pub fn new<T: Any>(payload: T) -> Self {
+        Self {
+            payload: Box::new(payload),
+        }
+    }
+
+    /// Show the drag source. While dragging, a translucent box the size of
+    /// the source follows the cursor.
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<DragSourceResponse> {
+        self.show_with_preview(children, || {})
+    }
+
+    /// Show the drag source, building `preview` to follow the cursor while
+    /// dragging.
+    #[track_caller]
+    pub fn show_with_preview<F: FnOnce(), P: FnOnce()>(
+        self,
+        children: F,
+        preview: P,
+    ) -> Response<DragSourceResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<DragSourceWidget>(self);
+
+        children();
+
+        if response.dragging {
+            widget_children::<DragPreviewWidget, P>(preview, ());
+        }
+
+        dom.end_widget::<DragSourceWidget>(response.id);
+        response
+    }
+}
+
+impl fmt::Debug for DragSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragSource").finish_non_exhaustive()
+    }
+}
+
+/**
+A widget that payloads of type `T` can be dropped onto from a [DragSource].
+
+Payloads of other types are rejected. Targets can further restrict what they
+accept with [DropTarget::accept].
+
+Responds with [DropTargetResponse].
+*/
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DropTarget<'a, T> {
+    accept: Option<&'a dyn Fn(&T) -> bool>,
+}
+
+impl<'a, T: Any> DropTarget<'a, T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { accept: None }
+    }
+
+    /// Only accept payloads for which `accept` returns `true`.
+    pub fn accept(mut self, accept: &'a dyn Fn(&T) -> bool) -> Self {
+        self.accept = Some(accept);
+        self
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<DropTargetResponse<T>> {
+        widget_children::<DropTargetWidget<T>, F>(children, self)
+    }
+}
+
+impl<T> fmt::Debug for DropTarget<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropTarget")
+            .field("accept", &self.accept.is_some())
+            .finish()
+    }
+}
+
+/// Whether a [DropTarget] would accept the payload being dragged over it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropHover {
+    Accept,
+    Reject,
+}
+
+#[derive(Debug)]
+pub struct DragSourceResponse {
+    /// Whether this source is being dragged.
+    pub dragging: bool,
+
+    /// The drop target that accepted this source's payload since the last
+    /// update, if any.
+    pub dropped_on: Option<WidgetId>,
+}
+
+#[derive(Debug)]
+pub struct DropTargetResponse<T> {
+    /// Whether a payload this target would accept is being dragged anywhere.
+    pub can_accept: bool,
+
+    /// Whether a payload is being dragged over this target, and whether it
+    /// would be accepted.
+    pub hovering: Option<DropHover>,
+
+    /// The payload that was dropped onto this target since the last update.
+    pub dropped: Option<T>,
+}
+
+/// Drag and drop state shared by every source and target in a DOM.
+#[derive(Clone, Default)]
+struct DragAndDrop(Rc<RefCell<DragAndDropState>>);
+
+#[derive(Default)]
+struct DragAndDropState {
+    active: Option<ActiveDrag>,
+
+    /// Drop targets and whether they accept the active payload.
+    targets: Vec<(WidgetId, bool)>,
+
+    /// A payload that was dropped and hasn't been picked up by its target.
+    dropped: Option<(WidgetId, Box<dyn Any>)>,
+}
+
+struct ActiveDrag {
+    source: WidgetId,
+    payload: Box<dyn Any>,
+
+    /// The position of the cursor in logical pixels.
+    position: Vec2,
+
+    /// Where the cursor grabbed the source, relative to its top left.
+    grab_offset: Vec2,
+
+    /// The topmost drop target under the cursor.
+    hovered: Option<WidgetId>,
+}
+
+impl DragAndDrop {
+    fn get() -> Self {
+        context::dom().get_global_or_init(Self::default)
+    }
+}
+
+impl DragAndDropState {
+    /// Finds the topmost drop target under the given point, forgetting
+    /// targets that were removed. Targets are hit tested like any other
+    /// widget, so layers and clipping are respected.
+    fn target_at(&mut self, layout: &LayoutDom, position: Vec2) -> Option<(WidgetId, bool)> {
+        self.targets.retain(|&(id, _)| layout.get(id).is_some());
+
+        let mut hits = Vec::new();
+        layout.hit_test(position, &mut hits);
+
+        hits.into_iter().find_map(|hit| {
+            self.targets
+                .iter()
+                .copied()
+                .find(|&(target, _)| target == hit)
+        })
+    }
+}
+
+#[derive(Debug)]
+enum SourceState {
+    Idle,
+    Pressed { position: Vec2 },
+    Dragging,
+}
+
+pub struct DragSourceWidget {
+    payload: Option<Box<dyn Any>>,
+    state: SourceState,
+    dropped_on: Option<WidgetId>,
+}
+
+impl fmt::Debug for DragSourceWidget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragSourceWidget")
+            .field("state", &self.state)
+            .field("dropped_on", &self.dropped_on)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DragSourceWidget {
+    fn cancel(&mut self, ctx: &EventContext<'_>) {
+        let shared = ctx.dom.get_global_or_init(DragAndDrop::default);
+        let mut shared = shared.0.borrow_mut();
+        let id = ctx.dom.current();
+
+        if shared
+            .active
+            .as_ref()
+            .is_some_and(|active| active.source == id)
+        {
+            shared.active = None;
+        }
+
+        self.state = SourceState::Idle;
+    }
+}
+
+impl Widget for DragSourceWidget {
+    type Props<'a> = DragSource;
+    type Response = DragSourceResponse;
+
+    fn new() -> Self {
+        Self {
+            payload: None,
+            state: SourceState::Idle,
+            dropped_on: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.payload = Some(props.payload);
+
+        DragSourceResponse {
+            dragging: matches!(self.state, SourceState::Dragging),
+            dropped_on: self.dropped_on.take(),
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        // Our layout from the previous frame is still around, so we can use
+        // it to place the preview relative to ourselves.
+        let previous = ctx.layout.get(ctx.dom.current()).map(|node| node.rect);
+
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let is_preview = ctx
+                .dom
+                .get(child)
+                .is_some_and(|node| node.widget.as_any().is::<DragPreviewWidget>());
+
+            if is_preview {
+                ctx.calculate_layout(child, Constraints::none());
+
+                let shared = ctx.dom.get_global_or_init(DragAndDrop::default);
+                let shared = shared.0.borrow();
+                if let (Some(active), Some(previous)) = (&shared.active, previous) {
+                    let pos = active.position - active.grab_offset - previous.pos();
+                    ctx.layout.set_pos(child, pos);
+                }
+            } else {
+                let child_size = ctx.calculate_layout(child, constraints);
+                size = size.max(child_size);
+            }
+        }
+
+        constraints.constrain_min(size)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match *event {
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: true,
+                inside: true,
+                position,
+                ..
+            } => {
+                ctx.capture_pointer();
+                self.state = SourceState::Pressed { position };
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                position,
+                ..
+            } => {
+                if let SourceState::Dragging = self.state {
+                    let shared = ctx.dom.get_global_or_init(DragAndDrop::default);
+                    let mut shared = shared.0.borrow_mut();
+
+                    let target = shared.target_at(ctx.layout, position);
+                    if let Some(active) = shared.active.take() {
+                        if let Some((target, true)) = target {
+                            shared.dropped = Some((target, active.payload));
+                            self.dropped_on = Some(target);
+                        }
+                    }
+                }
+
+                let handled = !matches!(self.state, SourceState::Idle);
+                self.state = SourceState::Idle;
+
+                if handled {
+                    EventResponse::Sink
+                } else {
+                    EventResponse::Bubble
+                }
+            }
+
+            WidgetEvent::MouseMoved(Some(position)) => match self.state {
+                SourceState::Pressed { position: start } => {
+                    if position.distance(start) < DRAG_THRESHOLD {
+                        return EventResponse::Bubble;
+                    }
+
+                    let (Some(payload), Some(node)) =
+                        (self.payload.take(), ctx.layout.get(ctx.dom.current()))
+                    else {
+                        return EventResponse::Bubble;
+                    };
+
+                    let shared = ctx.dom.get_global_or_init(DragAndDrop::default);
+                    let mut shared = shared.0.borrow_mut();
+                    let hovered = shared.target_at(ctx.layout, position).map(|(id, _)| id);
+                    shared.active = Some(ActiveDrag {
+                        source: ctx.dom.current(),
+                        payload,
+                        position,
+                        grab_offset: start - node.rect.pos(),
+                        hovered,
+                    });
+
+                    self.state = SourceState::Dragging;
+                    EventResponse::Sink
+                }
+
+                SourceState::Dragging => {
+                    let shared = ctx.dom.get_global_or_init(DragAndDrop::default);
+                    let mut shared = shared.0.borrow_mut();
+                    let hovered = shared.target_at(ctx.layout, position).map(|(id, _)| id);
+
+                    if let Some(active) = &mut shared.active {
+                        active.position = position;
+                        active.hovered = hovered;
+                    }
+
+                    EventResponse::Sink
+                }
+
+                SourceState::Idle => EventResponse::Bubble,
+            },
+
+            WidgetEvent::PointerCaptureLost => {
+                self.cancel(&ctx);
+                EventResponse::Bubble
+            }
+
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+/// Follows the cursor while a [DragSource] is dragged.
+#[derive(Debug)]
+pub struct DragPreviewWidget;
+
+impl Widget for DragPreviewWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        // The preview follows the cursor anywhere in the window, so it can't
+        // be clipped by a scrolling container around its source.
+        ctx.layout.new_layer(ctx.dom);
+        ctx.layout.escape_clipping(ctx.dom);
+
+        let node = ctx.dom.get_current();
+
+        // With no custom preview, match the size the source had last frame.
+        if node.children.is_empty() {
+            return node
+                .parent
+                .and_then(|parent| ctx.layout.get(parent))
+                .map(|parent| parent.rect.size())
+                .unwrap_or(Vec2::ZERO);
+        }
+
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+
+        size
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+
+        if node.children.is_empty() {
+            let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+            let mut rect = PaintRect::new(layout_node.rect);
+            rect.brush = PREVIEW_COLOR.into();
+            rect.add(ctx.paint);
+        } else {
+            self.default_paint(ctx);
+        }
+    }
+}
+
+pub struct DropTargetWidget<T> {
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T> fmt::Debug for DropTargetWidget<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropTargetWidget").finish()
+    }
+}
+
+impl<T: Any> Widget for DropTargetWidget<T> {
+    type Props<'a> = DropTarget<'a, T>;
+    type Response = DropTargetResponse<T>;
+
+    fn new() -> Self {
+        Self {
+            _payload: PhantomData,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let id = context::dom().current();
+        let shared = DragAndDrop::get();
+        let mut shared = shared.0.borrow_mut();
+
+        let dropped = match shared.dropped.take() {
+            Some((target, payload)) if target == id => payload.downcast::<T>().ok().map(|p| *p),
+            other => {
+                shared.dropped = other;
+                None
+            }
+        };
+
+        let accepts = shared.active.as_ref().is_some_and(|active| {
+            active
+                .payload
+                .downcast_ref::<T>()
+                .is_some_and(|payload| props.accept.is_none_or(|accept| accept(payload)))
+        });
+
+        let hovering = shared
+            .active
+            .as_ref()
+            .filter(|active| active.hovered == Some(id))
+            .map(|_| {
+                if accepts {
+                    DropHover::Accept
+                } else {
+                    DropHover::Reject
+                }
+            });
+
+        shared.targets.retain(|&(target, _)| target != id);
+        shared.targets.push((id, accepts));
+
+        DropTargetResponse {
+            can_accept: accepts,
+            hovering,
+            dropped,
+        }
+    }
+
+    // Drop targets don't handle any events, but need to be hit tested to find
+    // the target under a drag.
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+}
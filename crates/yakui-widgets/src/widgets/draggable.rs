@@ -62,7 +62,7 @@ impl Widget for DraggableWidget {
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_ALL
+        EventInterest::MOUSE_INSIDE
     }
 
     fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
@@ -76,6 +76,7 @@ impl Widget for DraggableWidget {
             } => {
                 if down && inside {
                     let node = ctx.layout.get(ctx.dom.current()).unwrap();
+                    ctx.capture_pointer();
 
                     self.current_drag = Some(DragState {
                         start_position: node.rect.pos(),
@@ -98,6 +99,10 @@ impl Widget for DraggableWidget {
 
                 EventResponse::Bubble
             }
+            WidgetEvent::PointerCaptureLost => {
+                self.current_drag = None;
+                EventResponse::Bubble
+            }
             _ => EventResponse::Bubble,
         }
     }
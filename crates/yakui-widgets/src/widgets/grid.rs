@@ -0,0 +1,836 @@
+use std::collections::HashSet;
+
+use yakui_core::dom::Dom;
+use yakui_core::geometry::{Constraints, Dim, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{Alignment, Flow, Response, WidgetId};
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Lays out children in rows and columns whose sizes are described by tracks,
+like a CSS grid.
+
+Each column and row is a [GridTrack] that can have a fixed size, take a share of
+the leftover space, or fit its contents. Children are placed in the next free
+cell from left to right and top to bottom, unless they're wrapped in a
+[GridItem] that places them in a specific cell or makes them span several.
+
+Rows past the ones given in `rows` are sized with `auto_rows`.
+
+Responds with [GridResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::GridTrack;
+
+yakui::grid([GridTrack::auto(), GridTrack::fr(1.0)], || {
+    yakui::label("Name");
+    yakui::textbox("Ferris");
+
+    yakui::label("Species");
+    yakui::textbox("Crab");
+});
+```
+
+Extended:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Grid, GridItem, GridTrack};
+
+Grid::new()
+    .columns([
+        GridTrack::px(100.0),
+        GridTrack::fr(1.0),
+        GridTrack::min_max(GridTrack::px(50.0), GridTrack::fr(2.0)),
+    ])
+    .column_gap(8.0)
+    .row_gap(4.0)
+    .show(|| {
+        GridItem::new().column_span(3).show(|| {
+            yakui::label("Spans the whole first row");
+        });
+
+        GridItem::at(2, 1).show(|| {
+            yakui::label("Placed in the middle of the third row");
+        });
+
+        yakui::label("Placed automatically");
+    });
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Grid {
+    pub columns: Vec<GridTrack>,
+    pub rows: Vec<GridTrack>,
+    /// The track used for rows that aren't listed in `rows`.
+    pub auto_rows: GridTrack,
+    /// Space between adjacent columns.
+    pub column_gap: f32,
+    /// Space between adjacent rows.
+    pub row_gap: f32,
+    /// Where children smaller than their cell are placed within it.
+    pub item_alignment: Alignment,
+    /// Whether children are forced to fill their cells.
+    pub stretch_items: bool,
+}
+
+auto_builders!(Grid {
+    columns: Vec<GridTrack>,
+    rows: Vec<GridTrack>,
+    auto_rows: GridTrack,
+    column_gap: f32,
+    row_gap: f32,
+    item_alignment: Alignment,
+    stretch_items: bool,
+});
+
+impl Grid {
+    pub fn new() -> Self {
+        Self {
+            columns: vec![GridTrack::auto()],
+            rows: Vec::new(),
+            auto_rows: GridTrack::auto(),
+            column_gap: 0.0,
+            row_gap: 0.0,
+            item_alignment: Alignment::TOP_LEFT,
+            stretch_items: false,
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<GridResponse> {
+        widget_children::<GridWidget, F>(children, self)
+    }
+}
+
+/// Describes how big a column or row of a [Grid] is.
+///
+/// Tracks have a minimum and maximum sizing function, like CSS's `minmax()`.
+/// Most tracks use the same function for both.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridTrack {
+    pub min: TrackSize,
+    pub max: TrackSize,
+}
+
+/// One of the sizing functions of a [GridTrack].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackSize {
+    /// A fixed length, where percentages are of the grid's size. Percentages
+    /// resolve to zero if the grid's size along the axis is unbounded.
+    Fixed(Dim),
+
+    /// Fits the children in the track.
+    Auto,
+
+    /// A share of the space left over after every other track is sized,
+    /// proportional to this factor. Behaves like [TrackSize::Auto] when used
+    /// as a minimum.
+    Fraction(f32),
+}
+
+impl GridTrack {
+    /// A track that's always the given number of logical pixels.
+    pub fn px(pixels: f32) -> Self {
+        Self::fixed(Dim::pixels(pixels))
+    }
+
+    /// A track that's a percentage of the grid's size, where `1.0` is 100%.
+    pub fn percent(percent: f32) -> Self {
+        Self::fixed(Dim::percent(percent))
+    }
+
+    /// A track with a fixed length.
+    pub fn fixed(dim: Dim) -> Self {
+        Self {
+            min: TrackSize::Fixed(dim),
+            max: TrackSize::Fixed(dim),
+        }
+    }
+
+    /// A track that fits its children.
+    pub fn auto() -> Self {
+        Self {
+            min: TrackSize::Auto,
+            max: TrackSize::Auto,
+        }
+    }
+
+    /// A track that takes a share of the leftover space, but is never smaller
+    /// than the minimum content size of its children, like `1fr` in CSS.
+    pub fn fr(fraction: f32) -> Self {
+        Self {
+            min: TrackSize::Auto,
+            max: TrackSize::Fraction(fraction),
+        }
+    }
+
+    /// A track that's sized by the maximum of `max`, but is never smaller than
+    /// the minimum of `min`.
+    pub fn min_max(min: GridTrack, max: GridTrack) -> Self {
+        Self {
+            min: min.min,
+            max: max.max,
+        }
+    }
+
+    fn fraction(&self) -> Option<f32> {
+        match self.max {
+            TrackSize::Fraction(fraction) => Some(fraction),
+            _ => None,
+        }
+    }
+
+    fn is_content_sized(&self) -> bool {
+        !matches!(self.min, TrackSize::Fixed(_)) || self.max == TrackSize::Auto
+    }
+}
+
+/**
+Places its child in a specific cell of a [Grid], optionally spanning several
+columns or rows. Cells left as `None` are picked automatically.
+
+Outside of a grid, this does nothing.
+
+Responds with [GridItemResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Grid, GridItem, GridTrack};
+
+Grid::new()
+    .columns([GridTrack::fr(1.0), GridTrack::fr(1.0)])
+    .show(|| {
+        GridItem::at(0, 1).row_span(2).show(|| {
+            yakui::label("Right column, two rows tall");
+        });
+    });
+```
+*/
+#[derive(Debug, Clone, Copy)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct GridItem {
+    pub row: Option<usize>,
+    pub column: Option<usize>,
+    pub row_span: usize,
+    pub column_span: usize,
+}
+
+impl GridItem {
+    /// An item placed in the next free cell.
+    pub fn new() -> Self {
+        Self {
+            row: None,
+            column: None,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+
+    /// An item placed in the given row and column, counting from zero.
+    pub fn at(row: usize, column: usize) -> Self {
+        Self {
+            row: Some(row),
+            column: Some(column),
+            ..Self::new()
+        }
+    }
+
+    /// Places the item in the given row, counting from zero.
+    pub fn row(self, row: usize) -> Self {
+        Self {
+            row: Some(row),
+            ..self
+        }
+    }
+
+    /// Places the item in the given column, counting from zero.
+    pub fn column(self, column: usize) -> Self {
+        Self {
+            column: Some(column),
+            ..self
+        }
+    }
+
+    pub fn row_span(self, row_span: usize) -> Self {
+        Self { row_span, ..self }
+    }
+
+    pub fn column_span(self, column_span: usize) -> Self {
+        Self {
+            column_span,
+            ..self
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<GridItemResponse> {
+        widget_children::<GridItemWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct GridItemWidget {
+    props: GridItem,
+}
+
+pub type GridItemResponse = ();
+
+impl Widget for GridItemWidget {
+    type Props<'a> = GridItem;
+    type Response = GridItemResponse;
+
+    fn new() -> Self {
+        Self {
+            props: GridItem::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+}
+
+#[derive(Debug)]
+pub struct GridWidget {
+    props: Grid,
+}
+
+pub type GridResponse = ();
+
+/// Where a child ended up in the grid.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    id: WidgetId,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+impl Widget for GridWidget {
+    type Props<'a> = Grid;
+    type Response = GridResponse;
+
+    fn new() -> Self {
+        Self { props: Grid::new() }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let placements = self.place_children(ctx.dom, &node.children);
+        let (columns, rows) = self.tracks(&placements);
+
+        // Columns are sized first from how wide each child would like to be,
+        // without laying anything out.
+        let column_items = column_items(ctx.intrinsic(), &placements);
+        let column_sizes = size_tracks(&columns, input.max.x, self.props.column_gap, &column_items);
+
+        // Rows are sized using each child's height at the width of its
+        // columns.
+        let measured = placements
+            .iter()
+            .map(|p| {
+                let width = span_size(
+                    &column_sizes,
+                    p.column,
+                    p.column_span,
+                    self.props.column_gap,
+                );
+                let constraints = Constraints::loose(Vec2::new(width, f32::INFINITY));
+                ctx.calculate_layout(p.id, constraints)
+            })
+            .collect::<Vec<_>>();
+        let row_items = placements
+            .iter()
+            .zip(&measured)
+            .map(|(p, size)| TrackItem::new(p.row, p.row_span, size.y))
+            .collect::<Vec<_>>();
+        let row_sizes = size_tracks(&rows, input.max.y, self.props.row_gap, &row_items);
+
+        let column_starts = track_starts(&column_sizes, self.props.column_gap);
+        let row_starts = track_starts(&row_sizes, self.props.row_gap);
+
+        for (p, &measured) in placements.iter().zip(&measured) {
+            let cell = Vec2::new(
+                span_size(
+                    &column_sizes,
+                    p.column,
+                    p.column_span,
+                    self.props.column_gap,
+                ),
+                span_size(&row_sizes, p.row, p.row_span, self.props.row_gap),
+            );
+
+            // A child that already fits in its cell at the cell's width would
+            // come out the same size, so it only needs another layout when it
+            // has to stretch or shrink.
+            let size = if self.props.stretch_items {
+                ctx.calculate_layout(p.id, Constraints::tight(cell))
+            } else if measured.y <= cell.y {
+                measured
+            } else {
+                ctx.calculate_layout(p.id, Constraints::loose(cell))
+            };
+
+            let offset = (cell - size).max(Vec2::ZERO) * self.props.item_alignment.as_vec2();
+            let pos = Vec2::new(column_starts[p.column], row_starts[p.row]) + offset;
+            ctx.layout.set_pos(p.id, pos);
+        }
+
+        let content = Vec2::new(
+            span_size(&column_sizes, 0, column_sizes.len(), self.props.column_gap),
+            span_size(&row_sizes, 0, row_sizes.len(), self.props.row_gap),
+        );
+        let container_size = input.constrain(content);
+
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if let Flow::Relative { anchor, offset } = child.widget.flow() {
+                ctx.calculate_layout(child_id, Constraints::none());
+
+                let anchor = container_size * anchor.as_vec2();
+                let offset = offset.resolve(container_size);
+                ctx.layout.set_pos(child_id, anchor + offset);
+            }
+        }
+
+        container_size
+    }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        self.intrinsic_width(ctx, false)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        self.intrinsic_width(ctx, true)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.intrinsic_height(ctx, width, false)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.intrinsic_height(ctx, width, true)
+    }
+}
+
+impl GridWidget {
+    /// The columns and rows needed to fit every placed child.
+    fn tracks(&self, placements: &[Placement]) -> (Vec<GridTrack>, Vec<GridTrack>) {
+        let column_count = placements
+            .iter()
+            .map(|p| p.column + p.column_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.props.columns.len());
+        let row_count = placements
+            .iter()
+            .map(|p| p.row + p.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.props.rows.len());
+
+        let columns = (0..column_count)
+            .map(|i| {
+                self.props
+                    .columns
+                    .get(i)
+                    .copied()
+                    .unwrap_or(GridTrack::auto())
+            })
+            .collect();
+        let rows = (0..row_count)
+            .map(|i| {
+                self.props
+                    .rows
+                    .get(i)
+                    .copied()
+                    .unwrap_or(self.props.auto_rows)
+            })
+            .collect();
+
+        (columns, rows)
+    }
+
+    /// The width of all columns when every child gets its minimum or maximum
+    /// intrinsic width.
+    fn intrinsic_width(&self, ctx: IntrinsicContext<'_>, max: bool) -> f32 {
+        let node = ctx.dom.get_current();
+        let placements = self.place_children(ctx.dom, &node.children);
+        let (columns, _rows) = self.tracks(&placements);
+
+        let items = column_items(ctx, &placements)
+            .into_iter()
+            .map(|item| {
+                let size = if max {
+                    item.max_content
+                } else {
+                    item.min_content
+                };
+                TrackItem::new(item.start, item.span, size)
+            })
+            .collect::<Vec<_>>();
+
+        let sizes = size_tracks(&columns, f32::INFINITY, self.props.column_gap, &items);
+        span_size(&sizes, 0, sizes.len(), self.props.column_gap)
+    }
+
+    /// The height of all rows when the grid is given `width`, and every child
+    /// gets its minimum or maximum intrinsic height at the width of its
+    /// columns.
+    fn intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32, max: bool) -> f32 {
+        let node = ctx.dom.get_current();
+        let placements = self.place_children(ctx.dom, &node.children);
+        let (columns, rows) = self.tracks(&placements);
+
+        let column_items = column_items(ctx, &placements);
+        let column_sizes = size_tracks(&columns, width, self.props.column_gap, &column_items);
+
+        let row_items = placements
+            .iter()
+            .map(|p| {
+                let width = span_size(
+                    &column_sizes,
+                    p.column,
+                    p.column_span,
+                    self.props.column_gap,
+                );
+                let height = if max {
+                    ctx.max_intrinsic_height(p.id, width)
+                } else {
+                    ctx.min_intrinsic_height(p.id, width)
+                };
+                TrackItem::new(p.row, p.row_span, height)
+            })
+            .collect::<Vec<_>>();
+
+        let sizes = size_tracks(&rows, f32::INFINITY, self.props.row_gap, &row_items);
+        span_size(&sizes, 0, sizes.len(), self.props.row_gap)
+    }
+
+    /// Places children with explicit cells first, then fills the remaining
+    /// cells in order with everything else.
+    fn place_children(&self, dom: &Dom, children: &[WidgetId]) -> Vec<Placement> {
+        let items = children
+            .iter()
+            .filter_map(|&id| {
+                let child = dom.get(id).unwrap();
+                if child.widget.flow() != Flow::Inline {
+                    return None;
+                }
+
+                let item = child
+                    .widget
+                    .as_any()
+                    .downcast_ref::<GridItemWidget>()
+                    .map(|widget| widget.props)
+                    .unwrap_or(GridItem::new());
+                Some((id, item))
+            })
+            .collect::<Vec<_>>();
+
+        // Explicitly placed children can make the grid wider, but children
+        // placed automatically wrap at the last column.
+        let column_count = items
+            .iter()
+            .filter_map(|(_, item)| Some(item.column? + item.column_span.max(1)))
+            .max()
+            .unwrap_or(0)
+            .max(self.props.columns.len())
+            .max(1);
+
+        let mut occupied = HashSet::new();
+        let mut placements = Vec::new();
+        let mut place =
+            |occupied: &mut HashSet<(usize, usize)>, id, row, column, item: &GridItem| {
+                let row_span = item.row_span.max(1);
+                let column_span = item.column_span.max(1);
+                for r in row..row + row_span {
+                    for c in column..column + column_span {
+                        occupied.insert((r, c));
+                    }
+                }
+
+                placements.push(Placement {
+                    id,
+                    row,
+                    column,
+                    row_span,
+                    column_span,
+                });
+            };
+
+        let fits =
+            |occupied: &HashSet<(usize, usize)>, row: usize, column: usize, item: &GridItem| {
+                (row..row + item.row_span.max(1)).all(|r| {
+                    (column..column + item.column_span.max(1)).all(|c| !occupied.contains(&(r, c)))
+                })
+            };
+
+        for (id, item) in &items {
+            if let (Some(row), Some(column)) = (item.row, item.column) {
+                place(&mut occupied, *id, row, column, item);
+            }
+        }
+
+        let mut cursor = (0, 0);
+        for (id, item) in &items {
+            let column_span = item.column_span.max(1).min(column_count);
+            let last_column = column_count - column_span;
+            let item = GridItem {
+                column_span,
+                ..*item
+            };
+
+            let (row, column) = match (item.row, item.column) {
+                (Some(_), Some(_)) => continue,
+
+                (Some(row), None) => {
+                    let column = (0..=last_column)
+                        .find(|&c| fits(&occupied, row, c, &item))
+                        .unwrap_or(0);
+                    (row, column)
+                }
+
+                (None, Some(column)) => {
+                    let row = (0..).find(|&r| fits(&occupied, r, column, &item)).unwrap();
+                    (row, column)
+                }
+
+                (None, None) => {
+                    let (mut row, mut column) = cursor;
+                    loop {
+                        if column > last_column {
+                            row += 1;
+                            column = 0;
+                        } else if fits(&occupied, row, column, &item) {
+                            break;
+                        } else {
+                            column += 1;
+                        }
+                    }
+
+                    cursor = (row, column + column_span);
+                    (row, column)
+                }
+            };
+
+            place(&mut occupied, *id, row, column, &item);
+        }
+
+        placements
+    }
+}
+
+/// What a child needs from the tracks it spans along one axis.
+#[derive(Debug, Clone, Copy)]
+struct TrackItem {
+    start: usize,
+    span: usize,
+
+    /// The smallest size the child fits in, like the width of its longest
+    /// word. Content-sized tracks never shrink below this.
+    min_content: f32,
+
+    /// The size the child would like to have, like the width of its text
+    /// without wrapping.
+    max_content: f32,
+}
+
+impl TrackItem {
+    fn new(start: usize, span: usize, size: f32) -> Self {
+        Self {
+            start,
+            span,
+            min_content: size,
+            max_content: size,
+        }
+    }
+}
+
+/// Measures how wide each child would like to be without laying it out.
+fn column_items(ctx: IntrinsicContext<'_>, placements: &[Placement]) -> Vec<TrackItem> {
+    placements
+        .iter()
+        .map(|p| TrackItem {
+            start: p.column,
+            span: p.column_span,
+            min_content: ctx.min_intrinsic_width(p.id, f32::INFINITY),
+            max_content: ctx.max_intrinsic_width(p.id, f32::INFINITY),
+        })
+        .collect()
+}
+
+/// Sizes a grid's tracks along one axis.
+///
+/// Content-sized minimums only guarantee the children's minimum content size.
+/// Tracks that fit their contents then grow to the maximum content size, while
+/// fractional tracks take their share of the leftover space, so that their
+/// children can wrap instead of forcing them wider.
+fn size_tracks(tracks: &[GridTrack], available: f32, gap: f32, items: &[TrackItem]) -> Vec<f32> {
+    let resolve = |dim: Dim| {
+        if available.is_finite() {
+            dim.resolve(available)
+        } else {
+            dim.pixels
+        }
+    };
+
+    let min_content = content_sizes(tracks, gap, items, resolve, |item| item.min_content);
+    let max_content = content_sizes(tracks, gap, items, resolve, |item| item.max_content);
+
+    let mut sizes = tracks
+        .iter()
+        .enumerate()
+        .map(|(i, track)| {
+            let min = match track.min {
+                TrackSize::Fixed(dim) => resolve(dim),
+                TrackSize::Auto | TrackSize::Fraction(_) => min_content[i],
+            };
+
+            match track.max {
+                TrackSize::Fixed(dim) => resolve(dim).max(min),
+                TrackSize::Auto => max_content[i].max(min),
+                TrackSize::Fraction(_) => min,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Fractional tracks split the leftover space. A track whose minimum is
+    // bigger than its share keeps its minimum and is left out of the split.
+    let mut flexible = (0..tracks.len())
+        .filter(|&i| tracks[i].fraction().is_some())
+        .collect::<Vec<_>>();
+    let gaps = gap * tracks.len().saturating_sub(1) as f32;
+
+    let fraction_size = if available.is_finite() {
+        loop {
+            let fixed = (0..tracks.len())
+                .filter(|i| !flexible.contains(i))
+                .map(|i| sizes[i])
+                .sum::<f32>();
+            let total_fraction = flexible
+                .iter()
+                .map(|&i| tracks[i].fraction().unwrap())
+                .sum::<f32>();
+            if total_fraction <= 0.0 {
+                break 0.0;
+            }
+
+            let fraction_size = (available - fixed - gaps).max(0.0) / total_fraction;
+            let before = flexible.len();
+            flexible.retain(|&i| tracks[i].fraction().unwrap() * fraction_size >= sizes[i]);
+
+            if flexible.len() == before {
+                break fraction_size;
+            }
+        }
+    } else {
+        // Without a size to fill, fractions stay proportional while fitting
+        // every track's contents without wrapping.
+        flexible
+            .iter()
+            .map(|&i| {
+                let fraction = tracks[i].fraction().unwrap();
+                if fraction > 0.0 {
+                    sizes[i].max(max_content[i]) / fraction
+                } else {
+                    0.0
+                }
+            })
+            .fold(0.0, f32::max)
+    };
+
+    for i in flexible {
+        sizes[i] = sizes[i].max(tracks[i].fraction().unwrap() * fraction_size);
+    }
+
+    sizes
+}
+
+/// Finds how much each content-sized track needs to fit the children in it.
+/// Children spanning several tracks are handled after the ones in a single
+/// track, growing the content-sized tracks they span evenly.
+fn content_sizes(
+    tracks: &[GridTrack],
+    gap: f32,
+    items: &[TrackItem],
+    resolve: impl Fn(Dim) -> f32 + Copy,
+    size: impl Fn(&TrackItem) -> f32,
+) -> Vec<f32> {
+    let mut content = vec![0.0f32; tracks.len()];
+    for item in items {
+        if item.span == 1 && tracks[item.start].is_content_sized() {
+            content[item.start] = content[item.start].max(size(item));
+        }
+    }
+
+    for item in items {
+        let range = item.start..item.start + item.span;
+        if item.span == 1 || tracks[range.clone()].iter().any(|t| t.fraction().is_some()) {
+            continue;
+        }
+
+        let growable = range
+            .clone()
+            .filter(|&i| tracks[i].is_content_sized())
+            .collect::<Vec<_>>();
+        if growable.is_empty() {
+            continue;
+        }
+
+        let current = range
+            .map(|i| fixed_or_content(&tracks[i], content[i], resolve))
+            .sum::<f32>()
+            + gap * (item.span - 1) as f32;
+        let extra = (size(item) - current).max(0.0) / growable.len() as f32;
+        for i in growable {
+            content[i] += extra;
+        }
+    }
+
+    content
+}
+
+/// The size of a track before fractions are resolved, used to see how much
+/// room spanning children already have.
+fn fixed_or_content(track: &GridTrack, content: f32, resolve: impl Fn(Dim) -> f32) -> f32 {
+    match (track.min, track.max) {
+        (TrackSize::Fixed(min), TrackSize::Fixed(max)) => resolve(max).max(resolve(min)),
+        (TrackSize::Fixed(min), _) => resolve(min).max(content),
+        _ => content,
+    }
+}
+
+/// The total size of `span` tracks starting at `start`, including the gaps
+/// between them.
+fn span_size(sizes: &[f32], start: usize, span: usize, gap: f32) -> f32 {
+    let end = (start + span).min(sizes.len());
+    if start >= end {
+        return 0.0;
+    }
+
+    sizes[start..end].iter().sum::<f32>() + gap * (end - start - 1) as f32
+}
+
+/// Where each track starts.
+fn track_starts(sizes: &[f32], gap: f32) -> Vec<f32> {
+    let mut next = 0.0;
+    sizes
+        .iter()
+        .map(|size| {
+            let start = next;
+            next += size + gap;
+            start
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::{Response, TextureId};
 
 use crate::auto_builders;
@@ -106,12 +106,28 @@ impl Widget for ImageWidget {
         output_size
     }
 
+    fn min_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        self.props.size.x
+    }
+
+    fn max_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        self.props.size.x
+    }
+
+    fn min_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        self.props.size.y
+    }
+
+    fn max_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        self.props.size.y
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
         if let Some(image) = self.props.image {
             let mut rect = PaintRect::new(layout_node.rect);
-            rect.color = self.props.color;
+            rect.brush = self.props.color.into();
             rect.texture = Some((image, Rect::ONE));
             rect.add(ctx.paint);
         }
@@ -0,0 +1,474 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::rc::Rc;
+
+use yakui_core::context;
+use yakui_core::dom::Dom;
+use yakui_core::geometry::{Color, Constraints, FlexFit, Rect, Vec2};
+use yakui_core::layout::LayoutDom;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{Alignment, Flow, Response, WidgetId};
+
+use crate::style::{TextAlignment, TextStyle};
+use crate::widgets::{
+    Button, ColoredBox, ConstrainedBox, DynamicButtonStyle, List, Pad, Scrollable, Text,
+};
+use crate::{colors, shapes};
+
+const PANEL_WIDTH: f32 = 320.0;
+const TREE_HEIGHT: f32 = 360.0;
+const FONT_SIZE: f32 = 12.0;
+const INDENT: f32 = 12.0;
+
+const LAYOUT_COLOR: Color = Color::rgba(86, 156, 214, 96);
+const CLIP_COLOR: Color = Color::rgb(220, 80, 220);
+const HOVER_COLOR: Color = Color::rgba(86, 156, 214, 64);
+const SELECTED_COLOR: Color = Color::rgb(255, 200, 0);
+
+/**
+An overlay for debugging layout.
+
+The inspector outlines the layout rect of every widget and the rect of every
+widget that clips its children. The widget under the cursor is highlighted,
+and a panel shows its type, ID, rect, incoming constraints, flex, and flow,
+along with a collapsible tree of the DOM. Clicking a widget in the tree selects
+it, showing its details in place of the hovered widget.
+
+The inspector creates its own layer and takes up no space, so it should be
+shown last, at the top level of the UI. Widgets that belong to the inspector
+are left out of its outlines and tree. The panel describes the state of the
+previous frame.
+
+Responds with [InspectorResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::Inspector;
+let inspecting = true;
+
+yakui::label("Hello!");
+Inspector::new().enabled(inspecting).show();
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Inspector {
+    /// Whether the inspector is drawn. The selection and expanded nodes of
+    /// the tree are kept while the inspector is disabled.
+    pub enabled: bool,
+}
+
+impl Inspector {
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    #[track_caller]
+    pub fn show(self) -> Response<InspectorResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<InspectorWidget>(self);
+
+        // The panel is built from a snapshot taken while painting the previous
+        // frame.
+        if let Some(state) = &response.panel {
+            show_panel(state);
+        }
+
+        dom.end_widget::<InspectorWidget>(response.id);
+        response
+    }
+}
+
+fn show_panel(state: &Rc<RefCell<InspectorState>>) {
+    ColoredBox::container(colors::BACKGROUND_1.with_alpha(0.9)).show_children(|| {
+        Pad::all(8.0).show(|| {
+            List::column().item_spacing(4.0).show(|| {
+                let details = state.borrow().details.clone();
+                let text = match details {
+                    Some(details) => details.describe(),
+                    None => "Hover or select a widget to inspect it.".to_owned(),
+                };
+                Text::new(FONT_SIZE, text).show();
+
+                let constraints = Constraints {
+                    min: Vec2::new(PANEL_WIDTH, 0.0),
+                    max: Vec2::new(PANEL_WIDTH, TREE_HEIGHT),
+                };
+                ConstrainedBox::new(constraints).show(|| {
+                    Scrollable::vertical().show(|| {
+                        List::column().show(|| show_tree(state));
+                    });
+                });
+            });
+        });
+    });
+}
+
+fn show_tree(state: &Rc<RefCell<InspectorState>>) {
+    let (rows, selected) = {
+        let state = state.borrow();
+        (state.tree.clone(), state.selected)
+    };
+
+    for row in rows {
+        let toggle = match (row.has_children, row.expanded) {
+            (false, _) => " ",
+            (true, true) => "-",
+            (true, false) => "+",
+        };
+
+        List::row().show(|| {
+            Pad {
+                left: row.depth as f32 * INDENT,
+                ..Pad::ZERO
+            }
+            .show(|| {
+                let toggled = Button::unstyled(toggle)
+                    .padding(Pad::horizontal(4.0))
+                    .style(row_style(false))
+                    .hover_style(row_style(true))
+                    .down_style(row_style(true))
+                    .show()
+                    .clicked;
+
+                if toggled && row.has_children {
+                    let mut state = state.borrow_mut();
+                    if !state.expanded.remove(&row.id) {
+                        state.expanded.insert(row.id);
+                    }
+                }
+            });
+
+            let is_selected = selected == Some(row.id);
+            let clicked = Button::unstyled(format!("{} {:?}", row.name, row.id))
+                .alignment(Alignment::CENTER_LEFT)
+                .padding(Pad::horizontal(4.0))
+                .style(row_style(is_selected))
+                .hover_style(row_style(true))
+                .down_style(row_style(true))
+                .show()
+                .clicked;
+
+            if clicked {
+                let mut state = state.borrow_mut();
+                state.selected = if is_selected { None } else { Some(row.id) };
+            }
+        });
+    }
+}
+
+fn row_style(highlighted: bool) -> DynamicButtonStyle {
+    let fill = if highlighted {
+        colors::BACKGROUND_3
+    } else {
+        Color::CLEAR
+    };
+
+    DynamicButtonStyle {
+        text: TextStyle::label()
+            .font_size(FONT_SIZE)
+            .align(TextAlignment::Start),
+        fill: fill.into(),
+        border: None,
+        shadow: None,
+    }
+}
+
+#[derive(Debug)]
+pub struct InspectorWidget {
+    props: Inspector,
+    state: Rc<RefCell<InspectorState>>,
+}
+
+/// The widgets that the inspector is pointing at.
+#[derive(Debug)]
+pub struct InspectorResponse {
+    /// The widget under the cursor during the previous frame.
+    pub hovered: Option<WidgetId>,
+
+    /// The widget selected in the tree.
+    pub selected: Option<WidgetId>,
+
+    panel: Option<Rc<RefCell<InspectorState>>>,
+}
+
+#[derive(Debug, Default)]
+struct InspectorState {
+    mouse: Option<Vec2>,
+    hovered: Option<WidgetId>,
+    selected: Option<WidgetId>,
+    expanded: HashSet<WidgetId>,
+    tree: Vec<TreeRow>,
+    details: Option<Details>,
+}
+
+#[derive(Debug, Clone)]
+struct TreeRow {
+    id: WidgetId,
+    name: &'static str,
+    depth: usize,
+    has_children: bool,
+    expanded: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Details {
+    id: WidgetId,
+    name: &'static str,
+    rect: Rect,
+    constraints: Constraints,
+    flex: (u32, FlexFit),
+    flow: Flow,
+    clip: Option<Rect>,
+    new_layer: bool,
+}
+
+impl Details {
+    fn describe(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "{} {:?}", self.name, self.id);
+        let _ = writeln!(
+            text,
+            "rect: {} @ {}",
+            fmt_vec(self.rect.size()),
+            fmt_vec(self.rect.pos())
+        );
+        let _ = writeln!(
+            text,
+            "constraints: {} to {}",
+            fmt_vec(self.constraints.min),
+            fmt_vec(self.constraints.max)
+        );
+        let _ = writeln!(text, "flex: {} ({:?})", self.flex.0, self.flex.1);
+        let _ = writeln!(text, "flow: {:?}", self.flow);
+
+        match self.clip {
+            Some(clip) => {
+                let _ = write!(
+                    text,
+                    "clipped to: {} @ {}",
+                    fmt_vec(clip.size()),
+                    fmt_vec(clip.pos())
+                );
+            }
+            None => text.push_str("not clipped"),
+        }
+
+        if self.new_layer {
+            text.push_str("\nstarts a new layer");
+        }
+
+        text
+    }
+}
+
+fn fmt_vec(v: Vec2) -> String {
+    let fmt = |x: f32| {
+        if x.is_finite() {
+            format!("{x:.1}")
+        } else {
+            "inf".to_owned()
+        }
+    };
+
+    format!("({}, {})", fmt(v.x), fmt(v.y))
+}
+
+/// Strips the module path from a widget's type name.
+fn short_name(name: &'static str) -> &'static str {
+    let base = name.split('<').next().unwrap_or(name);
+    let start = base.rfind("::").map(|i| i + 2).unwrap_or(0);
+    &name[start..]
+}
+
+impl Widget for InspectorWidget {
+    type Props<'a> = Inspector;
+    type Response = InspectorResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Inspector::new(),
+            state: Rc::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        let state = self.state.borrow();
+        InspectorResponse {
+            hovered: state.hovered,
+            selected: state.selected,
+            panel: self.props.enabled.then(|| self.state.clone()),
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.new_layer(ctx.dom);
+        self.state.borrow_mut().mouse = ctx.input.mouse_pos(ctx.layout);
+
+        let viewport = ctx.layout.viewport().size();
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            let size = ctx.calculate_layout(child, Constraints::loose(viewport));
+            let pos = Vec2::new((viewport.x - size.x - 8.0).max(0.0), 8.0);
+            ctx.layout.set_pos(child, pos);
+        }
+
+        constraints.constrain_min(Vec2::ZERO)
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        if !self.props.enabled {
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let own = ctx.dom.current();
+
+        let hovered = state
+            .mouse
+            .and_then(|pos| hit_test(ctx.dom, ctx.layout, own, pos));
+        state.hovered = hovered;
+
+        if let Some(selected) = state.selected {
+            if ctx.layout.get(selected).is_none() {
+                state.selected = None;
+            }
+        }
+
+        let target = state.selected.or(hovered);
+        state.details = target.and_then(|id| details(ctx.dom, ctx.layout, id));
+
+        let mut tree = Vec::new();
+        collect_tree(ctx.dom, own, &state.expanded, ctx.dom.root(), 0, &mut tree);
+        state.tree = tree;
+
+        visit(ctx.dom, own, ctx.dom.root(), &mut |id| {
+            let Some(node) = ctx.layout.get(id) else {
+                return;
+            };
+
+            shapes::outline(ctx.paint, node.rect, 1.0, LAYOUT_COLOR);
+            if node.clipping_enabled {
+                shapes::outline(ctx.paint, node.rect, 1.0, CLIP_COLOR);
+            }
+        });
+
+        if let Some(node) = hovered.and_then(|id| ctx.layout.get(id)) {
+            let mut highlight = PaintRect::new(node.rect);
+            highlight.brush = HOVER_COLOR.into();
+            highlight.add(ctx.paint);
+        }
+
+        if let Some(node) = state.selected.and_then(|id| ctx.layout.get(id)) {
+            shapes::outline(ctx.paint, node.rect, 2.0, SELECTED_COLOR);
+        }
+
+        drop(state);
+        self.default_paint(ctx);
+    }
+}
+
+/// Calls `visit` with every widget in the tree below `id` in paint order,
+/// skipping the inspector itself.
+fn visit(dom: &Dom, inspector: WidgetId, id: WidgetId, visit_node: &mut impl FnMut(WidgetId)) {
+    if id == inspector {
+        return;
+    }
+
+    visit_node(id);
+
+    let children = dom.get(id).map(|node| node.children.clone());
+    for child in children.into_iter().flatten() {
+        visit(dom, inspector, child, visit_node);
+    }
+}
+
+/// Finds the deepest widget under the given point, preferring widgets painted
+/// later.
+fn hit_test(dom: &Dom, layout: &LayoutDom, inspector: WidgetId, pos: Vec2) -> Option<WidgetId> {
+    let mut hit = None;
+
+    visit(dom, inspector, dom.root(), &mut |id| {
+        let Some(node) = layout.get(id) else {
+            return;
+        };
+
+        let clipped = node
+            .clipped_by
+            .and_then(|clip| layout.get(clip))
+            .is_some_and(|clip| !clip.rect.contains_point(pos));
+
+        if id != dom.root() && node.rect.contains_point(pos) && !clipped {
+            hit = Some(id);
+        }
+    });
+
+    hit
+}
+
+fn details(dom: &Dom, layout: &LayoutDom, id: WidgetId) -> Option<Details> {
+    let node = dom.get(id)?;
+    let layout_node = layout.get(id)?;
+
+    Some(Details {
+        id,
+        name: short_name(node.widget.type_name()),
+        rect: layout_node.rect,
+        constraints: layout_node.constraints,
+        flex: node.widget.flex(),
+        flow: node.widget.flow(),
+        clip: layout_node
+            .clipped_by
+            .and_then(|clip| layout.get(clip))
+            .map(|clip| clip.rect),
+        new_layer: layout_node.new_layer,
+    })
+}
+
+fn collect_tree(
+    dom: &Dom,
+    inspector: WidgetId,
+    expanded: &HashSet<WidgetId>,
+    id: WidgetId,
+    depth: usize,
+    output: &mut Vec<TreeRow>,
+) {
+    if id == inspector {
+        return;
+    }
+
+    let Some(node) = dom.get(id) else {
+        return;
+    };
+
+    let children: Vec<_> = node
+        .children
+        .iter()
+        .copied()
+        .filter(|&child| child != inspector)
+        .collect();
+    let is_expanded = depth == 0 || expanded.contains(&id);
+
+    output.push(TreeRow {
+        id,
+        name: short_name(node.widget.type_name()),
+        depth,
+        has_children: !children.is_empty(),
+        expanded: is_expanded,
+    });
+    drop(node);
+
+    if is_expanded {
+        for child in children {
+            collect_tree(dom, inspector, expanded, child, depth + 1, output);
+        }
+    }
+}
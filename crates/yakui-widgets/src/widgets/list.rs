@@ -1,6 +1,9 @@
 use yakui_core::geometry::{Constraints, FlexFit, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::{CrossAxisAlignment, Direction, Flow, MainAxisAlignment, MainAxisSize, Response};
+use yakui_core::layout::LayoutDomNode;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{
+    CrossAxisAlignment, Direction, Flow, MainAxisAlignment, MainAxisSize, Response, WidgetId,
+};
 
 use crate::auto_builders;
 use crate::util::widget_children;
@@ -190,6 +193,28 @@ impl Widget for ListWidget {
             max_cross_axis_size = f32::max(max_cross_axis_size, direction.get_cross_axis(size));
         }
 
+        // Children lined up by their baselines need room for the tallest part
+        // above the shared baseline and the tallest part below it.
+        let align_baselines = self.props.cross_axis_alignment == CrossAxisAlignment::Baseline
+            && direction == Direction::Right;
+        let mut max_baseline = 0.0;
+        if align_baselines {
+            let mut max_descent = 0.0;
+            for &child_id in &node.children {
+                let child = ctx.dom.get(child_id).unwrap();
+                if child.widget.flow() != Flow::Inline {
+                    continue;
+                }
+
+                let child_layout = ctx.layout.get(child_id).unwrap();
+                let baseline = baseline_or_bottom(child_layout);
+                max_baseline = f32::max(max_baseline, baseline);
+                max_descent = f32::max(max_descent, child_layout.rect.size().y - baseline);
+            }
+
+            max_cross_axis_size = f32::max(max_cross_axis_size, max_baseline + max_descent);
+        }
+
         let cross_size = max_cross_axis_size.max(direction.get_cross_axis(input.min));
 
         let main_axis_size = match self.props.main_axis_size {
@@ -276,7 +301,12 @@ impl Widget for ListWidget {
             let child_cross = direction.get_cross_axis(child_size);
 
             let cross = match self.props.cross_axis_alignment {
-                CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
+                CrossAxisAlignment::Baseline if align_baselines => {
+                    max_baseline - baseline_or_bottom(child_layout)
+                }
+                CrossAxisAlignment::Start
+                | CrossAxisAlignment::Stretch
+                | CrossAxisAlignment::Baseline => 0.0,
                 CrossAxisAlignment::Center => (cross_size - child_cross) / 2.0,
                 CrossAxisAlignment::End => cross_size - child_cross,
             };
@@ -288,4 +318,161 @@ impl Widget for ListWidget {
 
         container_size
     }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        match self.props.direction {
+            Direction::Right => {
+                self.intrinsic_main_size(ctx, height, IntrinsicContext::min_intrinsic_width)
+            }
+            Direction::Down => self.intrinsic_cross_size(
+                ctx,
+                height,
+                IntrinsicContext::max_intrinsic_height,
+                IntrinsicContext::min_intrinsic_width,
+            ),
+        }
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        match self.props.direction {
+            Direction::Right => {
+                self.intrinsic_main_size(ctx, height, IntrinsicContext::max_intrinsic_width)
+            }
+            Direction::Down => self.intrinsic_cross_size(
+                ctx,
+                height,
+                IntrinsicContext::max_intrinsic_height,
+                IntrinsicContext::max_intrinsic_width,
+            ),
+        }
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        match self.props.direction {
+            Direction::Down => {
+                self.intrinsic_main_size(ctx, width, IntrinsicContext::min_intrinsic_height)
+            }
+            Direction::Right => self.intrinsic_cross_size(
+                ctx,
+                width,
+                IntrinsicContext::max_intrinsic_width,
+                IntrinsicContext::min_intrinsic_height,
+            ),
+        }
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        match self.props.direction {
+            Direction::Down => {
+                self.intrinsic_main_size(ctx, width, IntrinsicContext::max_intrinsic_height)
+            }
+            Direction::Right => self.intrinsic_cross_size(
+                ctx,
+                width,
+                IntrinsicContext::max_intrinsic_width,
+                IntrinsicContext::max_intrinsic_height,
+            ),
+        }
+    }
+}
+
+/// Returns the baseline of a laid out child, treating its bottom edge as its
+/// baseline if it doesn't have one.
+fn baseline_or_bottom(layout: &LayoutDomNode) -> f32 {
+    layout.baseline.unwrap_or(layout.rect.size().y)
+}
+
+/// One of the intrinsic size queries on [`IntrinsicContext`], asked of a child
+/// given its size along the other axis.
+type IntrinsicQuery<'dom> = fn(&IntrinsicContext<'dom>, WidgetId, f32) -> f32;
+
+impl ListWidget {
+    /// Answers an intrinsic size query along the main axis. Children are
+    /// placed one after another, and flexible children need enough room that
+    /// the one wanting the most space per unit of flex gets it.
+    fn intrinsic_main_size<'dom>(
+        &self,
+        ctx: IntrinsicContext<'dom>,
+        cross: f32,
+        query: IntrinsicQuery<'dom>,
+    ) -> f32 {
+        let node = ctx.dom.get_current();
+
+        let mut inflexible = self.props.item_spacing * node.children.len().saturating_sub(1) as f32;
+        let mut total_flex = 0;
+        let mut max_flex_fraction = 0.0;
+
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if child.widget.flow() != Flow::Inline {
+                continue;
+            }
+
+            let (flex, _fit) = child.widget.flex();
+            let size = query(&ctx, child_id, cross);
+
+            if flex == 0 {
+                inflexible += size;
+            } else {
+                total_flex += flex;
+                max_flex_fraction = f32::max(max_flex_fraction, size / flex as f32);
+            }
+        }
+
+        inflexible + max_flex_fraction * total_flex as f32
+    }
+
+    /// Answers an intrinsic size query along the cross axis by splitting
+    /// `main` between children the same way that layout does.
+    fn intrinsic_cross_size<'dom>(
+        &self,
+        ctx: IntrinsicContext<'dom>,
+        main: f32,
+        main_query: IntrinsicQuery<'dom>,
+        cross_query: IntrinsicQuery<'dom>,
+    ) -> f32 {
+        let node = ctx.dom.get_current();
+
+        let mut inflexible_main =
+            self.props.item_spacing * node.children.len().saturating_sub(1) as f32;
+        let mut total_flex = 0;
+        let mut cross = 0.0;
+
+        // Like in layout, children that don't flex get as much of the main
+        // axis as they want.
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if child.widget.flow() != Flow::Inline {
+                continue;
+            }
+
+            let (flex, _fit) = child.widget.flex();
+            if flex != 0 {
+                total_flex += flex;
+                continue;
+            }
+
+            let child_main = main_query(&ctx, child_id, f32::INFINITY);
+            inflexible_main += child_main;
+            cross = f32::max(cross, cross_query(&ctx, child_id, child_main));
+        }
+
+        if total_flex == 0 {
+            return cross;
+        }
+
+        let remaining_main = (main - inflexible_main).max(0.0);
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            let (flex, _fit) = child.widget.flex();
+            if flex == 0 || child.widget.flow() != Flow::Inline {
+                continue;
+            }
+
+            let child_main = flex as f32 * remaining_main / total_flex as f32;
+            cross = f32::max(cross, cross_query(&ctx, child_id, child_main));
+        }
+
+        cross
+    }
 }
@@ -1,5 +1,5 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
 use yakui_core::Response;
 
 use crate::util::widget_children;
@@ -61,4 +61,22 @@ impl Widget for MaxWidthWidget {
 
         size
     }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+            .min(self.props.max_width)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+            .min(self.props.max_width)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width.min(self.props.max_width))
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width.min(self.props.max_width))
+    }
 }
@@ -1,3 +1,4 @@
+mod action;
 mod align;
 mod button;
 mod canvas;
@@ -8,14 +9,19 @@ mod constrained_box;
 mod count_grid;
 mod cutout;
 mod divider;
+mod drag_and_drop;
 mod draggable;
 mod flexible;
+mod grid;
 mod image;
+mod inspector;
 mod layer;
 mod list;
 mod max_width;
+mod modal;
 mod nineslice;
 mod offset;
+mod opacity;
 mod opaque;
 mod pad;
 mod panel;
@@ -28,11 +34,14 @@ mod slider;
 mod spacer;
 mod stack;
 mod state;
+mod stats_overlay;
 mod text;
 mod textbox;
 mod unconstrained_box;
 mod window;
+mod wrap;
 
+pub use self::action::*;
 pub use self::align::*;
 pub use self::button::*;
 pub use self::canvas::*;
@@ -43,14 +52,19 @@ pub use self::constrained_box::*;
 pub use self::count_grid::*;
 pub use self::cutout::*;
 pub use self::divider::*;
+pub use self::drag_and_drop::*;
 pub use self::draggable::*;
 pub use self::flexible::*;
+pub use self::grid::*;
 pub use self::image::*;
+pub use self::inspector::*;
 pub use self::layer::*;
 pub use self::list::*;
 pub use self::max_width::*;
+pub use self::modal::*;
 pub use self::nineslice::*;
 pub use self::offset::*;
+pub use self::opacity::*;
 pub use self::opaque::*;
 pub use self::pad::*;
 pub use self::panel::*;
@@ -63,7 +77,9 @@ pub use self::slider::*;
 pub use self::spacer::*;
 pub use self::stack::*;
 pub use self::state::*;
+pub use self::stats_overlay::*;
 pub use self::text::*;
 pub use self::textbox::*;
 pub use self::unconstrained_box::*;
 pub use self::window::*;
+pub use self::wrap::*;
@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+use std::mem;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::input::{KeyCode, Shortcut, ShortcutScope};
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+use crate::widgets::{Action, Button, List, Pad, RoundRect, Text};
+use crate::{auto_builders, colors, pad};
+
+/**
+A dialog that blocks input to the rest of the UI while it's shown.
+
+The modal covers the whole viewport with a translucent backdrop and centers its
+children on top of it. While it's shown:
+- Widgets in layers below it don't receive mouse events.
+- Keyboard navigation can't leave the modal, and wraps around at its ends.
+- Shortcuts registered outside of the modal are ignored.
+- Pressing Escape reports that the modal was dismissed.
+
+When the modal opens, the focused widget loses focus, and it gets focus back
+once the modal is no longer shown.
+
+The backdrop is positioned using where the modal was placed on the previous
+frame, so it's best shown near the root of the UI.
+
+Responds with [ModalResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Modal;
+
+let open = yakui::use_state(|| true);
+
+if open.get() {
+    let res = Modal::new().show(|| {
+        yakui::column(|| {
+            yakui::label("Are you sure?");
+            if yakui::button("Close").clicked {
+                open.set(false);
+            }
+        });
+    });
+
+    if res.dismissed {
+        open.set(false);
+    }
+}
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Modal {
+    pub backdrop: Color,
+    pub close_on_escape: bool,
+}
+
+auto_builders!(Modal {
+    backdrop: Color,
+    close_on_escape: bool,
+});
+
+impl Modal {
+    pub fn new() -> Self {
+        Self {
+            backdrop: Color::BLACK.with_alpha(0.6),
+            close_on_escape: true,
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<ModalResponse> {
+        let backdrop = self.backdrop;
+
+        widget_children::<ModalWidget, _>(
+            || {
+                widget_children::<ModalBackdropWidget, F>(children, backdrop);
+            },
+            self,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ModalWidget {
+    props: Modal,
+    dismissed: bool,
+}
+
+#[derive(Debug)]
+pub struct ModalResponse {
+    /// Whether the user asked to close the modal by pressing Escape since the
+    /// last update.
+    pub dismissed: bool,
+}
+
+impl Widget for ModalWidget {
+    type Props<'a> = Modal;
+    type Response = ModalResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Modal::new(),
+            dismissed: false,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        ModalResponse {
+            dismissed: mem::take(&mut self.dismissed),
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let id = ctx.dom.current();
+        ctx.layout.new_modal_layer(ctx.dom);
+        ctx.input.trap_focus(id);
+
+        if self.props.close_on_escape {
+            // The scoped shortcut takes priority over Escape shortcuts in
+            // enclosing scopes. The global one works when nothing is focused.
+            let escape = Shortcut::from(KeyCode::Escape);
+            ctx.input
+                .register_shortcut(id, escape.clone(), ShortcutScope::Focus(id));
+            ctx.input
+                .register_shortcut(id, escape, ShortcutScope::Global);
+        }
+
+        // Positions are relative to the parent, so covering the viewport
+        // means undoing wherever the modal ended up last frame.
+        let viewport = ctx.layout.viewport();
+        let origin = ctx
+            .layout
+            .get(id)
+            .map(|node| node.rect.pos())
+            .unwrap_or(Vec2::ZERO);
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::tight(viewport.size()));
+            ctx.layout.set_pos(child, viewport.pos() - origin);
+        }
+
+        constraints.constrain_min(Vec2::ZERO)
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::ShortcutTriggered(_) => {
+                self.dismissed = true;
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+/// Covers the viewport behind a [Modal]'s children, centering them and
+/// catching any clicks that miss them.
+#[derive(Debug)]
+pub struct ModalBackdropWidget {
+    color: Color,
+}
+
+impl Widget for ModalBackdropWidget {
+    type Props<'a> = Color;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            color: Color::CLEAR,
+        }
+    }
+
+    fn update(&mut self, color: Self::Props<'_>) -> Self::Response {
+        self.color = color;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let size = constraints.max;
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, Constraints::loose(size));
+            ctx.layout
+                .set_pos(child, ((size - child_size) / 2.0).round());
+        }
+
+        size
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        let mut rect = PaintRect::new(layout_node.rect);
+        rect.brush = self.color.into();
+        rect.add(ctx.paint);
+
+        self.default_paint(ctx);
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::MouseButtonChanged { .. } | WidgetEvent::MouseScroll { .. } => {
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+/// The button that a user chose in a [ConfirmDialog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogChoice {
+    Confirm,
+    Cancel,
+}
+
+/**
+A [Modal] asking the user to confirm or cancel something.
+
+Enter confirms and Escape cancels. Returns the user's choice on the frame after
+they made it, or `None` if they haven't chosen yet.
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{ConfirmDialog, DialogChoice};
+
+let choice = ConfirmDialog::new("Quit", "Unsaved changes will be lost.")
+    .confirm_label("Quit")
+    .show();
+
+if choice == Some(DialogChoice::Confirm) {
+    println!("Quitting...");
+}
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct ConfirmDialog {
+    pub title: Cow<'static, str>,
+    pub message: Cow<'static, str>,
+    pub confirm_label: Cow<'static, str>,
+    pub cancel_label: Cow<'static, str>,
+}
+
+auto_builders!(ConfirmDialog {
+    confirm_label: Cow<'static, str>,
+    cancel_label: Cow<'static, str>,
+});
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<Cow<'static, str>>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "OK".into(),
+            cancel_label: "Cancel".into(),
+        }
+    }
+
+    #[track_caller]
+    pub fn show(self) -> Option<DialogChoice> {
+        let mut choice = None;
+
+        let modal = Modal::new().show(|| {
+            let enter = Action::new()
+                .shortcut(KeyCode::Enter)
+                .shortcut(KeyCode::NumpadEnter)
+                .show();
+            if enter.triggered {
+                choice = Some(DialogChoice::Confirm);
+            }
+
+            RoundRect::new(6.0)
+                .color(colors::BACKGROUND_2)
+                .show_children(|| {
+                    pad(Pad::all(16.0), || {
+                        List::column().item_spacing(12.0).show(|| {
+                            Text::new(20.0, self.title).show();
+                            Text::label(self.message).show();
+
+                            List::row().item_spacing(8.0).show(|| {
+                                if Button::styled(self.cancel_label).show().clicked {
+                                    choice = Some(DialogChoice::Cancel);
+                                }
+
+                                if Button::styled(self.confirm_label).show().clicked {
+                                    choice = Some(DialogChoice::Confirm);
+                                }
+                            });
+                        });
+                    });
+                });
+        });
+
+        if modal.dismissed {
+            choice = Some(DialogChoice::Cancel);
+        }
+
+        choice
+    }
+}
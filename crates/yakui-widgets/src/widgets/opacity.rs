@@ -0,0 +1,87 @@
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Fades its children by the given opacity, between `0.0` and `1.0`.
+
+By default, every piece of geometry is faded on its own, so overlapping
+children can be seen through each other. Setting `group` renders the children
+offscreen first so that they fade as a single image.
+
+Responds with [OpacityResponse].
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Opacity {
+    pub opacity: f32,
+    pub group: bool,
+}
+
+auto_builders!(Opacity {
+    opacity: f32,
+    group: bool,
+});
+
+impl Opacity {
+    pub fn new(opacity: f32) -> Self {
+        Self {
+            opacity,
+            group: false,
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<OpacityResponse> {
+        widget_children::<OpacityWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct OpacityWidget {
+    props: Opacity,
+}
+
+pub type OpacityResponse = ();
+
+impl Widget for OpacityWidget {
+    type Props<'a> = Opacity;
+    type Response = OpacityResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Opacity::new(1.0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let opacity = self.props.opacity.clamp(0.0, 1.0);
+
+        if opacity == 0.0 {
+            return;
+        }
+
+        if self.props.group {
+            ctx.paint.push_group(opacity);
+        } else {
+            ctx.paint.push_opacity(opacity);
+        }
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        if self.props.group {
+            ctx.paint.pop_group();
+        } else {
+            ctx.paint.pop_opacity();
+        }
+    }
+}
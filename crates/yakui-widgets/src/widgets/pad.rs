@@ -1,5 +1,5 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
 use yakui_core::Response;
 
 use crate::auto_builders;
@@ -122,4 +122,34 @@ impl Widget for PadWidget {
         self_size = self_size.max(total_padding);
         input.constrain_min(self_size)
     }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let height = (height - self.vertical()).max(0.0);
+        self.horizontal() + self.default_min_intrinsic_width(ctx, height)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        let height = (height - self.vertical()).max(0.0);
+        self.horizontal() + self.default_max_intrinsic_width(ctx, height)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let width = (width - self.horizontal()).max(0.0);
+        self.vertical() + self.default_min_intrinsic_height(ctx, width)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let width = (width - self.horizontal()).max(0.0);
+        self.vertical() + self.default_max_intrinsic_height(ctx, width)
+    }
+}
+
+impl PadWidget {
+    fn horizontal(&self) -> f32 {
+        self.props.left + self.props.right
+    }
+
+    fn vertical(&self) -> f32 {
+        self.props.top + self.props.bottom
+    }
 }
@@ -106,7 +106,7 @@ impl Widget for PanelWidget {
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
         let mut rect = PaintRect::new(layout_node.rect);
-        rect.color = colors::BACKGROUND_2;
+        rect.brush = colors::BACKGROUND_2.into();
         rect.add(ctx.paint);
 
         let node = ctx.dom.get_current();
@@ -2,7 +2,7 @@ use std::cell::{Cell, RefCell};
 
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::{PaintRect, Pipeline};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{BaselineContext, IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::{Response, TextureId};
 
 use crate::font::Fonts;
@@ -62,6 +62,7 @@ pub struct RenderTextWidget {
     buffer: RefCell<Option<cosmic_text::Buffer>>,
     line_offsets: RefCell<Vec<f32>>,
     size: Cell<Option<Vec2>>,
+    baseline: Cell<Option<f32>>,
     max_size: Cell<Option<(Option<f32>, Option<f32>)>>,
     scale_factor: Cell<Option<f32>>,
     last_scroll: Cell<Option<cosmic_text::Scroll>>,
@@ -79,6 +80,7 @@ impl Widget for RenderTextWidget {
             buffer: RefCell::default(),
             line_offsets: RefCell::default(),
             size: Cell::default(),
+            baseline: Cell::default(),
             max_size: Cell::default(),
             scale_factor: Cell::default(),
             last_scroll: Cell::default(),
@@ -196,6 +198,10 @@ impl Widget for RenderTextWidget {
                 )
             };
 
+            let baseline = buffer.layout_runs().next().map(|run| run.line_y);
+            self.baseline
+                .set(baseline.map(|line_y| line_y / ctx.layout.scale_factor()));
+
             let size = constraints.constrain(size);
             self.size.set(Some(size));
 
@@ -203,6 +209,29 @@ impl Widget for RenderTextWidget {
         })
     }
 
+    fn baseline(&self, _ctx: BaselineContext<'_>) -> Option<f32> {
+        self.baseline.get()
+    }
+
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        // Wrapping at every word without breaking any of them up leaves the
+        // widest word as the widest line.
+        self.measure(ctx, Some(0.0), cosmic_text::Wrap::Word).x
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        self.measure(ctx, None, cosmic_text::Wrap::None).x
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        let width = width.is_finite().then_some(width);
+        self.measure(ctx, width, cosmic_text::Wrap::WordOrGlyph).y
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.min_intrinsic_height(ctx, width)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let fonts = ctx.dom.get_global_or_init(Fonts::default);
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
@@ -234,6 +263,54 @@ impl Widget for RenderTextWidget {
     }
 }
 
+impl RenderTextWidget {
+    /// Lays out the text in a scratch buffer to find out how big it would be
+    /// if it wrapped at the given width, without touching the buffer used for
+    /// layout and painting.
+    fn measure(
+        &self,
+        ctx: IntrinsicContext<'_>,
+        max_width: Option<f32>,
+        wrap: cosmic_text::Wrap,
+    ) -> Vec2 {
+        let scale_factor = ctx.layout.scale_factor();
+        let fonts = ctx.dom.get_global_or_init(Fonts::default);
+
+        fonts.with_system(|font_system| {
+            let mut buffer =
+                cosmic_text::Buffer::new(font_system, self.props.style.to_metrics(scale_factor));
+            buffer.set_wrap(font_system, wrap);
+            buffer.set_size(
+                font_system,
+                max_width.map(|width| width * scale_factor),
+                None,
+            );
+            buffer.set_text(
+                font_system,
+                &self.props.text,
+                &self.props.style.attrs.as_attrs(),
+                cosmic_text::Shaping::Advanced,
+                None,
+            );
+            buffer.shape_until_scroll(font_system, true);
+
+            let widest_line = buffer
+                .layout_runs()
+                .map(|layout| layout.line_w)
+                .max_by(|a, b| a.total_cmp(b))
+                .unwrap_or_default();
+
+            let size_y = buffer
+                .layout_runs()
+                .map(|layout| layout.line_height)
+                .sum::<f32>()
+                .ceil();
+
+            Vec2::new(widest_line / scale_factor, (size_y / scale_factor).ceil())
+        })
+    }
+}
+
 fn paint_text(
     ctx: &mut PaintContext<'_>,
     color: Color,
@@ -256,9 +333,9 @@ fn paint_text(
     ));
 
     if render.kind == Kind::Mask {
-        rect.color = color;
+        rect.brush = color.into();
     } else {
-        rect.color = Color::CLEAR;
+        rect.brush = Color::CLEAR.into();
     }
     rect.texture = Some((TextureId::Managed(render.texture), render.tex_rect));
     rect.pipeline = Pipeline::Text;
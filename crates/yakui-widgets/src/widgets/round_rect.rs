@@ -1,8 +1,9 @@
-use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::geometry::{Brush, Constraints, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::border::{Border, BorderRadius};
+use crate::shadow::Shadow;
 use crate::util::{widget, widget_children};
 use crate::{auto_builders, shapes};
 
@@ -14,26 +15,29 @@ Responds with [RoundRectResponse].
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct RoundRect {
-    pub color: Color,
+    pub color: Brush,
     pub min_size: Vec2,
     pub border: Option<Border>,
     pub radius: BorderRadius,
+    pub shadow: Option<Shadow>,
 }
 
 auto_builders!(RoundRect {
-    color: Color,
+    color: Brush,
     min_size: Vec2,
     border: Option<Border>,
     radius: BorderRadius,
+    shadow: Option<Shadow>,
 });
 
 impl RoundRect {
     pub fn new<T: Into<BorderRadius>>(radius: T) -> Self {
         Self {
-            color: Color::WHITE,
+            color: Brush::default(),
             min_size: Vec2::ZERO,
             radius: radius.into(),
             border: None,
+            shadow: None,
         }
     }
 
@@ -81,13 +85,34 @@ impl Widget for RoundRectWidget {
         input.constrain_min(size)
     }
 
+    fn min_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_min_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn max_intrinsic_width(&self, ctx: IntrinsicContext<'_>, height: f32) -> f32 {
+        self.default_max_intrinsic_width(ctx, height)
+            .max(self.props.min_size.x)
+    }
+
+    fn min_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_min_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
+    fn max_intrinsic_height(&self, ctx: IntrinsicContext<'_>, width: f32) -> f32 {
+        self.default_max_intrinsic_height(ctx, width)
+            .max(self.props.min_size.y)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
         shapes::RoundedRectangle::new(layout_node.rect, self.props.radius)
-            .color(self.props.color)
+            .brush(self.props.color.clone())
             .border(self.props.border)
+            .shadow(self.props.shadow)
             .add(ctx.paint);
 
         for &child in &node.children {
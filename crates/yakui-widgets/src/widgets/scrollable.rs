@@ -2,25 +2,46 @@ use std::cell::Cell;
 
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::input::ScrollDelta;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
+use crate::auto_builders;
 use crate::util::widget_children;
 
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Scrollable {
     pub direction: Option<ScrollDirection>,
+
+    /// How far one line of scrolling moves the content, like one notch of a
+    /// mouse wheel. Trackpads that scroll by pixels aren't affected.
+    pub line_height: f32,
 }
 
+auto_builders!(Scrollable { line_height: f32 });
+
 impl Scrollable {
     pub fn none() -> Self {
-        Scrollable { direction: None }
+        Scrollable {
+            direction: None,
+            line_height: ScrollDelta::DEFAULT_LINE_HEIGHT,
+        }
     }
 
     pub fn vertical() -> Self {
         Scrollable {
             direction: Some(ScrollDirection::Y),
+            ..Self::none()
+        }
+    }
+
+    /// Scrolls horizontally. Vertical mouse wheels scroll horizontally too,
+    /// unless the wheel can scroll sideways on its own.
+    pub fn horizontal() -> Self {
+        Scrollable {
+            direction: Some(ScrollDirection::X),
+            ..Self::none()
         }
     }
 
@@ -32,6 +53,7 @@ impl Scrollable {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScrollDirection {
+    X,
     Y,
 }
 
@@ -68,6 +90,10 @@ impl Widget for ScrollableWidget {
 
         let child_constraints = match self.props.direction {
             None => constraints,
+            Some(ScrollDirection::X) => Constraints {
+                min: Vec2::new(0.0, constraints.min.y),
+                max: Vec2::new(f32::INFINITY, constraints.max.y),
+            },
             Some(ScrollDirection::Y) => Constraints {
                 min: Vec2::new(constraints.min.x, 0.0),
                 max: Vec2::new(constraints.max.x, f32::INFINITY),
@@ -91,6 +117,7 @@ impl Widget for ScrollableWidget {
 
         match self.props.direction {
             None => scroll_position = Vec2::ZERO,
+            Some(ScrollDirection::X) => scroll_position.y = 0.0,
             Some(ScrollDirection::Y) => scroll_position.x = 0.0,
         }
 
@@ -118,6 +145,11 @@ impl Widget for ScrollableWidget {
     fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match *event {
             WidgetEvent::MouseScroll { delta, .. } => {
+                let mut delta = delta.to_pixels(self.props.line_height);
+                if self.props.direction == Some(ScrollDirection::X) && delta.x == 0.0 {
+                    delta.x = delta.y;
+                }
+
                 let pos = self.scroll_position.get();
                 self.scroll_position.set(pos + delta);
                 EventResponse::Sink
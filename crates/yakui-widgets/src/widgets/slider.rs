@@ -1,7 +1,7 @@
 use std::cell::Cell;
 
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::{auto_builders, colored_circle, colors, draggable, util};
@@ -139,6 +139,22 @@ impl Widget for SliderWidget {
         size
     }
 
+    fn min_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        KNOB_SIZE
+    }
+
+    fn max_intrinsic_width(&self, _ctx: IntrinsicContext<'_>, _height: f32) -> f32 {
+        DEFAULT_WIDTH
+    }
+
+    fn min_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        TOTAL_HEIGHT
+    }
+
+    fn max_intrinsic_height(&self, _ctx: IntrinsicContext<'_>, _width: f32) -> f32 {
+        TOTAL_HEIGHT
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         // This is a little gross: stash our position from this frame's layout
         // pass so that we can compare it against any drag updates that happen
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use yakui_core::context;
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{FrameStats, Response};
+
+use crate::auto_builders;
+use crate::colors;
+use crate::util::widget;
+use crate::widgets::{ColoredBox, List, Pad, Text};
+
+const LAYOUT_COLOR: Color = Color::rgb(86, 156, 214);
+const PAINT_COLOR: Color = Color::rgb(230, 145, 56);
+const CALLS_COLOR: Color = Color::rgb(220, 220, 220);
+const BUDGET_COLOR: Color = Color::rgb(200, 60, 60);
+
+/// The frame time that the graph is scaled to unless a frame takes longer.
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+
+/**
+Shows statistics about recent frames, like the number of widgets, how long
+layout and paint took, and how much geometry was drawn.
+
+The graph stacks layout time on top of paint time for each frame, with a line
+marking a 60 FPS frame budget. The number of paint calls is drawn as a tick on
+each bar.
+
+Responds with [StatsOverlayResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::widgets::StatsOverlay::new().show();
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct StatsOverlay {
+    /// How many frames to keep in the graph.
+    pub history: usize,
+    pub graph_size: Vec2,
+}
+
+auto_builders!(StatsOverlay {
+    history: usize,
+    graph_size: Vec2,
+});
+
+impl StatsOverlay {
+    pub fn new() -> Self {
+        Self {
+            history: 120,
+            graph_size: Vec2::new(240.0, 60.0),
+        }
+    }
+
+    #[track_caller]
+    pub fn show(self) -> Response<StatsOverlayResponse> {
+        let mut response = None;
+
+        ColoredBox::container(colors::BACKGROUND_1).show_children(|| {
+            Pad::all(8.0).show(|| {
+                List::column().item_spacing(4.0).show(|| {
+                    let graph = widget::<StatsOverlayWidget>(self);
+                    let stats = *graph;
+
+                    let ms = |time: Duration| time.as_secs_f32() * 1000.0;
+                    let lines = [
+                        format!("{} widgets", stats.widgets),
+                        format!(
+                            "layout {:.2} ms, paint {:.2} ms",
+                            ms(stats.layout_time),
+                            ms(stats.paint_time)
+                        ),
+                        format!(
+                            "{} calls ({} before batching)",
+                            stats.paint_calls, stats.batching.calls_before
+                        ),
+                        format!("{} vertices, {} indices", stats.vertices, stats.indices),
+                        format!(
+                            "{} texture uploads ({:.1} KiB)",
+                            stats.texture_uploads,
+                            stats.texture_upload_bytes as f32 / 1024.0
+                        ),
+                    ];
+
+                    for line in lines {
+                        Text::new(12.0, line).show();
+                    }
+
+                    response = Some(graph);
+                });
+            });
+        });
+
+        response.unwrap()
+    }
+}
+
+#[derive(Debug)]
+pub struct StatsOverlayWidget {
+    props: StatsOverlay,
+    frames: VecDeque<FrameStats>,
+}
+
+/// The statistics of the most recently painted frame.
+pub type StatsOverlayResponse = FrameStats;
+
+impl Widget for StatsOverlayWidget {
+    type Props<'a> = StatsOverlay;
+    type Response = StatsOverlayResponse;
+
+    fn new() -> Self {
+        Self {
+            props: StatsOverlay::new(),
+            frames: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        let stats = context::dom().frame_stats();
+        self.frames.push_back(stats);
+        while self.frames.len() > self.props.history.max(1) {
+            self.frames.pop_front();
+        }
+
+        stats
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        input.constrain(self.props.graph_size)
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+        let rect = layout_node.rect;
+
+        let mut background = PaintRect::new(rect);
+        background.brush = colors::BACKGROUND_2.into();
+        background.add(ctx.paint);
+
+        let frame_time = |stats: &FrameStats| stats.layout_time + stats.paint_time;
+        let max_time = self
+            .frames
+            .iter()
+            .map(frame_time)
+            .fold(FRAME_BUDGET, Duration::max)
+            .as_secs_f32();
+        let max_calls = self
+            .frames
+            .iter()
+            .map(|stats| stats.paint_calls)
+            .max()
+            .unwrap_or(0)
+            .max(1) as f32;
+
+        let history = self.props.history.max(1);
+        let bar_width = rect.size().x / history as f32;
+        let height = rect.size().y;
+        let bottom = rect.max().y;
+
+        // Newest frames are drawn on the right edge of the graph.
+        let first = history - self.frames.len();
+        for (i, stats) in self.frames.iter().enumerate() {
+            let x = rect.pos().x + (first + i) as f32 * bar_width;
+
+            let paint = stats.paint_time.as_secs_f32() / max_time * height;
+            let layout = stats.layout_time.as_secs_f32() / max_time * height;
+
+            let mut bar = PaintRect::new(Rect::from_pos_size(
+                Vec2::new(x, bottom - paint),
+                Vec2::new(bar_width, paint),
+            ));
+            bar.brush = PAINT_COLOR.into();
+            bar.add(ctx.paint);
+
+            bar.rect = Rect::from_pos_size(
+                Vec2::new(x, bottom - paint - layout),
+                Vec2::new(bar_width, layout),
+            );
+            bar.brush = LAYOUT_COLOR.into();
+            bar.add(ctx.paint);
+
+            let calls = stats.paint_calls as f32 / max_calls * (height - 1.0);
+            bar.rect = Rect::from_pos_size(
+                Vec2::new(x, bottom - calls - 1.0),
+                Vec2::new(bar_width, 1.0),
+            );
+            bar.brush = CALLS_COLOR.into();
+            bar.add(ctx.paint);
+        }
+
+        let budget = FRAME_BUDGET.as_secs_f32() / max_time * height;
+        let mut line = PaintRect::new(Rect::from_pos_size(
+            Vec2::new(rect.pos().x, bottom - budget),
+            Vec2::new(rect.size().x, 1.0),
+        ));
+        line.brush = BUDGET_COLOR.into();
+        line.add(ctx.paint);
+    }
+}
@@ -4,7 +4,7 @@ use std::mem;
 use cosmic_text::{Edit, Selection};
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
-use yakui_core::input::{KeyCode, Modifiers, MouseButton};
+use yakui_core::input::{primary_modifier, KeyCode, Modifiers, MouseButton};
 use yakui_core::navigation::NavDirection;
 use yakui_core::paint::PaintRect;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
@@ -293,7 +293,7 @@ impl Widget for TextBoxWidget {
         fonts.with_system(|font_system| {
             if let Some(fill_color) = self.props.fill {
                 let mut bg = RoundedRectangle::new(layout_node.rect, self.props.radius);
-                bg.color = fill_color;
+                bg.brush = fill_color.into();
                 bg.add(ctx.paint);
             }
 
@@ -322,7 +322,7 @@ impl Widget for TextBoxWidget {
                                     + Vec2::new(x, y) * inv_scale_factor,
                                 Vec2::new(w, h) * inv_scale_factor,
                             ));
-                            bg.color = self.props.selected_bg_color;
+                            bg.brush = self.props.selected_bg_color.into();
                             bg.add(ctx.paint);
                         }
                     }
@@ -344,7 +344,7 @@ impl Widget for TextBoxWidget {
                                 + Vec2::new(x, y) * inv_scale_factor,
                             Vec2::new(1.5, h) * inv_scale_factor,
                         ));
-                        bg.color = self.props.cursor_color;
+                        bg.brush = self.props.cursor_color.into();
                         bg.add(ctx.paint);
                     }
                 });
@@ -359,10 +359,7 @@ impl Widget for TextBoxWidget {
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE
-            | EventInterest::FOCUS
-            | EventInterest::FOCUSED_KEYBOARD
-            | EventInterest::MOUSE_MOVE
+        EventInterest::MOUSE_INSIDE | EventInterest::FOCUS | EventInterest::FOCUSED_KEYBOARD
     }
 
     fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
@@ -419,6 +416,11 @@ impl Widget for TextBoxWidget {
                 ..
             } => {
                 if !inside {
+                    // With the pointer captured, a drag can end outside.
+                    if !*down {
+                        self.drag = DragState::None;
+                    }
+
                     return EventResponse::Sink;
                 }
 
@@ -432,6 +434,7 @@ impl Widget for TextBoxWidget {
                         if *down {
                             if self.drag == DragState::None {
                                 self.drag = DragState::DragStart;
+                                ctx.capture_pointer();
                             }
 
                             if let Some(editor) = self.cosmic_editor.get_mut() {
@@ -466,6 +469,11 @@ impl Widget for TextBoxWidget {
                 EventResponse::Sink
             }
 
+            WidgetEvent::PointerCaptureLost => {
+                self.drag = DragState::None;
+                EventResponse::Bubble
+            }
+
             WidgetEvent::KeyChanged {
                 key,
                 down,
@@ -699,10 +707,13 @@ impl Widget for TextBoxWidget {
                                         ctx.input.set_selection(None);
                                     }
                                 }
-                                res = EventResponse::Sink;
+
+                                // Let Escape through to shortcuts, like the
+                                // one that closes a modal.
+                                res = EventResponse::Bubble;
                             }
 
-                            KeyCode::KeyA if *down && main_modifier(modifiers) => {
+                            KeyCode::KeyA if *down && modifiers.contains(primary_modifier()) => {
                                 editor.set_selection(cosmic_text::Selection::Line(editor.cursor()));
 
                                 if let Some((_start, end)) = editor.selection_bounds() {
@@ -712,7 +723,7 @@ impl Widget for TextBoxWidget {
                                 res = EventResponse::Sink;
                             }
 
-                            KeyCode::KeyX if *down && main_modifier(modifiers) => {
+                            KeyCode::KeyX if *down && modifiers.contains(primary_modifier()) => {
                                 let clipboard =
                                     ctx.dom.get_global_or_init(ClipboardHolder::default);
 
@@ -725,7 +736,7 @@ impl Widget for TextBoxWidget {
                                 res = EventResponse::Sink;
                             }
 
-                            KeyCode::KeyC if *down && main_modifier(modifiers) => {
+                            KeyCode::KeyC if *down && modifiers.contains(primary_modifier()) => {
                                 let clipboard =
                                     ctx.dom.get_global_or_init(ClipboardHolder::default);
 
@@ -736,7 +747,7 @@ impl Widget for TextBoxWidget {
                                 res = EventResponse::Sink;
                             }
 
-                            KeyCode::KeyV if *down && main_modifier(modifiers) => {
+                            KeyCode::KeyV if *down && modifiers.contains(primary_modifier()) => {
                                 let clipboard =
                                     ctx.dom.get_global_or_init(ClipboardHolder::default);
 
@@ -748,6 +759,15 @@ impl Widget for TextBoxWidget {
                                 res = EventResponse::Sink;
                             }
 
+                            // Let other chords through so that shortcuts keep
+                            // working while typing.
+                            _ if modifiers.intersects(
+                                Modifiers::CONTROL | Modifiers::ALT | Modifiers::META,
+                            ) =>
+                            {
+                                res = EventResponse::Bubble
+                            }
+
                             _ => res = EventResponse::Sink,
                         }
 
@@ -794,13 +814,3 @@ impl Widget for TextBoxWidget {
         }
     }
 }
-
-/// Tells whether the set of modifiers contains the primary modifier, like ctrl
-/// on Windows or Linux or Command on macOS.
-fn main_modifier(modifiers: &Modifiers) -> bool {
-    if cfg!(target_os = "macos") {
-        modifiers.meta()
-    } else {
-        modifiers.ctrl()
-    }
-}
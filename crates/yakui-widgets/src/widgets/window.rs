@@ -4,9 +4,10 @@ use yakui_core::geometry::{Color, Constraints, Vec2};
 use yakui_core::widget::Widget;
 use yakui_core::Response;
 
-use crate::colors;
+use crate::shadow::Shadow;
 use crate::util::widget;
-use crate::widgets::Pad;
+use crate::widgets::{Pad, RoundRect};
+use crate::{auto_builders, colors};
 
 /**
 A floating window within the application.
@@ -16,13 +17,20 @@ Responds with [WindowResponse].
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Window {
     pub initial_size: Vec2,
+    pub shadow: Option<Shadow>,
     children: Option<Box<dyn Fn()>>,
 }
 
+auto_builders!(Window {
+    initial_size: Vec2,
+    shadow: Option<Shadow>,
+});
+
 impl Window {
     pub fn new<S: Into<Vec2>>(initial_size: S) -> Self {
         Self {
             initial_size: initial_size.into(),
+            shadow: None,
             children: None,
         }
     }
@@ -54,7 +62,7 @@ impl Widget for WindowWidget {
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        crate::colored_box_container(colors::BACKGROUND_2, || {
+        let contents = || {
             crate::column(|| {
                 // Window Title Bar
                 let constraints = Constraints::loose(self.props.initial_size);
@@ -79,7 +87,20 @@ impl Widget for WindowWidget {
                     }
                 });
             });
-        });
+        };
+
+        // Windows without a shadow keep their plain background box.
+        match self.props.shadow {
+            Some(shadow) => {
+                RoundRect::new(0.0)
+                    .color(colors::BACKGROUND_2)
+                    .shadow(Some(shadow))
+                    .show_children(contents);
+            }
+            None => {
+                crate::colored_box_container(colors::BACKGROUND_2, contents);
+            }
+        }
     }
 }
 
@@ -87,6 +108,7 @@ impl fmt::Debug for Window {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Window")
             .field("size", &self.initial_size)
+            .field("shadow", &self.shadow)
             .finish_non_exhaustive()
     }
 }
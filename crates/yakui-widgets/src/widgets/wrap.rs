@@ -0,0 +1,351 @@
+use yakui_core::geometry::{Constraints, FlexFit, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{
+    CrossAxisAlignment, Direction, Flow, MainAxisAlignment, MainAxisSize, Response, WidgetId,
+};
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Lays out children in a direction, starting a new run whenever the next child
+wouldn't fit. Useful for tags, chips, and toolbars that should wrap instead of
+overflowing.
+
+Each run works like a [List][super::List]: children are aligned along the main
+axis with `main_axis_alignment` and within the run's cross axis size with
+`cross_axis_alignment`. Flexible children share whatever space is left in their
+run. Runs are placed along the cross axis according to `run_alignment`.
+
+Responds with [WrapResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::wrap(|| {
+    for tag in ["rust", "gamedev", "ui", "immediate-mode"] {
+        yakui::button(tag);
+    }
+});
+```
+
+Extended:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Wrap;
+use yakui::{CrossAxisAlignment, MainAxisAlignment};
+
+Wrap::row()
+    .main_axis_spacing(4.0)
+    .cross_axis_spacing(8.0)
+    .main_axis_alignment(MainAxisAlignment::Center)
+    .cross_axis_alignment(CrossAxisAlignment::Center)
+    .show(|| {
+        for tag in ["rust", "gamedev", "ui", "immediate-mode"] {
+            yakui::button(tag);
+        }
+    });
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Wrap {
+    pub direction: Direction,
+    /// Space between adjacent children in the same run.
+    pub main_axis_spacing: f32,
+    /// Space between adjacent runs.
+    pub cross_axis_spacing: f32,
+    pub main_axis_size: MainAxisSize,
+    /// How children are placed along the main axis within each run.
+    pub main_axis_alignment: MainAxisAlignment,
+    /// How children are aligned within the cross axis size of their run.
+    pub cross_axis_alignment: CrossAxisAlignment,
+    /// How runs are placed along the cross axis when the container is bigger
+    /// than they need.
+    pub run_alignment: MainAxisAlignment,
+}
+
+auto_builders!(Wrap {
+    main_axis_spacing: f32,
+    cross_axis_spacing: f32,
+    main_axis_size: MainAxisSize,
+    main_axis_alignment: MainAxisAlignment,
+    cross_axis_alignment: CrossAxisAlignment,
+    run_alignment: MainAxisAlignment,
+});
+
+impl Wrap {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            main_axis_spacing: 0.0,
+            cross_axis_spacing: 0.0,
+            main_axis_size: MainAxisSize::Max,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+            run_alignment: MainAxisAlignment::Start,
+        }
+    }
+
+    /// Lays out children left to right, wrapping downwards.
+    pub fn row() -> Self {
+        Self::new(Direction::Right)
+    }
+
+    /// Lays out children top to bottom, wrapping to the right.
+    pub fn column() -> Self {
+        Self::new(Direction::Down)
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<WrapResponse> {
+        widget_children::<WrapWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct WrapWidget {
+    props: Wrap,
+}
+
+pub type WrapResponse = ();
+
+/// A line of children that fit along the main axis together.
+#[derive(Debug, Default)]
+struct Run {
+    children: Vec<WidgetId>,
+    main_size: f32,
+    cross_size: f32,
+}
+
+impl Widget for WrapWidget {
+    type Props<'a> = Wrap;
+    type Response = WrapResponse;
+
+    fn new() -> Self {
+        Self { props: Wrap::row() }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let main_spacing = self.props.main_axis_spacing;
+        let cross_spacing = self.props.cross_axis_spacing;
+
+        let main_axis_max = direction.get_main_axis(input.max);
+        let cross_axis_max = direction.get_cross_axis(input.max);
+
+        // Measure every child at its natural size, up to the size of the
+        // container, and break them into runs wherever the next child would
+        // overflow the main axis. A child that's too big on its own gets the
+        // chance to wrap internally, like long text.
+        let mut runs: Vec<Run> = Vec::new();
+        let mut current = Run::default();
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if child.widget.flow() != Flow::Inline {
+                continue;
+            }
+
+            let constraints = Constraints {
+                min: Vec2::ZERO,
+                max: direction.vec2(main_axis_max, cross_axis_max),
+            };
+            let size = ctx.calculate_layout(child_id, constraints);
+            let child_main = direction.get_main_axis(size);
+
+            if !current.children.is_empty()
+                && current.main_size + main_spacing + child_main > main_axis_max
+            {
+                runs.push(std::mem::take(&mut current));
+            }
+
+            if !current.children.is_empty() {
+                current.main_size += main_spacing;
+            }
+            current.children.push(child_id);
+            current.main_size += child_main;
+            current.cross_size = current.cross_size.max(direction.get_cross_axis(size));
+        }
+        if !current.children.is_empty() {
+            runs.push(current);
+        }
+
+        // Flexible children split the space left over in their run, like in a
+        // List. Without a bounded main axis, they keep their natural size.
+        if main_axis_max.is_finite() {
+            for run in &mut runs {
+                self.flex_run(&mut ctx, run, main_axis_max, cross_axis_max);
+            }
+        }
+
+        if self.props.cross_axis_alignment == CrossAxisAlignment::Stretch {
+            for run in &runs {
+                for &child_id in &run.children {
+                    let size = ctx.layout.get(child_id).unwrap().rect.size();
+                    let main = direction.get_main_axis(size);
+                    let constraints = Constraints::tight(direction.vec2(main, run.cross_size));
+                    ctx.calculate_layout(child_id, constraints);
+                }
+            }
+        }
+
+        let content_main = runs.iter().map(|run| run.main_size).fold(0.0, f32::max);
+        let content_cross = runs.iter().map(|run| run.cross_size).sum::<f32>()
+            + cross_spacing * runs.len().saturating_sub(1) as f32;
+
+        let main_axis_size = match self.props.main_axis_size {
+            MainAxisSize::Max if main_axis_max.is_finite() => content_main.max(main_axis_max),
+            _ => content_main,
+        };
+        let cross_axis_size = content_cross.max(direction.get_cross_axis(input.min));
+        let container_size = input.constrain(direction.vec2(main_axis_size, cross_axis_size));
+        let main_axis_size = direction.get_main_axis(container_size);
+        let cross_axis_size = direction.get_cross_axis(container_size);
+
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if let Flow::Relative { anchor, offset } = child.widget.flow() {
+                ctx.calculate_layout(child_id, Constraints::none());
+
+                let anchor = container_size * anchor.as_vec2();
+                let offset = offset.resolve(container_size);
+
+                let child_layout = ctx.layout.get_mut(child_id).unwrap();
+                child_layout.rect.set_pos(anchor + offset);
+            }
+        }
+
+        let (mut next_cross, run_between) = distribute(
+            self.props.run_alignment,
+            cross_axis_size - content_cross,
+            runs.len(),
+        );
+
+        for run in &runs {
+            let (mut next_main, between) = distribute(
+                self.props.main_axis_alignment,
+                main_axis_size - run.main_size,
+                run.children.len(),
+            );
+
+            for &child_id in &run.children {
+                let child_layout = ctx.layout.get_mut(child_id).unwrap();
+                let child_size = child_layout.rect.size();
+                let child_cross = direction.get_cross_axis(child_size);
+
+                let cross = match self.props.cross_axis_alignment {
+                    CrossAxisAlignment::Start
+                    | CrossAxisAlignment::Stretch
+                    | CrossAxisAlignment::Baseline => 0.0,
+                    CrossAxisAlignment::Center => (run.cross_size - child_cross) / 2.0,
+                    CrossAxisAlignment::End => run.cross_size - child_cross,
+                };
+                child_layout
+                    .rect
+                    .set_pos(direction.vec2(next_main, next_cross + cross));
+
+                next_main += direction.get_main_axis(child_size) + between + main_spacing;
+            }
+
+            next_cross += run.cross_size + run_between + cross_spacing;
+        }
+
+        container_size
+    }
+}
+
+impl WrapWidget {
+    /// Gives the flexible children of a run their share of its leftover main
+    /// axis space, then updates the run's size to match.
+    fn flex_run(
+        &self,
+        ctx: &mut LayoutContext<'_>,
+        run: &mut Run,
+        main_axis_max: f32,
+        cross_axis_max: f32,
+    ) {
+        let direction = self.props.direction;
+
+        let mut total_flex = 0;
+        let mut fixed_main =
+            self.props.main_axis_spacing * run.children.len().saturating_sub(1) as f32;
+        for &child_id in &run.children {
+            let (flex, _fit) = ctx.dom.get(child_id).unwrap().widget.flex();
+            total_flex += flex;
+
+            if flex == 0 {
+                let size = ctx.layout.get(child_id).unwrap().rect.size();
+                fixed_main += direction.get_main_axis(size);
+            }
+        }
+
+        if total_flex == 0 {
+            return;
+        }
+
+        let remaining = (main_axis_max - fixed_main).max(0.0);
+        run.main_size = fixed_main;
+        run.cross_size = 0.0;
+
+        for &child_id in &run.children {
+            let (flex, fit) = ctx.dom.get(child_id).unwrap().widget.flex();
+
+            let size = if flex == 0 {
+                ctx.layout.get(child_id).unwrap().rect.size()
+            } else {
+                let main = flex as f32 * remaining / total_flex as f32;
+                let min_main = match fit {
+                    FlexFit::Loose => 0.0,
+                    FlexFit::Tight => main,
+                };
+
+                let constraints = Constraints {
+                    min: direction.vec2(min_main, 0.0),
+                    max: direction.vec2(main, cross_axis_max),
+                };
+                let size = ctx.calculate_layout(child_id, constraints);
+                run.main_size += direction.get_main_axis(size);
+                size
+            };
+
+            run.cross_size = run.cross_size.max(direction.get_cross_axis(size));
+        }
+    }
+}
+
+/// Returns the space before the first item and between adjacent items needed
+/// to place `count` items with the given alignment, with `free` space left
+/// over.
+fn distribute(alignment: MainAxisAlignment, free: f32, count: usize) -> (f32, f32) {
+    let free = free.max(0.0);
+
+    match alignment {
+        MainAxisAlignment::Start => (0.0, 0.0),
+        MainAxisAlignment::Center => (free / 2.0, 0.0),
+        MainAxisAlignment::End => (free, 0.0),
+        MainAxisAlignment::SpaceAround => {
+            if count == 0 {
+                (0.0, 0.0)
+            } else {
+                let between = free / count as f32;
+                (between / 2.0, between)
+            }
+        }
+        MainAxisAlignment::SpaceBetween => {
+            if count <= 1 {
+                (0.0, 0.0)
+            } else {
+                (0.0, free / (count - 1) as f32)
+            }
+        }
+        MainAxisAlignment::SpaceEvenly => {
+            let between = free / (count + 1) as f32;
+            (between, between)
+        }
+    }
+}
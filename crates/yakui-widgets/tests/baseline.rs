@@ -0,0 +1,63 @@
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::{Alignment, CrossAxisAlignment, WidgetId, Yakui};
+use yakui_widgets::widgets::{List, Pad};
+use yakui_widgets::{align, colored_box, constrained, pad, text};
+
+fn absolute_baseline(yak: &Yakui, id: WidgetId) -> f32 {
+    let node = yak.layout_dom().get(id).unwrap();
+    node.rect.pos().y + node.baseline.unwrap()
+}
+
+#[test]
+fn row_lines_up_baselines() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(1000.0, 1000.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    yak.start();
+    let mut ids = Vec::new();
+    let mut square = None;
+    let row = List::row()
+        .cross_axis_alignment(CrossAxisAlignment::Baseline)
+        .show(|| {
+            ids.push(text(12.0, "small").id);
+            ids.push(text(32.0, "large").id);
+            ids.push(
+                pad(Pad::all(10.0), || {
+                    text(16.0, "padded");
+                })
+                .id,
+            );
+
+            let tall = Constraints::tight(Vec2::new(80.0, 100.0));
+            ids.push(
+                constrained(tall, || {
+                    align(Alignment::TOP_LEFT, || {
+                        text(16.0, "aligned");
+                    });
+                })
+                .id,
+            );
+
+            square = Some(colored_box(Color::RED, [20.0, 20.0]).id);
+        });
+    yak.finish();
+
+    let baseline = absolute_baseline(&yak, ids[0]);
+    for &id in &ids[1..] {
+        assert_eq!(absolute_baseline(&yak, id), baseline);
+    }
+
+    // Widgets without a baseline sit on it, and the row reports it too.
+    let square = yak.layout_dom().get(square.unwrap()).unwrap().rect;
+    assert_eq!(square.max().y, baseline);
+    assert_eq!(absolute_baseline(&yak, row.id), baseline);
+
+    // The largest text has the most room above its baseline, so it stays at
+    // the top of the row and pushes everything else down.
+    let small = yak.layout_dom().get(ids[0]).unwrap().rect;
+    let large = yak.layout_dom().get(ids[1]).unwrap().rect;
+    assert_eq!(large.pos().y, 0.0);
+    assert!(small.pos().y > 0.0);
+}
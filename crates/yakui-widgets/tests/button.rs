@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use yakui_core::event::Event;
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::Yakui;
+use yakui_widgets::widgets::{Button, ButtonResponse};
+
+#[test]
+fn slow_clicks_still_click() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(200.0, 100.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+    yak.set_time(Duration::ZERO);
+
+    let frame = |yak: &mut Yakui| -> ButtonResponse {
+        yak.start();
+        let res = Button::unstyled("hold me").show();
+        yak.finish();
+        res.into_inner()
+    };
+    let press = |yak: &mut Yakui, down| {
+        yak.handle_event(Event::MouseButtonChanged {
+            button: MouseButton::One,
+            down,
+        });
+    };
+
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(5.0, 5.0))));
+    press(&mut yak, true);
+
+    let long_press_time = yak.click_settings().long_press_time;
+    yak.set_time(long_press_time + Duration::from_millis(100));
+    let res = frame(&mut yak);
+    assert!(res.long_pressed);
+    assert!(!res.clicked);
+
+    press(&mut yak, false);
+    let res = frame(&mut yak);
+    assert!(res.clicked);
+    assert!(!res.long_pressed);
+}
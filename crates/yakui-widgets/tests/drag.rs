@@ -0,0 +1,272 @@
+use yakui_core::event::Event;
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::{WidgetId, Yakui};
+use yakui_widgets::widgets::{Button, ColoredBox, DragSource, DropHover, DropTarget, Layer};
+use yakui_widgets::{column, constrained, draggable, scroll_vertical, stack};
+
+#[test]
+fn drag_continues_outside_widget_and_window() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let mut dragging = None;
+        let mut hovering = false;
+        column(|| {
+            dragging = draggable(|| {
+                ColoredBox::sized(Color::RED, Vec2::splat(50.0)).show();
+            })
+            .dragging;
+            hovering = Button::unstyled("other").show().hovering;
+        });
+        yak.finish();
+
+        (dragging, hovering)
+    };
+
+    let button = |down| Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down,
+    };
+
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(button(true));
+    let (dragging, _) = frame(&mut yak);
+    assert_eq!(dragging.unwrap().start, Vec2::ZERO);
+
+    // Passing over another widget doesn't hover it or end the drag.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 60.0))));
+    let (dragging, hovering) = frame(&mut yak);
+    assert_eq!(dragging.unwrap().current, Vec2::new(0.0, 50.0));
+    assert!(!hovering);
+
+    // Leaving the window doesn't end the drag either.
+    yak.handle_event(Event::CursorMoved(None));
+    let (dragging, _) = frame(&mut yak);
+    assert!(dragging.is_some());
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(400.0, 400.0))));
+    yak.handle_event(button(false));
+    let (dragging, _) = frame(&mut yak);
+    assert!(dragging.is_none());
+}
+
+#[derive(Debug, Default)]
+struct SlotFrame {
+    dragging: bool,
+    hovering: [Option<DropHover>; 3],
+    can_accept: [bool; 3],
+    dropped_on: Option<WidgetId>,
+    source: Option<WidgetId>,
+    targets: Vec<WidgetId>,
+}
+
+#[test]
+fn drag_and_drop_moves_payload_between_slots() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let mut slots = [Some(Color::RED), None];
+    let mut rejected = Vec::new();
+
+    let mut frame = |yak: &mut Yakui, slots: &mut [Option<Color>; 2]| {
+        yak.start();
+        let mut result = SlotFrame::default();
+
+        column(|| {
+            for index in 0..slots.len() {
+                let response = DropTarget::<usize>::new().show(|| match slots[index] {
+                    Some(color) => {
+                        let source = DragSource::new(index).show(|| {
+                            ColoredBox::sized(color, Vec2::splat(50.0)).show();
+                        });
+                        result.source = Some(source.id);
+                        result.dragging |= source.dragging;
+                        result.dropped_on = result.dropped_on.or(source.dropped_on);
+                    }
+                    None => {
+                        ColoredBox::sized(Color::GRAY, Vec2::splat(50.0)).show();
+                    }
+                });
+
+                result.targets.push(response.id);
+                let response = response.into_inner();
+                result.hovering[index] = response.hovering;
+                result.can_accept[index] = response.can_accept;
+
+                if let Some(from) = response.dropped {
+                    slots.swap(from, index);
+                }
+            }
+
+            // Only accepts strings, so rejects every slot.
+            let response = DropTarget::<String>::new().show(|| {
+                ColoredBox::sized(Color::BLUE, Vec2::splat(50.0)).show();
+            });
+            result.targets.push(response.id);
+            let response = response.into_inner();
+            result.hovering[2] = response.hovering;
+            result.can_accept[2] = response.can_accept;
+            rejected.extend(response.dropped);
+        });
+
+        yak.finish();
+        yak.paint();
+        result
+    };
+
+    let button = |down| Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down,
+    };
+
+    frame(&mut yak, &mut slots);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(button(true));
+
+    // Small movements don't start a drag.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(11.0, 11.0))));
+    assert!(!frame(&mut yak, &mut slots).dragging);
+
+    // Hovering a target that doesn't accept the payload.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 110.0))));
+    let result = frame(&mut yak, &mut slots);
+    assert!(result.dragging);
+    assert_eq!(result.can_accept, [true, true, false]);
+    assert_eq!(result.hovering, [None, None, Some(DropHover::Reject)]);
+
+    // The preview follows the cursor in its own layer.
+    let preview = yak.dom().get(result.source.unwrap()).unwrap().children[1];
+    let preview = yak.layout_dom().get(preview).unwrap();
+    assert!(preview.new_layer);
+    assert_eq!(
+        preview.rect,
+        Rect::from_pos_size(Vec2::new(0.0, 100.0), Vec2::splat(50.0))
+    );
+
+    // Hovering a target that does.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 60.0))));
+    let result = frame(&mut yak, &mut slots);
+    assert_eq!(result.hovering, [None, Some(DropHover::Accept), None]);
+
+    yak.handle_event(button(false));
+    let result = frame(&mut yak, &mut slots);
+    assert!(!result.dragging);
+    assert_eq!(result.dropped_on, Some(result.targets[1]));
+    assert_eq!(slots, [None, Some(Color::RED)]);
+
+    let result = frame(&mut yak, &mut slots);
+    assert_eq!(result.hovering, [None, None, None]);
+
+    // Dropping onto a target that rejects the payload does nothing.
+    yak.handle_event(button(true));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 120.0))));
+    yak.handle_event(button(false));
+    frame(&mut yak, &mut slots);
+    frame(&mut yak, &mut slots);
+    assert_eq!(slots, [None, Some(Color::RED)]);
+    assert!(rejected.is_empty());
+}
+
+#[test]
+fn drag_preview_escapes_scrolling_clip() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let mut source = None;
+        constrained(Constraints::loose(Vec2::splat(100.0)), || {
+            scroll_vertical(|| {
+                let response = DragSource::new(()).show(|| {
+                    ColoredBox::sized(Color::RED, Vec2::splat(50.0)).show();
+                });
+                source = Some(response.id);
+            });
+        });
+        yak.finish();
+        yak.paint();
+        source.unwrap()
+    };
+
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(310.0, 310.0))));
+    let source = frame(&mut yak);
+
+    let preview = yak.dom().get(source).unwrap().children[1];
+    let preview = yak.layout_dom().get(preview).unwrap();
+    assert!(preview.escapes_clipping);
+    assert_eq!(preview.clipped_by, None);
+    assert_eq!(preview.rect.pos(), Vec2::splat(300.0));
+
+    // The source is still clipped by the scrolling container, but the preview
+    // isn't.
+    let layers = yak.paint().layers();
+    assert!(layers[0].calls.iter().all(|call| call.clip.is_some()));
+    let preview_calls = &layers.last().unwrap().calls;
+    assert!(!preview_calls.is_empty());
+    assert!(preview_calls.iter().all(|call| call.clip.is_none()));
+}
+
+#[test]
+fn drop_target_in_higher_layer_wins() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let mut hovering = [None; 2];
+        column(|| {
+            DragSource::new(()).show(|| {
+                ColoredBox::sized(Color::RED, Vec2::splat(50.0)).show();
+            });
+
+            // The target in the layer is updated first, but drawn on top.
+            stack(|| {
+                Layer::new().show(|| {
+                    hovering[0] = DropTarget::<()>::new()
+                        .show(|| {
+                            ColoredBox::sized(Color::BLUE, Vec2::splat(50.0)).show();
+                        })
+                        .hovering;
+                });
+                hovering[1] = DropTarget::<()>::new()
+                    .show(|| {
+                        ColoredBox::sized(Color::GRAY, Vec2::splat(50.0)).show();
+                    })
+                    .hovering;
+            });
+        });
+        yak.finish();
+        yak.paint();
+        hovering
+    };
+
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 60.0))));
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 70.0))));
+    let hovering = frame(&mut yak);
+    assert_eq!(hovering, [Some(DropHover::Accept), None]);
+}
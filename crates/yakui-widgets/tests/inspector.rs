@@ -0,0 +1,52 @@
+use yakui_core::event::Event;
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::Yakui;
+use yakui_widgets::widgets::{ColoredBox, Inspector, Pad};
+use yakui_widgets::{column, pad};
+
+#[test]
+fn inspector_finds_hovered_widget() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let mut boxes = Vec::new();
+        let list = column(|| {
+            pad(Pad::all(10.0), || {
+                boxes.push(ColoredBox::sized(Color::RED, Vec2::splat(50.0)).show().id);
+            });
+            boxes.push(ColoredBox::sized(Color::BLUE, Vec2::splat(50.0)).show().id);
+        })
+        .id;
+        boxes.push(list);
+        let inspector = Inspector::new().show();
+        yak.finish();
+        yak.paint();
+
+        (boxes, inspector.into_inner())
+    };
+
+    frame(&mut yak);
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(30.0, 30.0))));
+    frame(&mut yak);
+
+    let (boxes, inspector) = frame(&mut yak);
+    assert_eq!(inspector.hovered, Some(boxes[0]));
+    assert_eq!(inspector.selected, None);
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(30.0, 100.0))));
+    frame(&mut yak);
+
+    let (boxes, inspector) = frame(&mut yak);
+    assert_eq!(inspector.hovered, Some(boxes[1]));
+
+    // The inspector's own panel is skipped, finding the list under it.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(480.0, 20.0))));
+    frame(&mut yak);
+
+    let (boxes, inspector) = frame(&mut yak);
+    assert_eq!(inspector.hovered, Some(boxes[2]));
+}
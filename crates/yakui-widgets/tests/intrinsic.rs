@@ -0,0 +1,189 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{Response, Yakui};
+use yakui_widgets::util::widget_children;
+use yakui_widgets::widgets::{ConstrainedBox, Flexible, List, Pad, RenderText};
+use yakui_widgets::{checkbox, colored_box};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Intrinsics {
+    min_width: f32,
+    max_width: f32,
+    /// Both measured at the maximum intrinsic width.
+    min_height: f32,
+    max_height: f32,
+}
+
+/// Records the intrinsic sizes of its child, then lays it out at exactly its
+/// maximum intrinsic width.
+#[derive(Debug)]
+struct IntrinsicWidthWidget {
+    out: Rc<Cell<Intrinsics>>,
+}
+
+impl Widget for IntrinsicWidthWidget {
+    type Props<'a> = Rc<Cell<Intrinsics>>;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { out: Rc::default() }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.out = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let child = ctx.dom.get_current().children[0];
+
+        let intrinsic = ctx.intrinsic();
+        let max_width = intrinsic.max_intrinsic_width(child, f32::INFINITY);
+        self.out.set(Intrinsics {
+            min_width: intrinsic.min_intrinsic_width(child, f32::INFINITY),
+            max_width,
+            min_height: intrinsic.min_intrinsic_height(child, max_width),
+            max_height: intrinsic.max_intrinsic_height(child, max_width),
+        });
+
+        let constraints = Constraints {
+            min: Vec2::new(max_width, 0.0),
+            max: Vec2::new(max_width, input.max.y),
+        };
+        ctx.calculate_layout(child, constraints)
+    }
+}
+
+fn intrinsic_width(children: impl FnOnce()) -> (Response<()>, Rc<Cell<Intrinsics>>) {
+    let out = Rc::new(Cell::new(Intrinsics::default()));
+    let res = widget_children::<IntrinsicWidthWidget, _>(children, out.clone());
+    (res, out)
+}
+
+fn start(yak: &mut Yakui) {
+    let size = Vec2::new(1000.0, 1000.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+    yak.start();
+}
+
+fn sized(width: f32, height: f32) {
+    ConstrainedBox::new(Constraints::tight(Vec2::new(width, height))).show(|| {});
+}
+
+#[test]
+fn list_pad_and_constrained_box() {
+    let mut yak = Yakui::new();
+    start(&mut yak);
+
+    let (res, out) = intrinsic_width(|| {
+        List::row().item_spacing(4.0).show(|| {
+            sized(30.0, 10.0);
+            Pad::all(5.0).show(|| sized(20.0, 20.0));
+        });
+    });
+    yak.finish();
+
+    assert_eq!(
+        out.get(),
+        Intrinsics {
+            min_width: 64.0,
+            max_width: 64.0,
+            min_height: 30.0,
+            max_height: 30.0,
+        }
+    );
+    let rect = yak.layout_dom().get(res.id).unwrap().rect;
+    assert_eq!(rect.size(), Vec2::new(64.0, 30.0));
+}
+
+#[test]
+fn fixed_size_widgets() {
+    let mut yak = Yakui::new();
+    start(&mut yak);
+
+    let (_, out) = intrinsic_width(|| {
+        List::row().show(|| {
+            colored_box(Color::RED, [30.0, 40.0]);
+            checkbox(false);
+        });
+    });
+    yak.finish();
+
+    let out = out.get();
+    assert_eq!(out.max_width, 54.0);
+    assert_eq!(out.min_height, 40.0);
+}
+
+#[test]
+fn flexible_children_keep_their_share() {
+    let mut yak = Yakui::new();
+    start(&mut yak);
+
+    // The second child needs 20 per unit of flex while the first needs 40, so
+    // the row needs 40 * 3 to fit both.
+    let (_, row) = intrinsic_width(|| {
+        List::row().show(|| {
+            Flexible::new(1).show(|| sized(40.0, 10.0));
+            Flexible::new(2).show(|| sized(40.0, 10.0));
+        });
+    });
+
+    // In a column, flexible children are measured along the cross axis at
+    // their share of the main axis.
+    let (_, column) = intrinsic_width(|| {
+        List::column().show(|| {
+            sized(10.0, 10.0);
+            Flexible::new(1).show(|| sized(25.0, 10.0));
+        });
+    });
+    yak.finish();
+
+    assert_eq!(row.get().max_width, 120.0);
+    assert_eq!(column.get().max_width, 25.0);
+    assert_eq!(column.get().min_height, 20.0);
+}
+
+#[test]
+fn text_measures_words_and_lines() {
+    let mut yak = Yakui::new();
+    let text = "wrap wrap wrap";
+
+    start(&mut yak);
+    let (res, text_size) = intrinsic_width(|| {
+        RenderText::new(text).show();
+    });
+    yak.finish();
+    let text_size = text_size.get();
+
+    // At its maximum intrinsic width, text fits on a single line.
+    let rect = yak.layout_dom().get(res.id).unwrap().rect;
+    assert_eq!(rect.size().x, text_size.max_width);
+    assert_eq!(rect.size().y, text_size.min_height);
+    assert_eq!(text_size.min_height, text_size.max_height);
+    assert!(text_size.min_width < text_size.max_width);
+
+    // At its minimum intrinsic width, every word ends up on its own line.
+    start(&mut yak);
+    let (res, wrapped) = intrinsic_width(|| {
+        ConstrainedBox::new(Constraints::loose(Vec2::new(
+            text_size.min_width,
+            f32::INFINITY,
+        )))
+        .show(|| {
+            RenderText::new(text).show();
+        });
+    });
+    yak.finish();
+    let wrapped = wrapped.get();
+
+    let rect = yak.layout_dom().get(res.id).unwrap().rect;
+    assert_eq!(wrapped.max_width, text_size.min_width);
+    assert_eq!(wrapped.min_height, text_size.min_height * 3.0);
+    assert_eq!(
+        rect.size(),
+        Vec2::new(wrapped.max_width, wrapped.min_height)
+    );
+}
@@ -0,0 +1,126 @@
+use yakui_core::event::Event;
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::input::{KeyCode, Modifiers, MouseButton};
+use yakui_core::{WidgetId, Yakui};
+use yakui_widgets::widgets::{Button, Modal};
+use yakui_widgets::{action, column, textbox};
+
+#[derive(Debug, Default)]
+struct ModalFrame {
+    behind_clicked: bool,
+    saved: bool,
+    dismissed: bool,
+    /// The textbox behind the modal followed by the two inside of it, with
+    /// whether each one received text this frame.
+    textboxes: Vec<(WidgetId, bool)>,
+}
+
+fn frame(yak: &mut Yakui, open: bool) -> ModalFrame {
+    yak.start();
+    let mut res = ModalFrame::default();
+    column(|| {
+        res.behind_clicked = Button::unstyled("behind").show().clicked;
+        let behind = textbox("");
+        res.textboxes.push((behind.id, behind.text.is_some()));
+        res.saved = action("Ctrl+S").triggered;
+
+        if open {
+            res.dismissed = Modal::new()
+                .show(|| {
+                    column(|| {
+                        for _ in 0..2 {
+                            let inside = textbox("");
+                            res.textboxes.push((inside.id, inside.text.is_some()));
+                        }
+                    });
+                })
+                .dismissed;
+        }
+    });
+    yak.finish();
+    res
+}
+
+fn key(yak: &mut Yakui, modifiers: Modifiers, key: KeyCode) -> bool {
+    yak.handle_event(Event::ModifiersChanged(modifiers));
+    let sunk = yak.handle_event(Event::KeyChanged {
+        key,
+        down: true,
+        modifiers: None,
+    });
+    yak.handle_event(Event::KeyChanged {
+        key,
+        down: false,
+        modifiers: None,
+    });
+    yak.handle_event(Event::ModifiersChanged(Modifiers::empty()));
+    sunk
+}
+
+/// Types a character and returns which textbox received it.
+fn typed_into(yak: &mut Yakui, open: bool) -> Option<usize> {
+    yak.handle_event(Event::TextInput('x'));
+    let res = frame(yak, open);
+    res.textboxes.iter().position(|(_, typed)| *typed)
+}
+
+#[test]
+fn modal_blocks_input_and_traps_focus() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(400.0, 300.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let res = frame(&mut yak, false);
+    yak.handle_event(Event::RequestFocus(Some(res.textboxes[0].0)));
+    frame(&mut yak, false);
+    assert_eq!(typed_into(&mut yak, false), Some(0));
+
+    // Opening the modal takes focus away from the widgets behind it.
+    let res = frame(&mut yak, true);
+    frame(&mut yak, true);
+    assert!(!yak.text_input_enabled());
+
+    // Clicks and shortcuts don't reach the widgets behind the modal.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(5.0, 5.0))));
+    for down in [true, false] {
+        assert!(yak.handle_event(Event::MouseButtonChanged {
+            button: MouseButton::One,
+            down,
+        }));
+    }
+    key(&mut yak, Modifiers::CONTROL, KeyCode::KeyS);
+    let after = frame(&mut yak, true);
+    assert!(!after.behind_clicked);
+    assert!(!after.saved);
+
+    // Tabbing wraps around inside of the modal.
+    yak.handle_event(Event::RequestFocus(Some(res.textboxes[1].0)));
+    frame(&mut yak, true);
+    assert_eq!(typed_into(&mut yak, true), Some(1));
+
+    key(&mut yak, Modifiers::empty(), KeyCode::Tab);
+    frame(&mut yak, true);
+    assert_eq!(typed_into(&mut yak, true), Some(2));
+
+    key(&mut yak, Modifiers::empty(), KeyCode::Tab);
+    frame(&mut yak, true);
+    assert_eq!(typed_into(&mut yak, true), Some(1));
+
+    key(&mut yak, Modifiers::SHIFT, KeyCode::Tab);
+    frame(&mut yak, true);
+    assert_eq!(typed_into(&mut yak, true), Some(2));
+
+    // Escape dismisses the modal even from a focused textbox, and closing it
+    // gives focus back to the textbox behind it.
+    assert!(key(&mut yak, Modifiers::empty(), KeyCode::Escape));
+    assert!(frame(&mut yak, true).dismissed);
+    frame(&mut yak, false);
+    frame(&mut yak, false);
+    assert!(yak.text_input_enabled());
+    assert_eq!(typed_into(&mut yak, false), Some(0));
+
+    // Shortcuts work again.
+    key(&mut yak, Modifiers::CONTROL, KeyCode::KeyS);
+    assert!(frame(&mut yak, false).saved);
+}
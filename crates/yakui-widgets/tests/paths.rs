@@ -0,0 +1,122 @@
+use yakui_core::geometry::{Color, Vec2};
+use yakui_core::Yakui;
+use yakui_widgets::canvas;
+use yakui_widgets::path::{FillRule, Path, Stroke};
+
+/// Paints with the given function and returns the area covered by fully
+/// opaque triangles, in logical pixels.
+fn opaque_area(paint: impl Fn(&mut yakui_core::paint::PaintDom) + 'static) -> f32 {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    canvas(move |ctx| paint(ctx.paint));
+    yak.finish();
+
+    let mut area = 0.0;
+    for call in &yak.paint().layers()[0].calls {
+        for triangle in call.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| call.vertices[triangle[i] as usize]);
+
+            if [a, b, c].iter().all(|vertex| vertex.color.w == 1.0) {
+                let ab = (b.position - a.position) * 100.0;
+                let ac = (c.position - a.position) * 100.0;
+                area += (ab.x * ac.y - ab.y * ac.x).abs() / 2.0;
+            }
+        }
+    }
+
+    area
+}
+
+fn square(path: &mut Path, min: f32, max: f32) {
+    path.move_to(Vec2::new(min, min))
+        .line_to(Vec2::new(max, min))
+        .line_to(Vec2::new(max, max))
+        .line_to(Vec2::new(min, max))
+        .close();
+}
+
+#[test]
+fn fill_rules() {
+    let mut path = Path::new();
+    square(&mut path, 10.0, 90.0);
+    square(&mut path, 30.0, 70.0);
+
+    let non_zero = path.clone();
+    let area = opaque_area(move |paint| non_zero.fill(paint, FillRule::NonZero, Color::RED));
+    assert!((area - 80.0 * 80.0).abs() < 0.1, "{area}");
+
+    let even_odd = path.clone();
+    let area = opaque_area(move |paint| even_odd.fill(paint, FillRule::EvenOdd, Color::RED));
+    assert!((area - (80.0 * 80.0 - 40.0 * 40.0)).abs() < 0.1, "{area}");
+}
+
+#[test]
+fn fill_self_intersecting() {
+    // A bowtie crossing itself at (50, 50).
+    let path = Path::polygon([
+        Vec2::new(0.0, 0.0),
+        Vec2::new(100.0, 100.0),
+        Vec2::new(100.0, 0.0),
+        Vec2::new(0.0, 100.0),
+    ]);
+
+    let area = opaque_area(move |paint| path.fill(paint, FillRule::EvenOdd, Color::RED));
+    assert!((area - 5000.0).abs() < 0.1, "{area}");
+}
+
+/// Fills the path and returns the positions of the vertices that fade out
+/// its edges, in logical pixels.
+fn faded_points(path: Path, rule: FillRule) -> Vec<Vec2> {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    canvas(move |ctx| path.fill(ctx.paint, rule, Color::RED));
+    yak.finish();
+
+    yak.paint().layers()[0].calls[0]
+        .vertices
+        .iter()
+        .filter(|vertex| vertex.color.w == 0.0)
+        .map(|vertex| vertex.position * 100.0)
+        .collect()
+}
+
+#[test]
+fn fill_fades_only_the_outline() {
+    let mut path = Path::new();
+    square(&mut path, 10.0, 90.0);
+    square(&mut path, 30.0, 70.0);
+
+    let inside = |pos: Vec2, min: f32, max: f32| {
+        pos.cmpgt(Vec2::splat(min + 0.5)).all() && pos.cmplt(Vec2::splat(max - 0.5)).all()
+    };
+
+    // The inner square is inside of the shape on both sides, so only the
+    // outer one fades out.
+    let faded = faded_points(path.clone(), FillRule::NonZero);
+    assert!(!faded.is_empty());
+    for pos in faded {
+        assert!(!inside(pos, 10.0, 90.0), "{pos}");
+    }
+
+    // The inner square is a hole, which fades out towards its middle.
+    let faded = faded_points(path, FillRule::EvenOdd);
+    assert!(faded.iter().any(|&pos| inside(pos, 30.0, 70.0)));
+    for pos in faded {
+        assert!(!inside(pos, 10.0, 90.0) || inside(pos, 29.0, 71.0), "{pos}");
+    }
+}
+
+#[test]
+fn stroke_dashes() {
+    let path = Path::polyline([Vec2::new(0.0, 50.0), Vec2::new(100.0, 50.0)]);
+    let stroke = Stroke::new(3.0, Color::RED).dashes(vec![10.0, 10.0]);
+
+    // Five dashes, each with a fully opaque core one pixel narrower than the
+    // stroke to leave room for anti-aliasing.
+    let area = opaque_area(move |paint| path.stroke(paint, &stroke));
+    assert!((area - 5.0 * 10.0 * 2.0).abs() < 0.1, "{area}");
+}
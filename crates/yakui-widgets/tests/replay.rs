@@ -0,0 +1,19 @@
+use yakui_test::{load_recording, replay};
+use yakui_widgets::{checkbox, column, text};
+
+#[test]
+fn replay_checkbox_click() {
+    let mut checked = false;
+
+    replay!(load_recording("tests/recordings/checkbox_click.json"), {
+        column(|| {
+            checked = checkbox(checked).checked;
+
+            if checked {
+                text(16.0, "Checked");
+            }
+        });
+    });
+
+    assert!(checked);
+}
@@ -0,0 +1,45 @@
+use yakui_core::event::Event;
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::input::ScrollDelta;
+use yakui_core::Yakui;
+use yakui_widgets::widgets::{ColoredBox, Scrollable};
+
+#[test]
+fn wheels_scroll_by_lines_and_trackpads_by_pixels() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(100.0, 50.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+    yak.set_scale_factor(2.0);
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let content = Scrollable::horizontal().line_height(10.0).show(|| {
+            ColoredBox::sized(Color::RED, Vec2::new(500.0, 20.0)).show();
+        });
+        yak.finish();
+
+        let scrollable = yak.dom().get(content.id).unwrap();
+        let child = scrollable.children[0];
+        yak.layout_dom().get(child).unwrap().rect.pos().x
+    };
+
+    let scroll = |yak: &mut Yakui, delta| {
+        yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+        assert!(yak.handle_event(Event::MouseScroll { delta }));
+    };
+
+    frame(&mut yak);
+    frame(&mut yak);
+
+    // A vertical wheel scrolls a horizontal scrollable, by lines.
+    scroll(&mut yak, ScrollDelta::Lines(Vec2::new(0.0, 2.0)));
+    assert_eq!(frame(&mut yak), -20.0);
+
+    // Pixels are physical, so they're scaled down.
+    scroll(&mut yak, ScrollDelta::Pixels(Vec2::new(7.0, 0.0)));
+    assert_eq!(frame(&mut yak), -23.5);
+
+    scroll(&mut yak, ScrollDelta::Lines(Vec2::new(-1.0, 0.0)));
+    assert_eq!(frame(&mut yak), -13.5);
+}
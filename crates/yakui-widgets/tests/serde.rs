@@ -0,0 +1,29 @@
+use serde_json::json;
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::layout::LayoutDomNode;
+use yakui_core::Yakui;
+use yakui_widgets::{colored_box, column};
+
+#[test]
+fn layout_output_inside_a_column_roundtrips() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(100.0, 100.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    yak.start();
+    let mut id = None;
+    column(|| {
+        id = Some(colored_box(Color::RED, [20.0, 20.0]).id);
+    });
+    yak.finish();
+
+    // Columns give their children an unbounded height.
+    let node = yak.layout_dom().get(id.unwrap()).unwrap();
+    let encoded = serde_json::to_value(node).unwrap();
+    assert_eq!(encoded["constraints"]["max"], json!([100.0, "inf"]));
+
+    let decoded: LayoutDomNode = serde_json::from_value(encoded).unwrap();
+    assert_eq!(decoded.rect, node.rect);
+    assert_eq!(decoded.constraints, node.constraints);
+}
@@ -0,0 +1,62 @@
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::Yakui;
+use yakui_widgets::canvas;
+use yakui_widgets::shadow::Shadow;
+use yakui_widgets::shapes;
+
+#[test]
+fn box_shadow_fades_out() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(200.0));
+
+    yak.start();
+    canvas(|ctx| {
+        let rect = Rect::from_pos_size(Vec2::splat(50.0), Vec2::splat(100.0));
+        let shadow = Shadow::new(Color::BLACK, 20.0).offset(Vec2::new(0.0, 10.0));
+        shapes::box_shadow(ctx.paint, rect, 10.0.into(), &shadow);
+    });
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+
+    let vertices = &calls[0].vertices;
+    let max = vertices.iter().map(|v| v.color.w).fold(0.0, f32::max);
+    let min = vertices.iter().map(|v| v.color.w).fold(1.0, f32::min);
+
+    assert!(max > 0.99, "{max}");
+    assert!(min < 0.01, "{min}");
+
+    // The shadow fades out a little past its blur around the offset
+    // rectangle.
+    for vertex in vertices {
+        let pos = vertex.position * 200.0;
+        assert!(pos.x >= 20.0 - 0.01 && pos.x <= 180.0 + 0.01, "{pos}");
+        assert!(pos.y >= 30.0 - 0.01 && pos.y <= 190.0 + 0.01, "{pos}");
+    }
+}
+
+#[test]
+fn circle_edges_fade_out() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::splat(100.0));
+
+    yak.start();
+    canvas(|ctx| {
+        shapes::Circle::new(Vec2::splat(50.0), 20.0)
+            .brush(Color::RED)
+            .add(ctx.paint);
+    });
+    yak.finish();
+
+    let calls = &yak.paint().layers()[0].calls;
+    for vertex in &calls[0].vertices {
+        let distance = (vertex.position * 100.0).distance(Vec2::splat(50.0));
+
+        if vertex.color.w == 0.0 {
+            assert!((distance - 20.5).abs() < 1e-3, "{distance}");
+        } else {
+            assert!(distance < 19.5 + 1e-3, "{distance}");
+        }
+    }
+}
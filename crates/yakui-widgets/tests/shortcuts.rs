@@ -0,0 +1,123 @@
+use yakui_core::event::Event;
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::input::{KeyCode, Modifiers};
+use yakui_core::{WidgetId, Yakui};
+use yakui_widgets::widgets::Action;
+use yakui_widgets::{action, column, textbox};
+
+#[derive(Debug, Default)]
+struct Triggered {
+    save: bool,
+    close: bool,
+    save_all: bool,
+    cancel_edit: bool,
+    select_all: bool,
+    textbox: Option<WidgetId>,
+}
+
+fn frame(yak: &mut Yakui) -> Triggered {
+    yak.start();
+    let mut triggered = Triggered::default();
+    column(|| {
+        triggered.save = action("Ctrl+S").triggered;
+        triggered.close = action("Escape").triggered;
+
+        triggered.select_all = Action::new()
+            .shortcut("Ctrl+A")
+            .show_scoped(|| {
+                triggered.cancel_edit = Action::new()
+                    .shortcut("Escape")
+                    .shortcut("Ctrl+K Ctrl+C")
+                    .show_scoped(|| {
+                        triggered.textbox = Some(textbox("hello").id);
+                    })
+                    .triggered;
+            })
+            .triggered;
+
+        triggered.save_all = action("Ctrl+K Ctrl+S").triggered;
+    });
+    yak.finish();
+    triggered
+}
+
+fn press(yak: &mut Yakui, modifiers: Modifiers, key: KeyCode) -> bool {
+    let sunk = yak.handle_event(Event::KeyChanged {
+        key,
+        down: true,
+        modifiers: Some(modifiers),
+    });
+    yak.handle_event(Event::KeyChanged {
+        key,
+        down: false,
+        modifiers: Some(modifiers),
+    });
+    sunk
+}
+
+#[test]
+fn shortcuts_resolve_by_focus_scope() {
+    let mut yak = Yakui::new();
+    let size = Vec2::splat(500.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let first = frame(&mut yak);
+    yak.handle_event(Event::RequestFocus(first.textbox));
+
+    // The textbox handles plain keys itself, but lets Ctrl+S through to the
+    // global shortcut.
+    assert!(press(&mut yak, Modifiers::empty(), KeyCode::KeyA));
+    assert!(press(&mut yak, Modifiers::CONTROL, KeyCode::KeyS));
+    let triggered = frame(&mut yak);
+    assert!(triggered.save);
+    assert!(!triggered.close && !triggered.cancel_edit);
+
+    // The focused textbox handles keys before scoped actions do.
+    assert!(press(&mut yak, Modifiers::CONTROL, KeyCode::KeyA));
+    let triggered = frame(&mut yak);
+    assert!(!triggered.select_all);
+
+    // The textbox lets Escape through, and the scoped action wins over the
+    // global one while the textbox is focused.
+    assert!(press(&mut yak, Modifiers::empty(), KeyCode::Escape));
+    let triggered = frame(&mut yak);
+    assert!(triggered.cancel_edit);
+    assert!(!triggered.close);
+
+    // Escape also stopped editing, so focus the textbox again.
+    yak.handle_event(Event::RequestFocus(first.textbox));
+
+    // A sequence started by one scope can be finished by another.
+    assert!(press(&mut yak, Modifiers::CONTROL, KeyCode::KeyK));
+    let triggered = frame(&mut yak);
+    assert!(!triggered.save_all && !triggered.cancel_edit);
+    assert!(press(&mut yak, Modifiers::CONTROL, KeyCode::KeyS));
+    let triggered = frame(&mut yak);
+    assert!(triggered.save_all);
+    assert!(!triggered.save);
+
+    // A key that doesn't continue the sequence is handled as usual.
+    press(&mut yak, Modifiers::CONTROL, KeyCode::KeyK);
+    press(&mut yak, Modifiers::CONTROL, KeyCode::KeyS);
+    let triggered = frame(&mut yak);
+    assert!(triggered.save_all);
+    press(&mut yak, Modifiers::CONTROL, KeyCode::KeyK);
+    yak.handle_event(Event::KeyChanged {
+        key: KeyCode::ControlLeft,
+        down: true,
+        modifiers: Some(Modifiers::CONTROL),
+    });
+    press(&mut yak, Modifiers::empty(), KeyCode::Escape);
+    let triggered = frame(&mut yak);
+    assert!(triggered.cancel_edit);
+    assert!(!triggered.save_all);
+
+    // Without focus, only global shortcuts are active.
+    yak.handle_event(Event::RequestFocus(None));
+    assert!(press(&mut yak, Modifiers::empty(), KeyCode::Escape));
+    let triggered = frame(&mut yak);
+    assert!(triggered.close);
+    assert!(!triggered.cancel_edit);
+    assert!(!press(&mut yak, Modifiers::empty(), KeyCode::KeyA));
+}
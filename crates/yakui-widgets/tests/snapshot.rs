@@ -2,7 +2,9 @@ use yakui::{Constraints, CrossAxisAlignment, Dim2, MainAxisAlignment, MainAxisSi
 use yakui_core::geometry::Color;
 use yakui_core::{Alignment, Pivot};
 use yakui_test::{run, Test};
-use yakui_widgets::widgets::{Button, List, Pad, UnconstrainedBox};
+use yakui_widgets::widgets::{
+    Button, Grid, GridItem, GridTrack, List, Pad, UnconstrainedBox, Wrap,
+};
 use yakui_widgets::{
     align, button, center, checkbox, colored_box, colored_box_container, column, constrained,
     expanded, pad, reflow, row, text,
@@ -234,6 +236,22 @@ fn row_cross_stretch() {
     });
 }
 
+#[test]
+fn row_cross_baseline() {
+    run!({
+        let mut row = List::row();
+        row.cross_axis_alignment = CrossAxisAlignment::Baseline;
+        row.show(|| {
+            text(12.0, "small");
+            text(24.0, "large");
+            pad(Pad::all(8.0), || {
+                text(16.0, "padded");
+            });
+            colored_box(Color::RED, [20.0, 20.0]);
+        });
+    });
+}
+
 /// When given infinite constraints, widgets like List need to pick the minimum
 /// size that fits their content, not infinity.
 #[test]
@@ -368,6 +386,189 @@ fn row_reflow() {
     });
 }
 
+#[test]
+fn wrap_basic() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(170.0, 1000.0)), || {
+            Wrap::row()
+                .main_axis_spacing(10.0)
+                .cross_axis_spacing(5.0)
+                .show(|| {
+                    for _ in 0..5 {
+                        rect_50x50();
+                    }
+                });
+        });
+    });
+}
+
+#[test]
+fn wrap_alignment() {
+    run!({
+        constrained(Constraints::tight(Vec2::new(120.0, 200.0)), || {
+            Wrap::row()
+                .main_axis_alignment(MainAxisAlignment::SpaceBetween)
+                .cross_axis_alignment(CrossAxisAlignment::Center)
+                .run_alignment(MainAxisAlignment::End)
+                .show(|| {
+                    rect(50.0, 20.0);
+                    rect(50.0, 40.0);
+                    rect(30.0, 30.0);
+                });
+        });
+    });
+}
+
+#[test]
+fn wrap_flex_per_run() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(120.0, 1000.0)), || {
+            Wrap::row().show(|| {
+                rect(50.0, 20.0);
+                expanded(|| {
+                    rect(10.0, 20.0);
+                });
+                rect(80.0, 20.0);
+                rect(20.0, 20.0);
+            });
+        });
+    });
+}
+
+#[test]
+fn wrap_long_label() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(150.0, 1000.0)), || {
+            Wrap::row().show(|| {
+                rect(50.0, 20.0);
+                text(16.0, "a label that is much wider than the wrap");
+                row(|| {
+                    rect(20.0, 20.0);
+                });
+                rect(50.0, 20.0);
+            });
+        });
+    });
+}
+
+#[test]
+fn wrap_column_stretch() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(1000.0, 100.0)), || {
+            Wrap::column()
+                .cross_axis_alignment(CrossAxisAlignment::Stretch)
+                .show(|| {
+                    rect(30.0, 50.0);
+                    rect(60.0, 40.0);
+                    rect(20.0, 30.0);
+                });
+        });
+    });
+}
+
+#[test]
+fn grid_track_sizing() {
+    run!({
+        Grid::new()
+            .columns([
+                GridTrack::px(100.0),
+                GridTrack::fr(1.0),
+                GridTrack::auto(),
+                GridTrack::fr(2.0),
+                GridTrack::percent(0.1),
+            ])
+            .column_gap(10.0)
+            .show(|| {
+                rect(50.0, 20.0);
+                rect(50.0, 20.0);
+                rect(70.0, 30.0);
+                rect(50.0, 20.0);
+                rect(50.0, 20.0);
+            });
+    });
+}
+
+#[test]
+fn grid_min_max_and_fr_minimum() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(300.0, 1000.0)), || {
+            Grid::new()
+                .columns([
+                    GridTrack::fr(1.0),
+                    GridTrack::fr(1.0),
+                    GridTrack::min_max(GridTrack::px(40.0), GridTrack::fr(1.0)),
+                ])
+                .show(|| {
+                    // Wider than its share, so the other columns split
+                    // what's left.
+                    rect(200.0, 20.0);
+                    rect(10.0, 20.0);
+                    rect(10.0, 20.0);
+                });
+        });
+    });
+}
+
+#[test]
+fn grid_fr_column_wraps_text() {
+    run!({
+        constrained(Constraints::loose(Vec2::new(200.0, 1000.0)), || {
+            Grid::new()
+                .columns([GridTrack::auto(), GridTrack::fr(1.0)])
+                .column_gap(10.0)
+                .show(|| {
+                    rect(50.0, 20.0);
+                    // Measured against its column instead of unbounded, so it
+                    // wraps instead of widening the grid.
+                    text(16.0, "a label that is much wider than its column");
+                });
+        });
+    });
+}
+
+#[test]
+fn grid_spans_and_auto_placement() {
+    run!({
+        Grid::new()
+            .columns([
+                GridTrack::px(50.0),
+                GridTrack::px(50.0),
+                GridTrack::px(50.0),
+            ])
+            .rows([GridTrack::px(20.0)])
+            .auto_rows(GridTrack::auto())
+            .row_gap(5.0)
+            .stretch_items(true)
+            .show(|| {
+                GridItem::at(0, 1).row_span(2).show(|| {
+                    rect(10.0, 10.0);
+                });
+                rect(10.0, 10.0);
+                rect(10.0, 10.0);
+                GridItem::new().column_span(2).show(|| {
+                    rect(10.0, 30.0);
+                });
+                rect(10.0, 10.0);
+                GridItem::new().column(1).show(|| {
+                    rect(10.0, 10.0);
+                });
+            });
+    });
+}
+
+#[test]
+fn grid_item_alignment() {
+    run!({
+        Grid::new()
+            .columns([GridTrack::px(100.0), GridTrack::px(100.0)])
+            .item_alignment(Alignment::CENTER)
+            .show(|| {
+                rect(50.0, 50.0);
+                rect(20.0, 20.0);
+            });
+    });
+}
+
 fn rect<V: IntoF32>(w: V, h: V) {
     colored_box(Color::WHITE, [w.to_f32(), h.to_f32()]);
 }
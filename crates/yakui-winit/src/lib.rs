@@ -7,7 +7,7 @@ use winit::event::{ElementState, MouseButton as WinitMouseButton, MouseScrollDel
 use winit::window::Window;
 use yakui_core::event::Event;
 use yakui_core::geometry::{Rect, Vec2};
-use yakui_core::input::MouseButton;
+use yakui_core::input::{MouseButton, ScrollDelta};
 
 pub use self::keys::{from_winit_key, from_winit_modifiers};
 
@@ -98,11 +98,13 @@ impl YakuiWinit {
                 state: button_state,
                 ..
             } => {
-                let button = match button {
+                let button = match *button {
                     WinitMouseButton::Left => MouseButton::One,
                     WinitMouseButton::Right => MouseButton::Two,
                     WinitMouseButton::Middle => MouseButton::Three,
-                    _ => return false,
+                    WinitMouseButton::Back => MouseButton::Back,
+                    WinitMouseButton::Forward => MouseButton::Forward,
+                    WinitMouseButton::Other(id) => MouseButton::Other(id),
                 };
 
                 let down = match button_state {
@@ -113,20 +115,14 @@ impl YakuiWinit {
                 state.handle_event(Event::MouseButtonChanged { button, down })
             }
             WindowEvent::MouseWheel { delta, .. } => {
-                // Observed logical pixels per scroll wheel increment in Windows on Chrome
-                const LINE_HEIGHT: f32 = 100.0 / 3.0;
-
+                // Flip delta axis from winit's expectations.
                 let delta = match *delta {
-                    MouseScrollDelta::LineDelta(x, y) => Vec2::new(x, y) * LINE_HEIGHT,
+                    MouseScrollDelta::LineDelta(x, y) => ScrollDelta::Lines(-Vec2::new(x, y)),
                     MouseScrollDelta::PixelDelta(offset) => {
-                        Vec2::new(offset.x as f32, offset.y as f32)
-                            / state.layout_dom().scale_factor()
+                        ScrollDelta::Pixels(-Vec2::new(offset.x as f32, offset.y as f32))
                     }
                 };
 
-                // Flip delta axis from winit's expectations.
-                let delta = -delta;
-
                 state.handle_event(Event::MouseScroll { delta })
             }
             WindowEvent::ModifiersChanged(mods) => {
@@ -160,6 +156,19 @@ impl YakuiWinit {
                 }
             }
 
+            WindowEvent::HoveredFile(path) => state.handle_event(Event::FileHovered {
+                path: path.clone(),
+                position: None,
+            }),
+            WindowEvent::HoveredFileCancelled => state.handle_event(Event::FileHoverCancelled),
+            WindowEvent::DroppedFile(path) => state.handle_event(Event::FileDropped {
+                path: path.clone(),
+                position: None,
+            }),
+            WindowEvent::Focused(focused) => {
+                state.handle_event(Event::WindowFocusChanged(*focused))
+            }
+
             WindowEvent::Ime(winit::event::Ime::Commit(text)) => {
                 for c in text.chars() {
                     state.handle_event(Event::TextInput(c));
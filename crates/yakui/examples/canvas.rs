@@ -31,7 +31,7 @@ pub fn run() {
 
                         let r = x as f32 / RES as f32;
                         let g = y as f32 / RES as f32;
-                        rect.color = Color::from_linear(Vec4::new(r, g, 0.0, 1.0));
+                        rect.brush = Color::from_linear(Vec4::new(r, g, 0.0, 1.0)).into();
 
                         rect.add(ctx.paint);
                     }
@@ -9,6 +9,7 @@ pub fn run(state: &mut ExampleState) {
         CrossAxisAlignment::Center,
         CrossAxisAlignment::End,
         CrossAxisAlignment::Stretch,
+        CrossAxisAlignment::Baseline,
     ];
 
     let index = (state.time.floor() as usize) % alignments.len();
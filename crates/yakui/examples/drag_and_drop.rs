@@ -0,0 +1,65 @@
+use yakui::widgets::{DragSource, DropHover, DropTarget, List, Pad};
+use yakui::{colored_box_container, label, pad, use_state, Color};
+
+const ITEMS: [(&str, Color); 4] = [
+    ("Sword", Color::RED),
+    ("Shield", Color::BLUE),
+    ("Potion", Color::GREEN),
+    ("Key", Color::REBECCA_PURPLE),
+];
+
+pub fn run() {
+    let slots = use_state(|| [Some(0), Some(1), None, Some(2), None, Some(3)]);
+    let hovered = use_state(|| None::<(usize, DropHover)>);
+
+    let mut next_hovered = None;
+
+    pad(Pad::all(16.0), || {
+        List::row().item_spacing(8.0).show(|| {
+            for index in 0..slots.borrow().len() {
+                let background = match hovered.get() {
+                    Some((slot, DropHover::Accept)) if slot == index => Color::CORNFLOWER_BLUE,
+                    Some((slot, DropHover::Reject)) if slot == index => Color::RED.adjust(0.5),
+                    _ => Color::GRAY.adjust(0.5),
+                };
+
+                // The last slot only accepts the key.
+                let accept = |&from: &usize| index != 5 || slots.borrow()[from] == Some(3);
+
+                let target = DropTarget::new().accept(&accept).show(|| {
+                    colored_box_container(background, || {
+                        pad(Pad::all(8.0), || {
+                            if let Some(item) = slots.borrow()[index] {
+                                let (name, color) = ITEMS[item];
+
+                                DragSource::new(index).show(|| {
+                                    colored_box_container(color, || {
+                                        pad(Pad::all(8.0), || {
+                                            label(name);
+                                        });
+                                    });
+                                });
+                            } else {
+                                label("Empty");
+                            }
+                        });
+                    });
+                });
+
+                if let Some(hover) = target.hovering {
+                    next_hovered = Some((index, hover));
+                }
+
+                if let Some(from) = target.dropped {
+                    slots.borrow_mut().swap(from, index);
+                }
+            }
+        });
+    });
+
+    hovered.set(next_hovered);
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -1,9 +1,11 @@
 //! This example shows how you might use `use_state` to keep a windowed average
-//! of the current framerate of your application.
+//! of the current framerate of your application, next to yakui's built-in
+//! frame statistics.
 
 use std::collections::VecDeque;
 use std::time::Instant;
 
+use yakui::widgets::StatsOverlay;
 use yakui::{column, label, use_state};
 
 pub fn run() {
@@ -27,6 +29,8 @@ pub fn run() {
     column(|| {
         label(format!("{:.2} ms", avg * 1000.0));
         label(format!("{:.0} FPS", 1.0 / avg));
+
+        StatsOverlay::new().show();
     });
 }
 
@@ -0,0 +1,52 @@
+use yakui::widgets::{Circle, ColoredBox, RoundRect};
+use yakui::{center, column, row, spacer, text, Brush, Color, Vec2};
+
+use bootstrap::ExampleState;
+
+pub fn run(state: &mut ExampleState) {
+    let health = (state.time * 0.5).sin().abs();
+
+    center(|| {
+        column(|| {
+            text(32.0, "Gradient Demo");
+            spacer(1);
+
+            let bar = Brush::linear(
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                [(0.0, Color::RED), (0.5, Color::YELLOW), (1.0, Color::GREEN)],
+            );
+
+            row(|| {
+                ColoredBox::sized(bar, Vec2::new(300.0 * health, 24.0)).show();
+            });
+            spacer(1);
+
+            row(|| {
+                RoundRect::new(16.0)
+                    .min_size(Vec2::new(140.0, 80.0))
+                    .color(Brush::linear(
+                        Vec2::new(0.0, 0.0),
+                        Vec2::new(1.0, 1.0),
+                        [(0.0, Color::BLUE), (1.0, Color::CYAN)],
+                    ))
+                    .show();
+
+                spacer(1);
+
+                Circle::new()
+                    .min_radius(40.0)
+                    .color(Brush::radial(
+                        Vec2::new(0.5, 0.5),
+                        Vec2::new(0.5, 0.5),
+                        [(0.0, Color::WHITE), (1.0, Color::rgb(255, 0, 255))],
+                    ))
+                    .show();
+            });
+        });
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn(&mut ExampleState));
+}
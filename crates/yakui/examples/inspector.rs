@@ -0,0 +1,30 @@
+use yakui::widgets::{Inspector, List, Pad};
+use yakui::{button, checkbox, colored_box, label, pad, row, use_state, Color};
+
+pub fn run() {
+    let inspecting = use_state(|| true);
+
+    List::column().item_spacing(8.0).show(|| {
+        row(|| {
+            let res = checkbox(inspecting.get());
+            inspecting.set(res.checked);
+            label("Show inspector");
+        });
+
+        pad(Pad::all(16.0), || {
+            row(|| {
+                colored_box(Color::RED, [80.0, 80.0]);
+                colored_box(Color::GREEN, [120.0, 60.0]);
+                colored_box(Color::BLUE, [60.0, 100.0]);
+            });
+        });
+
+        button("Hover me");
+    });
+
+    Inspector::new().enabled(inspecting.get()).show();
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -0,0 +1,66 @@
+//! This example shows how to draw vector paths with the `Canvas` widget.
+
+use std::f32::consts::TAU;
+
+use yakui::path::{FillRule, LineCap, LineJoin, Path, Stroke};
+use yakui::{canvas, center, constrained, Color, Constraints, Vec2};
+
+use bootstrap::ExampleState;
+
+pub fn run(state: &mut ExampleState) {
+    let time = state.time;
+    let constraints = Constraints::tight([400.0, 400.0].into());
+
+    center(|| {
+        constrained(constraints, || {
+            canvas(move |ctx| {
+                let layout = ctx.layout.get(ctx.dom.current()).unwrap();
+                let origin = layout.rect.pos();
+
+                // A chart line with round joins.
+                let chart = Path::polyline((0..20).map(|i| {
+                    let x = i as f32 * 20.0;
+                    let y = 80.0 + (x / 40.0 + time).sin() * 40.0;
+                    origin + Vec2::new(x, y)
+                }));
+                chart.stroke(
+                    ctx.paint,
+                    &Stroke::new(3.0, Color::CYAN)
+                        .join(LineJoin::Round)
+                        .cap(LineCap::Round),
+                );
+
+                // A node graph wire, dashed.
+                let mut wire = Path::new();
+                wire.move_to(origin + Vec2::new(20.0, 180.0)).cubic_to(
+                    origin + Vec2::new(200.0, 180.0),
+                    origin + Vec2::new(200.0, 260.0),
+                    origin + Vec2::new(380.0, 260.0),
+                );
+                wire.stroke(
+                    ctx.paint,
+                    &Stroke::new(2.0, Color::YELLOW).dashes(vec![10.0, 6.0]),
+                );
+
+                // A star, filled with both fill rules.
+                for (i, rule) in [FillRule::NonZero, FillRule::EvenOdd]
+                    .into_iter()
+                    .enumerate()
+                {
+                    let center = origin + Vec2::new(100.0 + 200.0 * i as f32, 330.0);
+                    let star = Path::polygon((0..5).map(|point| {
+                        let angle = TAU * (point * 2) as f32 / 5.0 - TAU / 4.0;
+                        center + Vec2::new(angle.cos(), angle.sin()) * 60.0
+                    }));
+
+                    star.fill(ctx.paint, rule, Color::rgb(255, 128, 0));
+                    star.stroke(ctx.paint, &Stroke::new(1.0, Color::WHITE));
+                }
+            });
+        });
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn(&mut ExampleState));
+}
@@ -72,7 +72,7 @@ fn draw_segment(ctx: &mut PaintContext<'_>, center: Vec2, start_angle: f32, end_
     let path = builder.build();
 
     {
-        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        let mut buffers: VertexBuffers<Point, u32> = VertexBuffers::new();
         let mut vertex_builder = simple_builder(&mut buffers);
         let mut tessellator = FillTessellator::new();
 
@@ -90,7 +90,7 @@ fn draw_segment(ctx: &mut PaintContext<'_>, center: Vec2, start_angle: f32, end_
     }
 
     {
-        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        let mut buffers: VertexBuffers<Point, u32> = VertexBuffers::new();
         let mut vertex_builder = simple_builder(&mut buffers);
         let mut tessellator = StrokeTessellator::new();
 